@@ -4,9 +4,11 @@ use crate::traits::HasNodeId;
 use internment::Intern;
 use xva_span::SourceSpan;
 
+mod func;
 mod stmt;
 mod ty;
 
+pub use func::*;
 pub use stmt::*;
 pub use ty::*;
 
@@ -21,7 +23,7 @@ impl Brick {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Item {
     pub id: NodeId,
     pub kind: ItemKind,
@@ -29,6 +31,10 @@ pub struct Item {
     /// The item's start (inclusive) and end (exclusive) range, in byte offsets
     /// from the source text.
     pub span: SourceSpan,
+
+    /// Doc comments written before the item. Items that declare something, like a [`Local`], keep their docs on the
+    /// declaration instead.
+    pub docs: Option<Docs>,
 }
 
 impl Item {
@@ -37,54 +43,226 @@ impl Item {
             id: ERROR_NODE_ID,
             kind: ItemKind::Error(err),
             span,
+            docs: None,
         }
     }
 }
-#[derive(Debug)]
+
+/// Documentation, from the doc comments (`///` or `/** */`) written directly before a node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Docs {
+    /// The text of every doc comment, in order, separated by newlines.
+    pub text: String,
+
+    /// The span from the start of the first doc comment to the end of the last.
+    pub span: SourceSpan,
+}
+#[derive(Debug, Clone)]
 pub enum ItemKind {
     Expression(Expression),
     Statement(Statement),
     Module(Module),
+    Function(Function),
 
     Error(Intern<String>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Module {
     pub items: Vec<Item>,
 }
 
-#[derive(Debug)]
+/// Items between braces, like the body of a function or a block expression.
+#[derive(Debug, Clone)]
+pub struct Block {
+    pub id: NodeId,
+    pub items: Vec<Item>,
+
+    /// The span from the opening brace to the closing one.
+    pub span: SourceSpan,
+}
+
+#[derive(Debug, Clone)]
 pub struct Expression {
     pub id: NodeId,
     pub kind: ExpressionKind,
     pub span: SourceSpan,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ExpressionKind {
     Literal(LiteralKind),
-    Identifier(String),
+
+    /// A name that refers to a declaration, like `x` or `std.io.stdout`. A lone identifier is a path of one segment.
+    Path(Name),
+
     Unary(UnaryOperator, Box<Expression>),
     Binary(BinaryOperator, Box<Expression>, Box<Expression>),
+
+    /// A string with expressions interpolated into it, like `"hello {name}"`. Strings without any interpolations
+    /// are [`LiteralKind::String`]s instead.
+    Interpolated(Vec<InterpolatedPart>),
+
+    /// A call of a function with its arguments, like `f(x, y)`.
+    Call(Box<Expression>, Vec<Expression>),
+
+    /// Returns from the function it's in, like `return x`. Without a value, it returns `unit`.
+    Return(Option<Box<Expression>>),
+
+    /// Items in a scope of their own, like `{ let x = 1; x + 1 }`. Its value is the value of its last item.
+    Block(Block),
 }
 
-#[derive(Debug)]
+/// A fragment of an interpolated string.
+#[derive(Debug, Clone)]
+pub enum InterpolatedPart {
+    /// Text, with its escapes already replaced. The span includes the quote or braces around the text, e.g.
+    /// `"hello {`.
+    Text(String, SourceSpan),
+
+    /// An interpolated expression, e.g. `name` in `"hello {name}"`.
+    Expression(Expression),
+}
+
+#[derive(Debug, Clone)]
 pub enum LiteralKind {
-    Integer(i128),
+    Integer(u128, Option<IntegerSuffix>),
     Boolean(bool),
     Char(char),
-    Float(f64),
+    Float(f64, Option<FloatSuffix>),
     String(String), // TODO PLEASE change this to interned strings bruh
 }
 
+/// The type suffix of an integer literal, e.g. `u8` in `10u8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegerSuffix {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+}
+
+impl IntegerSuffix {
+    pub const ALL: &'static [Self] = &[
+        Self::I8,
+        Self::I16,
+        Self::I32,
+        Self::I64,
+        Self::I128,
+        Self::U8,
+        Self::U16,
+        Self::U32,
+        Self::U64,
+        Self::U128,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::I128 => "i128",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::U128 => "u128",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|suffix| suffix.name() == name)
+    }
+
+    /// The largest value of this type.
+    pub fn max(&self) -> u128 {
+        match self {
+            Self::I8 => i8::MAX as u128,
+            Self::I16 => i16::MAX as u128,
+            Self::I32 => i32::MAX as u128,
+            Self::I64 => i64::MAX as u128,
+            Self::I128 => i128::MAX as u128,
+            Self::U8 => u8::MAX as u128,
+            Self::U16 => u16::MAX as u128,
+            Self::U32 => u32::MAX as u128,
+            Self::U64 => u64::MAX as u128,
+            Self::U128 => u128::MAX,
+        }
+    }
+
+    /// The largest value a literal with this suffix can be lexed with.
+    ///
+    /// Literals are never negative, since `-` is an operator, so the smallest value of a signed type is written by
+    /// negating one more than its maximum, e.g. `-128i8`. The lexer can't see the `-`, so it lets signed literals be
+    /// one more than [`Self::max`], and the parser reports them unless they're negated.
+    pub fn max_literal(&self) -> u128 {
+        match self {
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 => self.max() + 1,
+            _ => self.max(),
+        }
+    }
+}
+
+impl std::fmt::Display for IntegerSuffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The type suffix of a float literal, e.g. `f32` in `2.0f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatSuffix {
+    F32,
+    F64,
+}
+
+impl FloatSuffix {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::F32, Self::F64]
+            .into_iter()
+            .find(|suffix| suffix.name() == name)
+    }
+}
+
+impl std::fmt::Display for FloatSuffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum UnaryOperator {
     Negation,
     Not,
 }
 
-#[derive(Debug)]
+impl std::fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnaryOperator::Negation => write!(f, "-"),
+            UnaryOperator::Not => write!(f, "not"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
 pub enum BinaryOperator {
     LogicalAnd,
     LogicalOr,
@@ -107,6 +285,34 @@ pub enum BinaryOperator {
     LessThanEqual,
 }
 
+impl std::fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            BinaryOperator::LogicalAnd => "and",
+            BinaryOperator::LogicalOr => "or",
+            BinaryOperator::BitwiseAnd => "&",
+            BinaryOperator::BitwiseOr => "|",
+            BinaryOperator::BitwiseXor => "^",
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::Power => "**",
+            BinaryOperator::LeftShift => "<<",
+            BinaryOperator::RightShift => ">>",
+            BinaryOperator::LessThan => "<",
+            BinaryOperator::GreaterThan => ">",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::GreaterThanEqual => ">=",
+            BinaryOperator::LessThanEqual => "<=",
+        };
+
+        write!(f, "{symbol}")
+    }
+}
+
 #[derive(Debug)]
 pub enum LiteralIntegerKind {
     Signed,
@@ -128,6 +334,19 @@ pub struct Name {
     pub segments: Vec<NameSegment>,
 }
 
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", segment.ident.name)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// A segment of a Xva identifier. For example, the name `brick.module.function` would contain three [`NameSegment`]s.
 #[derive(Debug, Clone)]
 pub struct NameSegment {
@@ -0,0 +1,35 @@
+use xva_span::SourceSpan;
+
+use super::{Block, Docs, Identifier, Type};
+use crate::node_id::NodeId;
+
+/// A function declaration.
+///
+/// For example: `fn add(a: int, b: int) -> int { a + b }`
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub id: NodeId,
+
+    /// The span from the `fn` keyword to the end of the body.
+    pub span: SourceSpan,
+    pub name: Identifier,
+    pub params: Vec<Parameter>,
+
+    /// The return type. If `None`, the function returns `unit`.
+    pub return_ty: Option<Type>,
+
+    /// The body, whose last item is the value returned, unless the function returns `unit`.
+    pub body: Block,
+
+    /// Doc comments written before the function.
+    pub docs: Option<Docs>,
+}
+
+/// A parameter of a [`Function`], like `a: int`. Every parameter must have a type.
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    pub id: NodeId,
+    pub span: SourceSpan,
+    pub name: Identifier,
+    pub ty: Type,
+}
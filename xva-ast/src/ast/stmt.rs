@@ -1,16 +1,16 @@
 use xva_span::SourceSpan;
 
-use super::{Expression, Identifier, Type};
+use super::{Docs, Expression, Identifier, Type};
 use crate::node_id::NodeId;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Statement {
     pub id: NodeId,
     pub kind: StatementKind,
     pub span: SourceSpan,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum StatementKind {
     Local(Local),
 }
@@ -18,7 +18,7 @@ pub enum StatementKind {
 /// Represents a binding of a name, i.e. a variable declaration
 ///
 /// For example: `let x = 5` or `var x: bool = false`
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Local {
     pub id: NodeId,
     pub span: SourceSpan,
@@ -30,15 +30,18 @@ pub struct Local {
     ///
     /// Note that `None` is a distinct but related concept to [`TypeKind::Infer`]
     pub ty: Option<Type>,
+
+    /// Doc comments written before the binding.
+    pub docs: Option<Docs>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum BindingKind {
     Declared,
     Inited(Box<Expression>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum BindingPattern {
     Identifier(Identifier),
 }
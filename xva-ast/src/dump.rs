@@ -0,0 +1,346 @@
+//! A stable, textual dump of the syntax tree, as S-expressions.
+//!
+//! Unlike the [`Debug`] output, this format is meant to be diffed against: golden tests compare dumps, so it only
+//! changes when the language does, never because a Rust field was renamed.
+//!
+//! # Format
+//! Every node is written as a list: `(head #id @start..end atoms... children...)`.
+//! - `head` names the kind of node, e.g. `binary` or `local`.
+//! - `#id` is the node's [`NodeId`](crate::node_id::NodeId). It is left out unless [`DumpOptions::node_ids`] is set.
+//! - `@start..end` is the node's byte range in its source file.
+//! - `atoms` are the node's own data, like an operator or a name. Strings and chars are quoted and escaped.
+//! - `children` are nested nodes, each on its own line and indented by two spaces.
+//!
+//! Items that only wrap an expression or a statement are left out, and the expression or statement is written in
+//! their place.
+//!
+//! Numeric literals with a type suffix have it as an extra atom, e.g. `(int @0..4 10 u8)`.
+//!
+//! Docs are written as a `(docs @start..end "text")` child, after the node's other children.
+//!
+//! For example, `let x: int = 1 + 2` dumps as:
+//! ```text
+//! (local @0..5 let x
+//!   (type @7..10 int)
+//!   (binary @13..18 +
+//!     (int @13..14 1)
+//!     (int @17..18 2)))
+//! ```
+
+use std::fmt::{Display, Write};
+
+use xva_span::SourceSpan;
+
+use crate::{
+    ast::{
+        BindingKind, BindingPattern, Block, Docs, Expression, ExpressionKind, Function,
+        InterpolatedPart, Item, ItemKind, LiteralKind, Local, Parameter, Statement, StatementKind,
+        Type, TypeKind,
+    },
+    node_id::NodeId,
+};
+
+/// What to include in a dump.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DumpOptions {
+    /// Whether to write every node's [`NodeId`]. These change whenever a node is added anywhere before it, so they
+    /// are left out by default.
+    pub node_ids: bool,
+}
+
+/// A single S-expression: a list with a head, some atoms, and nested lists.
+#[derive(Debug, Clone)]
+pub struct SExpr {
+    head: String,
+    atoms: Vec<String>,
+    children: Vec<SExpr>,
+}
+
+impl SExpr {
+    pub fn new(head: impl Into<String>) -> Self {
+        Self {
+            head: head.into(),
+            atoms: vec![],
+            children: vec![],
+        }
+    }
+
+    /// Starts a list for a node, with its ID (if asked for) and span.
+    pub fn node(head: &str, id: NodeId, span: SourceSpan, opts: &DumpOptions) -> Self {
+        let node = Self::new(head);
+        let node = match opts.node_ids {
+            true => node.atom(format!("#{}", id.0)),
+            false => node,
+        };
+        node.atom(format!("@{}", span.range()))
+    }
+
+    /// Adds an atom, written as is.
+    pub fn atom(mut self, atom: impl Display) -> Self {
+        self.atoms.push(atom.to_string());
+        self
+    }
+
+    /// Adds an atom, quoted and escaped.
+    pub fn quoted(self, text: impl std::fmt::Debug) -> Self {
+        self.atom(format!("{text:?}"))
+    }
+
+    pub fn child(mut self, child: SExpr) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    fn write(&self, f: &mut impl Write, indent: usize) -> std::fmt::Result {
+        write!(f, "({}", self.head)?;
+        for atom in &self.atoms {
+            write!(f, " {atom}")?;
+        }
+
+        for child in &self.children {
+            write!(f, "\n{:width$}", "", width = indent + 2)?;
+            child.write(f, indent + 2)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl Display for SExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+/// Anything that can be dumped.
+pub trait Dump {
+    fn dump(&self, opts: &DumpOptions) -> SExpr;
+}
+
+/// Dumps a whole tree, one top-level node per line.
+pub fn dump_items(items: &[Item], opts: &DumpOptions) -> String {
+    items
+        .iter()
+        .map(|item| format!("{}\n", item.dump(opts)))
+        .collect()
+}
+
+impl Dump for Docs {
+    fn dump(&self, _opts: &DumpOptions) -> SExpr {
+        SExpr::new("docs")
+            .atom(format!("@{}", self.span.range()))
+            .quoted(&self.text)
+    }
+}
+
+impl SExpr {
+    /// Adds docs as a child, if there are any.
+    fn docs(self, docs: &Option<Docs>, opts: &DumpOptions) -> Self {
+        match docs {
+            Some(docs) => self.child(docs.dump(opts)),
+            None => self,
+        }
+    }
+
+    /// Adds a numeric literal's type suffix as an atom, if it has one.
+    pub fn suffix(self, suffix: &Option<impl Display>) -> Self {
+        match suffix {
+            Some(suffix) => self.atom(suffix),
+            None => self,
+        }
+    }
+}
+
+impl Dump for Item {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        let node = match &self.kind {
+            ItemKind::Expression(expr) => expr.dump(opts),
+            ItemKind::Statement(stmt) => stmt.dump(opts),
+            ItemKind::Module(module) => module.items.iter().fold(
+                SExpr::node("module", self.id, self.span, opts),
+                |node, item| node.child(item.dump(opts)),
+            ),
+            ItemKind::Function(function) => function.dump(opts),
+            ItemKind::Error(message) => {
+                SExpr::node("error", self.id, self.span, opts).quoted(message.as_str())
+            }
+        };
+
+        node.docs(&self.docs, opts)
+    }
+}
+
+impl Dump for Function {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        let node = self.params.iter().fold(
+            SExpr::node("fn", self.id, self.span, opts).atom(self.name.name),
+            |node, param| node.child(param.dump(opts)),
+        );
+
+        let node = match &self.return_ty {
+            Some(ty) => node.child(ty.dump(opts)),
+            None => node,
+        };
+
+        node.child(self.body.dump(opts)).docs(&self.docs, opts)
+    }
+}
+
+impl Dump for Parameter {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        SExpr::node("param", self.id, self.span, opts)
+            .atom(self.name.name)
+            .child(self.ty.dump(opts))
+    }
+}
+
+impl Dump for Block {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        self.items.iter().fold(
+            SExpr::node("block", self.id, self.span, opts),
+            |node, item| node.child(item.dump(opts)),
+        )
+    }
+}
+
+impl Dump for Statement {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        match &self.kind {
+            StatementKind::Local(local) => local.dump(opts),
+        }
+    }
+}
+
+impl Dump for Local {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        let keyword = match self.binding_flags.mutable {
+            true => "var",
+            false => "let",
+        };
+
+        let node = SExpr::node("local", self.id, self.span, opts).atom(keyword);
+        let node = match &self.pattern {
+            BindingPattern::Identifier(ident) => node.atom(ident.name),
+        };
+
+        let node = match &self.ty {
+            Some(ty) => node.child(ty.dump(opts)),
+            None => node,
+        };
+
+        let node = match &self.binding_kind {
+            BindingKind::Declared => node,
+            BindingKind::Inited(expr) => node.child(expr.dump(opts)),
+        };
+
+        node.docs(&self.docs, opts)
+    }
+}
+
+impl Dump for Expression {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        let node = |head| SExpr::node(head, self.id, self.span, opts);
+
+        match &self.kind {
+            ExpressionKind::Literal(lit) => match lit {
+                LiteralKind::Integer(i, suffix) => node("int").atom(i).suffix(suffix),
+                LiteralKind::Boolean(b) => node("bool").atom(b),
+                LiteralKind::Char(c) => node("char").quoted(c),
+                LiteralKind::Float(f, suffix) => {
+                    node("float").atom(format!("{f:?}")).suffix(suffix)
+                }
+                LiteralKind::String(s) => node("string").quoted(s),
+            },
+            ExpressionKind::Path(name) => node("path").atom(name),
+            ExpressionKind::Unary(op, operand) => node("unary").atom(op).child(operand.dump(opts)),
+            ExpressionKind::Binary(op, lhs, rhs) => node("binary")
+                .atom(op)
+                .child(lhs.dump(opts))
+                .child(rhs.dump(opts)),
+            ExpressionKind::Interpolated(parts) => {
+                parts.iter().fold(node("interpolated"), |node, part| {
+                    node.child(match part {
+                        // Text isn't a node of its own, so it has no ID.
+                        InterpolatedPart::Text(text, span) => SExpr::new("text")
+                            .atom(format!("@{}", span.range()))
+                            .quoted(text),
+                        InterpolatedPart::Expression(expr) => expr.dump(opts),
+                    })
+                })
+            }
+            ExpressionKind::Call(callee, args) => args
+                .iter()
+                .fold(node("call").child(callee.dump(opts)), |node, arg| {
+                    node.child(arg.dump(opts))
+                }),
+            ExpressionKind::Return(value) => match value {
+                Some(value) => node("return").child(value.dump(opts)),
+                None => node("return"),
+            },
+            ExpressionKind::Block(block) => block.dump(opts),
+        }
+    }
+}
+
+impl Dump for Type {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        let node = SExpr::node("type", self.id, self.span, opts);
+        match &self.kind {
+            TypeKind::Unit => node.atom("unit"),
+            TypeKind::Never => node.atom("never"),
+            TypeKind::Infer => node.atom("_"),
+            TypeKind::Named(name) => node.atom(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use xva_span::{CheapRange, SourceSpan};
+
+    use super::{Dump, DumpOptions, SExpr};
+    use crate::{
+        ast::{BinaryOperator, Expression, ExpressionKind, LiteralKind},
+        node_id::NodeId,
+    };
+
+    fn span(start: usize, end: usize) -> SourceSpan {
+        SourceSpan::new(0.into(), CheapRange::new(start, end))
+    }
+
+    fn int(id: i64, i: u128, start: usize) -> Box<Expression> {
+        Box::new(Expression {
+            id: NodeId(id),
+            kind: ExpressionKind::Literal(LiteralKind::Integer(i, None)),
+            span: span(start, start + 1),
+        })
+    }
+
+    #[test]
+    fn nesting() {
+        let sexpr = SExpr::new("a")
+            .atom(1)
+            .child(SExpr::new("b").child(SExpr::new("c")))
+            .child(SExpr::new("d").quoted("e\n"));
+
+        assert_eq!(sexpr.to_string(), "(a 1\n  (b\n    (c))\n  (d \"e\\n\"))");
+    }
+
+    #[test]
+    fn node_ids_are_optional() {
+        let expr = Expression {
+            id: NodeId(2),
+            kind: ExpressionKind::Binary(BinaryOperator::Add, int(0, 1, 0), int(1, 2, 4)),
+            span: span(0, 5),
+        };
+
+        assert_eq!(
+            expr.dump(&DumpOptions::default()).to_string(),
+            "(binary @0..5 +\n  (int @0..1 1)\n  (int @4..5 2))"
+        );
+        assert_eq!(
+            expr.dump(&DumpOptions { node_ids: true }).to_string(),
+            "(binary #2 @0..5 +\n  (int #0 @0..1 1)\n  (int #1 @4..5 2))"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 pub mod ast;
+pub mod dump;
 pub mod node_id;
 mod traits;
 
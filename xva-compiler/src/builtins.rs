@@ -0,0 +1,86 @@
+//! Functions built into the language, implemented in Rust rather than Xva.
+
+use internment::Intern;
+use xva_parse::error::SyntaxErrorKind;
+
+use crate::{
+    check::{Signature, Ty},
+    eval::{Interpreter, Value},
+};
+
+/// The Rust implementation of a builtin function. It receives the interpreter running the program, and the
+/// arguments, which the checker has already matched against [`Builtin::signature`].
+pub type BuiltinFn = fn(&Interpreter, Vec<Value>) -> Result<Value, SyntaxErrorKind>;
+
+/// A function built into the language. Names bound by the program hide builtins with the same name.
+#[derive(Debug)]
+pub struct Builtin {
+    pub name: &'static str,
+
+    /// The types of the function's parameters and the type it returns. Types can't be interned in a constant, so
+    /// this builds them when asked.
+    pub signature: fn() -> Signature,
+
+    pub call: BuiltinFn,
+}
+
+impl Builtin {
+    /// The type of the function, as the checker sees it.
+    pub fn ty(&self) -> Ty {
+        Ty::Function(Intern::new((self.signature)()))
+    }
+}
+
+// There is only one of each builtin, so two are the same if their names are.
+impl PartialEq for Builtin {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+/// Every builtin function.
+pub const BUILTINS: &[Builtin] = &[Builtin {
+    name: "args",
+    signature: || Signature {
+        params: vec![],
+        ret: Ty::List(Intern::new(Ty::String)),
+    },
+    call: args,
+}];
+
+/// Finds a builtin function by name.
+pub fn lookup(name: &str) -> Option<&'static Builtin> {
+    BUILTINS.iter().find(|builtin| builtin.name == name)
+}
+
+/// `args()`: the arguments passed to the program, e.g. `xva script.xva a b` gives `["a", "b"]`.
+fn args(interpreter: &Interpreter, _: Vec<Value>) -> Result<Value, SyntaxErrorKind> {
+    Ok(Value::List(
+        interpreter
+            .args()
+            .iter()
+            .cloned()
+            .map(Value::String)
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lookup;
+    use crate::eval::{Interpreter, Value};
+
+    #[test]
+    fn args() {
+        let interpreter = Interpreter::new(vec!["a".into(), "b c".into()]);
+        let args = lookup("args").unwrap();
+
+        let value = (args.call)(&interpreter, vec![]).unwrap();
+        assert_eq!(
+            value,
+            Value::List(vec![Value::String("a".into()), Value::String("b c".into())])
+        );
+        assert_eq!(value.to_string(), r#"["a", "b c"]"#);
+        assert_eq!(args.ty().to_string(), "fn() -> [string]");
+    }
+}
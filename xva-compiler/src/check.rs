@@ -0,0 +1,709 @@
+//! Semantic analysis. Compilation stage 3.
+//!
+//! The checker walks the tree produced by the parser, resolves names and infers the type of every expression,
+//! reporting anything that doesn't make sense along the way. It never executes any code.
+//!
+//! Like the lexer and parser, the checker never gives up: once an expression fails to check it is given the type
+//! [`Ty::Error`], which is compatible with everything, so that one mistake doesn't cascade into many.
+
+use std::collections::HashMap;
+
+use internment::Intern;
+use xva_ast::ast::{
+    BinaryOperator, BindingKind, BindingPattern, Block, Expression, ExpressionKind, Function,
+    InterpolatedPart, Item, ItemKind, LiteralKind, Local, Statement, StatementKind, Type, TypeKind,
+    UnaryOperator,
+};
+use xva_parse::{error::SyntaxErrorKind, SyntaxError};
+use xva_span::SourceSpan;
+
+use crate::builtins::{self, Builtin};
+
+/// A type, as understood by the checker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ty {
+    Int,
+    Float,
+    Bool,
+    Char,
+    String,
+    Unit,
+    Never,
+
+    /// A list of values of the same type, like `[string]`.
+    List(Intern<Ty>),
+
+    /// The type of a function, like `fn(int, int) -> int`.
+    Function(Intern<Signature>),
+
+    /// The type of anything that failed to check.
+    Error,
+}
+
+impl Ty {
+    /// Resolves a builtin type from its name, e.g. `int`.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "int" => Some(Ty::Int),
+            "float" => Some(Ty::Float),
+            "bool" => Some(Ty::Bool),
+            "char" => Some(Ty::Char),
+            "string" => Some(Ty::String),
+            "unit" => Some(Ty::Unit),
+            "never" => Some(Ty::Never),
+            _ => None,
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, Ty::Int | Ty::Float)
+    }
+
+    /// Whether a value of type `self` may be used where `other` is expected.
+    fn is_compatible_with(&self, other: &Ty) -> bool {
+        self == other || matches!(self, Ty::Error | Ty::Never) || matches!(other, Ty::Error)
+    }
+}
+
+impl std::fmt::Display for Ty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ty::Int => write!(f, "int"),
+            Ty::Float => write!(f, "float"),
+            Ty::Bool => write!(f, "bool"),
+            Ty::Char => write!(f, "char"),
+            Ty::String => write!(f, "string"),
+            Ty::Unit => write!(f, "unit"),
+            Ty::Never => write!(f, "never"),
+            Ty::List(element) => write!(f, "[{element}]"),
+            Ty::Function(signature) => {
+                let params = signature
+                    .params
+                    .iter()
+                    .map(Ty::to_string)
+                    .collect::<Vec<_>>();
+                write!(f, "fn({})", params.join(", "))?;
+                match signature.ret {
+                    Ty::Unit => Ok(()),
+                    ret => write!(f, " -> {ret}"),
+                }
+            }
+            Ty::Error => write!(f, "{{error}}"),
+        }
+    }
+}
+
+/// The types of a function's parameters, and the type it returns.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Signature {
+    pub params: Vec<Ty>,
+    pub ret: Ty,
+}
+
+/// Type checks a tree. The checker remembers every binding it has seen, so checking several trees one after
+/// another with the same `Checker` lets later trees refer to bindings declared in earlier ones.
+#[derive(Debug, Clone)]
+pub struct Checker {
+    scopes: Vec<HashMap<Intern<String>, Ty>>,
+
+    /// The return type of each function being checked, innermost last.
+    returns: Vec<Ty>,
+    errors: Vec<SyntaxError>,
+}
+
+impl Default for Checker {
+    fn default() -> Self {
+        Self {
+            scopes: vec![HashMap::new()],
+            returns: vec![],
+            errors: vec![],
+        }
+    }
+}
+
+impl Checker {
+    /// Checks each item in turn.
+    ///
+    /// # Returns
+    /// The type of the last item, which is the type of the value the items produce when run, and every error
+    /// found along the way.
+    pub fn check(&mut self, items: &[Item]) -> (Ty, Vec<SyntaxError>) {
+        let mut last = Ty::Unit;
+        for item in items {
+            last = self.item(item);
+        }
+
+        (last, std::mem::take(&mut self.errors))
+    }
+
+    /// Infers the type of a single expression, without binding anything.
+    pub fn type_of(&mut self, expr: &Expression) -> (Ty, Vec<SyntaxError>) {
+        let ty = self.expr(expr);
+        (ty, std::mem::take(&mut self.errors))
+    }
+
+    fn error(&mut self, kind: SyntaxErrorKind, span: SourceSpan) -> Ty {
+        self.errors.push(SyntaxError::new(kind, span));
+        Ty::Error
+    }
+
+    /// Finds the type of the binding with the given name, if it is in scope.
+    pub fn lookup(&self, name: &str) -> Option<Ty> {
+        let name = Intern::from_ref(name);
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&name).copied())
+    }
+
+    /// The names of every binding in scope.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.scopes
+            .iter()
+            .flat_map(|scope| scope.keys())
+            .map(|name| name.as_str())
+    }
+
+    fn bind(&mut self, name: Intern<String>, ty: Ty) {
+        // SAFETY: there is always at least the outermost scope.
+        self.scopes.last_mut().unwrap().insert(name, ty);
+    }
+
+    fn item(&mut self, item: &Item) -> Ty {
+        match &item.kind {
+            ItemKind::Expression(expr) => self.expr(expr),
+            ItemKind::Statement(stmt) => {
+                self.stmt(stmt);
+                Ty::Unit
+            }
+            ItemKind::Module(module) => {
+                for item in &module.items {
+                    self.item(item);
+                }
+                Ty::Unit
+            }
+            ItemKind::Function(function) => {
+                self.function(function);
+                Ty::Unit
+            }
+
+            // The parser has already reported this.
+            ItemKind::Error(_) => Ty::Error,
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Statement) {
+        match &stmt.kind {
+            StatementKind::Local(local) => self.local(local),
+        }
+    }
+
+    fn local(&mut self, local: &Local) {
+        let annotated = local.ty.as_ref().map(|ty| self.ty(ty));
+
+        let ty = match (&local.binding_kind, annotated) {
+            (BindingKind::Inited(expr), Some(annotated)) => {
+                let found = self.expr(expr);
+                if !found.is_compatible_with(&annotated) {
+                    self.error(
+                        SyntaxErrorKind::TypeMismatch {
+                            expected: Intern::new(annotated.to_string()),
+                            found: Intern::new(found.to_string()),
+                        },
+                        expr.span,
+                    );
+                }
+                annotated
+            }
+            (BindingKind::Inited(expr), None) => self.expr(expr),
+            (BindingKind::Declared, Some(annotated)) => annotated,
+            (BindingKind::Declared, None) => {
+                self.error(SyntaxErrorKind::TypeAnnotationNeeded, local.span)
+            }
+        };
+
+        match &local.pattern {
+            BindingPattern::Identifier(ident) => self.bind(ident.name, ty),
+        }
+    }
+
+    fn function(&mut self, function: &Function) {
+        let params = function
+            .params
+            .iter()
+            .map(|param| self.ty(&param.ty))
+            .collect::<Vec<_>>();
+        let ret = function
+            .return_ty
+            .as_ref()
+            .map_or(Ty::Unit, |ty| self.ty(ty));
+        let ty = Ty::Function(Intern::new(Signature {
+            params: params.clone(),
+            ret,
+        }));
+        self.bind(function.name.name, ty);
+
+        // A function can't see the locals around it, only the outermost scope. Its own name is bound again, so that
+        // functions declared inside other functions can call themselves too.
+        let enclosing = self.scopes.split_off(1);
+        let scope = std::iter::once((function.name.name, ty))
+            .chain(
+                function
+                    .params
+                    .iter()
+                    .zip(params)
+                    .map(|(param, ty)| (param.name.name, ty)),
+            )
+            .collect();
+        self.scopes.push(scope);
+        self.returns.push(ret);
+
+        let last = self.block(&function.body);
+
+        self.returns.pop();
+        self.scopes.truncate(1);
+        self.scopes.extend(enclosing);
+
+        // Without a return type, whatever the body ends with is thrown away.
+        let (found, span) = last;
+        if function.return_ty.is_some() && !found.is_compatible_with(&ret) {
+            self.error(
+                SyntaxErrorKind::TypeMismatch {
+                    expected: Intern::new(ret.to_string()),
+                    found: Intern::new(found.to_string()),
+                },
+                span,
+            );
+        }
+    }
+
+    /// Checks the items of a block in a scope of their own, so that nothing bound inside it can be seen after it.
+    ///
+    /// # Returns
+    /// The type of the last item and its span, or `unit` and the span of the whole block if it's empty.
+    fn block(&mut self, block: &Block) -> (Ty, SourceSpan) {
+        self.scopes.push(HashMap::new());
+
+        let mut last = (Ty::Unit, block.span);
+        for item in &block.items {
+            last = (self.item(item), item.span);
+        }
+
+        self.scopes.pop();
+        last
+    }
+
+    fn ty(&mut self, ty: &Type) -> Ty {
+        match &ty.kind {
+            TypeKind::Unit => Ty::Unit,
+            TypeKind::Never => Ty::Never,
+            TypeKind::Infer => Ty::Error,
+            TypeKind::Named(name) => {
+                let path = name.to_string();
+                match Ty::from_name(&path) {
+                    Some(ty) => ty,
+                    None => self.error(SyntaxErrorKind::UnknownType(Intern::new(path)), name.span),
+                }
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &Expression) -> Ty {
+        match &expr.kind {
+            // Suffixes are only checked by the lexer and parser, for the range of their type. Every integer is an
+            // `int` and every float a `float` here, whatever its suffix.
+            ExpressionKind::Literal(lit) => match lit {
+                LiteralKind::Integer(..) => Ty::Int,
+                LiteralKind::Boolean(_) => Ty::Bool,
+                LiteralKind::Char(_) => Ty::Char,
+                LiteralKind::Float(..) => Ty::Float,
+                LiteralKind::String(_) => Ty::String,
+            },
+
+            // There are no modules yet, so only a path with a single segment can name anything. Builtins are only
+            // found when nothing in scope has the same name.
+            ExpressionKind::Path(name) => {
+                let name = name.to_string();
+                match self
+                    .lookup(&name)
+                    .or_else(|| builtins::lookup(&name).map(Builtin::ty))
+                {
+                    Some(ty) => ty,
+                    None => self.error(
+                        SyntaxErrorKind::UnresolvedName(Intern::new(name)),
+                        expr.span,
+                    ),
+                }
+            }
+
+            ExpressionKind::Unary(op, operand) => {
+                let operand_ty = self.expr(operand);
+                let result = match (op, operand_ty) {
+                    (_, Ty::Error) => Some(Ty::Error),
+                    (UnaryOperator::Negation, ty) if ty.is_numeric() => Some(ty),
+                    (UnaryOperator::Not, Ty::Bool) => Some(Ty::Bool),
+                    _ => None,
+                };
+
+                result.unwrap_or_else(|| {
+                    self.error(
+                        SyntaxErrorKind::InvalidUnaryOperand {
+                            op: Intern::new(op.to_string()),
+                            operand: Intern::new(operand_ty.to_string()),
+                        },
+                        expr.span,
+                    )
+                })
+            }
+
+            ExpressionKind::Binary(op, lhs, rhs) => {
+                let (lhs_ty, rhs_ty) = (self.expr(lhs), self.expr(rhs));
+                match Self::binary(op, lhs_ty, rhs_ty) {
+                    Some(ty) => ty,
+                    None => self.error(
+                        SyntaxErrorKind::InvalidBinaryOperands {
+                            op: Intern::new(op.to_string()),
+                            lhs: Intern::new(lhs_ty.to_string()),
+                            rhs: Intern::new(rhs_ty.to_string()),
+                        },
+                        expr.span,
+                    ),
+                }
+            }
+
+            // Every type can be turned into a string, so only the parts themselves need checking.
+            ExpressionKind::Interpolated(parts) => {
+                let tys = parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        InterpolatedPart::Text(..) => None,
+                        InterpolatedPart::Expression(expr) => Some(self.expr(expr)),
+                    })
+                    .collect::<Vec<_>>();
+
+                match tys.contains(&Ty::Error) {
+                    true => Ty::Error,
+                    false => Ty::String,
+                }
+            }
+
+            ExpressionKind::Call(callee, args) => {
+                let callee_ty = self.expr(callee);
+                let arg_tys = args.iter().map(|arg| self.expr(arg)).collect::<Vec<_>>();
+
+                let signature = match callee_ty {
+                    Ty::Function(signature) => signature,
+                    Ty::Error => return Ty::Error,
+                    ty => {
+                        return self.error(
+                            SyntaxErrorKind::NotCallable(Intern::new(ty.to_string())),
+                            callee.span,
+                        )
+                    }
+                };
+
+                // What the function returns is known even if it's called wrongly.
+                if args.len() != signature.params.len() {
+                    self.error(
+                        SyntaxErrorKind::WrongArgumentCount {
+                            expected: signature.params.len(),
+                            found: args.len(),
+                        },
+                        expr.span,
+                    );
+                    return signature.ret;
+                }
+
+                for ((arg, found), expected) in args.iter().zip(arg_tys).zip(&signature.params) {
+                    if !found.is_compatible_with(expected) {
+                        self.error(
+                            SyntaxErrorKind::TypeMismatch {
+                                expected: Intern::new(expected.to_string()),
+                                found: Intern::new(found.to_string()),
+                            },
+                            arg.span,
+                        );
+                    }
+                }
+
+                signature.ret
+            }
+
+            ExpressionKind::Return(value) => {
+                let found = value.as_ref().map_or(Ty::Unit, |value| self.expr(value));
+                match self.returns.last().copied() {
+                    Some(expected) if !found.is_compatible_with(&expected) => {
+                        self.error(
+                            SyntaxErrorKind::TypeMismatch {
+                                expected: Intern::new(expected.to_string()),
+                                found: Intern::new(found.to_string()),
+                            },
+                            value.as_ref().map_or(expr.span, |value| value.span),
+                        );
+                    }
+                    Some(_) => (),
+                    None => {
+                        self.error(SyntaxErrorKind::ReturnOutsideFunction, expr.span);
+                    }
+                }
+
+                // Nothing after a return is ever reached.
+                Ty::Never
+            }
+
+            ExpressionKind::Block(block) => self.block(block).0,
+        }
+    }
+
+    /// The result type of a binary operation, or `None` if the operator can't be applied to the operands.
+    fn binary(op: &BinaryOperator, lhs: Ty, rhs: Ty) -> Option<Ty> {
+        use BinaryOperator::*;
+
+        if lhs == Ty::Error || rhs == Ty::Error {
+            return Some(Ty::Error);
+        }
+
+        match op {
+            Add | Subtract | Multiply | Divide | Modulo | Power
+                if lhs == rhs && lhs.is_numeric() =>
+            {
+                Some(lhs)
+            }
+            BitwiseAnd | BitwiseOr | BitwiseXor | LeftShift | RightShift
+                if lhs == Ty::Int && rhs == Ty::Int =>
+            {
+                Some(Ty::Int)
+            }
+            LogicalAnd | LogicalOr if lhs == Ty::Bool && rhs == Ty::Bool => Some(Ty::Bool),
+            LessThan | GreaterThan | LessThanEqual | GreaterThanEqual
+                if lhs == rhs && (lhs.is_numeric() || lhs == Ty::Char) =>
+            {
+                Some(Ty::Bool)
+            }
+            Equal | NotEqual if lhs == rhs => Some(Ty::Bool),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use internment::Intern;
+    use xva_parse::{error::SyntaxErrorKind, parser::parse, SyntaxError};
+
+    use super::{Checker, Ty};
+
+    fn check(input: &str) -> Vec<SyntaxError> {
+        let (tree, errors) = parse(input, 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+
+        Checker::default().check(&tree).1
+    }
+
+    fn kinds(errors: &[SyntaxError]) -> Vec<&SyntaxErrorKind> {
+        errors.iter().map(SyntaxError::kind).collect()
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert!(check("1 + 2 * 3").is_empty());
+        assert!(check("1.5 - 2.0").is_empty());
+    }
+
+    #[test]
+    fn mixed_arithmetic() {
+        assert_eq!(
+            kinds(&check("1 + 2.0")),
+            [&SyntaxErrorKind::InvalidBinaryOperands {
+                op: Intern::from_ref("+"),
+                lhs: Intern::from_ref("int"),
+                rhs: Intern::from_ref("float")
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_negation() {
+        assert_eq!(
+            kinds(&check("-true")),
+            [&SyntaxErrorKind::InvalidUnaryOperand {
+                op: Intern::from_ref("-"),
+                operand: Intern::from_ref("bool")
+            }]
+        );
+    }
+
+    #[test]
+    fn annotated_local() {
+        assert!(check("let x: int = 5").is_empty());
+        assert_eq!(
+            kinds(&check("let x: bool = 5")),
+            [&SyntaxErrorKind::TypeMismatch {
+                expected: Intern::from_ref("bool"),
+                found: Intern::from_ref("int")
+            }]
+        );
+    }
+
+    #[test]
+    fn unknown_type() {
+        assert_eq!(
+            kinds(&check("var x: thing")),
+            [&SyntaxErrorKind::UnknownType(Intern::from_ref("thing"))]
+        );
+    }
+
+    #[test]
+    fn errors_do_not_cascade() {
+        // Only the innermost expression is reported.
+        assert_eq!(check("(1 + true) * 2").len(), 1);
+    }
+
+    #[test]
+    fn interpolation() {
+        let (tree, _) = parse(r#""{1 + 2} and {true}""#, 0u32.into(), false);
+        assert_eq!(Checker::default().check(&tree).0, Ty::String);
+
+        // The parts are checked, but a bad part is only reported once.
+        assert_eq!(check(r#""{1 + true}""#).len(), 1);
+    }
+
+    #[test]
+    fn last_item_is_type() {
+        let (tree, _) = parse("let x = 5\nnot true", 0u32.into(), false);
+        assert_eq!(Checker::default().check(&tree).0, Ty::Bool);
+    }
+
+    #[test]
+    fn blocks() {
+        let (tree, _) = parse(
+            "let x = { let y = 1; y + 1 }\n{ x; 'c' }",
+            0u32.into(),
+            false,
+        );
+        let (ty, errors) = Checker::default().check(&tree);
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(ty, Ty::Char);
+        assert!(check("fn f() -> int {\n  let a = true\n  { let a = 1; a }\n}").is_empty());
+        assert_eq!(
+            kinds(&check("{ let y = 1 }\ny")),
+            [&SyntaxErrorKind::UnresolvedName(Intern::from_ref("y"))]
+        );
+    }
+
+    #[test]
+    fn paths() {
+        let (tree, _) = parse("let x = 'c'\nx", 0u32.into(), false);
+        assert_eq!(Checker::default().check(&tree).0, Ty::Char);
+        assert_eq!(
+            kinds(&check("let y = x + 1")),
+            [&SyntaxErrorKind::UnresolvedName(Intern::from_ref("x"))]
+        );
+    }
+
+    #[test]
+    fn functions() {
+        let (tree, _) = parse(
+            "fn add(a: int, b: int) -> int { a + b }\nadd(1, 2)",
+            0u32.into(),
+            false,
+        );
+        let mut checker = Checker::default();
+        let (ty, errors) = checker.check(&tree);
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(ty, Ty::Int);
+        assert_eq!(
+            checker.lookup("add").unwrap().to_string(),
+            "fn(int, int) -> int"
+        );
+
+        assert!(check("fn f() { 1 }\nfn g(n: int) -> int { g(n) }").is_empty());
+        assert_eq!(
+            kinds(&check("fn f() -> bool { 1 }")),
+            [&SyntaxErrorKind::TypeMismatch {
+                expected: Intern::from_ref("bool"),
+                found: Intern::from_ref("int")
+            }]
+        );
+    }
+
+    #[test]
+    fn functions_only_see_the_outermost_scope() {
+        assert!(check("let x = 1\nfn f() -> int { x }").is_empty());
+        assert_eq!(
+            kinds(&check("fn f(a: int) { fn g() -> int { a } }")),
+            [&SyntaxErrorKind::UnresolvedName(Intern::from_ref("a"))]
+        );
+        assert_eq!(
+            kinds(&check("fn f(a: int) {}\na")),
+            [&SyntaxErrorKind::UnresolvedName(Intern::from_ref("a"))]
+        );
+    }
+
+    #[test]
+    fn calls() {
+        assert_eq!(
+            kinds(&check("fn f(a: int) {}\nf(1, 2)")),
+            [&SyntaxErrorKind::WrongArgumentCount {
+                expected: 1,
+                found: 2
+            }]
+        );
+        assert_eq!(
+            kinds(&check("fn f(a: int, b: bool) {}\nf(1, 2)")),
+            [&SyntaxErrorKind::TypeMismatch {
+                expected: Intern::from_ref("bool"),
+                found: Intern::from_ref("int")
+            }]
+        );
+        assert_eq!(
+            kinds(&check("let x = 1\nx()")),
+            [&SyntaxErrorKind::NotCallable(Intern::from_ref("int"))]
+        );
+
+        // A call with the wrong arguments still has the type the function returns.
+        assert_eq!(check("fn f() -> int { 1 }\nf(1) + 1").len(), 1);
+    }
+
+    #[test]
+    fn returns() {
+        assert!(check("fn f() -> int { return 1 }").is_empty());
+        assert!(check("fn f() { return }").is_empty());
+        assert_eq!(
+            kinds(&check("fn f() -> int { return }")),
+            [&SyntaxErrorKind::TypeMismatch {
+                expected: Intern::from_ref("int"),
+                found: Intern::from_ref("unit")
+            }]
+        );
+        assert_eq!(
+            kinds(&check("return 1")),
+            [&SyntaxErrorKind::ReturnOutsideFunction]
+        );
+    }
+
+    #[test]
+    fn builtins() {
+        let (tree, _) = parse("args()", 0u32.into(), false);
+        assert_eq!(Checker::default().check(&tree).0.to_string(), "[string]");
+        assert_eq!(
+            kinds(&check("args(1)")),
+            [&SyntaxErrorKind::WrongArgumentCount {
+                expected: 0,
+                found: 1
+            }]
+        );
+
+        // A binding hides a builtin with the same name.
+        let (tree, _) = parse("let args = 1\nargs", 0u32.into(), false);
+        assert_eq!(Checker::default().check(&tree).0, Ty::Int);
+    }
+
+    #[test]
+    fn bindings_persist() {
+        let mut checker = Checker::default();
+        let (tree, _) = parse("let x = 5.0", 0u32.into(), false);
+        assert!(checker.check(&tree).1.is_empty());
+        assert_eq!(checker.lookup("x"), Some(Ty::Float));
+    }
+}
@@ -0,0 +1,157 @@
+//! Rendering diagnostics, either for people or for other programs.
+
+use std::str::FromStr;
+
+use serde_json::{json, Value};
+use xva_parse::{error::Severity, SyntaxError};
+use xva_span::{SourceMap, SourceSpan};
+
+/// How diagnostics are written out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// Coloured reports with source snippets, for reading in a terminal.
+    #[default]
+    Human,
+
+    /// One JSON object per diagnostic, each on its own line, for editors and CI tools.
+    Json,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "Unknown error format `{s}`, expected `human` or `json`"
+            )),
+        }
+    }
+}
+
+/// Converts a diagnostic into a JSON object, resolving its spans against the source map.
+///
+/// ```json
+/// {
+///   "severity": "error",
+///   "code": 204,
+///   "message": "Cannot find thing in this scope",
+///   "span": { "file": "main.xva", "start": 8, "end": 13, "line": 1, "column": 9, "end_line": 1, "end_column": 14 },
+///   "labels": [{ "message": "Not found", "span": { ... } }]
+/// }
+/// ```
+pub fn to_json(error: &SyntaxError, source_map: &SourceMap) -> Value {
+    let labels = error
+        .labels()
+        .into_iter()
+        .map(|(span, message)| {
+            json!({
+                "message": message,
+                "span": span_to_json(span, source_map),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "severity": error.severity().to_string(),
+        "code": error.code(),
+        "message": error.message(),
+        "span": span_to_json(error.span(), source_map),
+        "labels": labels,
+    })
+}
+
+/// Converts an error that isn't about any source text, like a file that couldn't be read, into a JSON object shaped
+/// like a diagnostic. It has no code, span or labels.
+pub fn message_to_json(message: &str) -> Value {
+    json!({
+        "severity": Severity::Error.to_string(),
+        "code": null,
+        "message": message,
+        "span": null,
+        "labels": [],
+    })
+}
+
+fn span_to_json(span: SourceSpan, source_map: &SourceMap) -> Value {
+    let src = span.src();
+    let range = span.range();
+
+    // Lines and columns are left out if the span doesn't fit the file, rather than guessed.
+    let (line, column) = source_map.line_col(&src, range.start()).unzip();
+    let (end_line, end_column) = source_map.line_col(&src, range.end()).unzip();
+
+    json!({
+        "file": source_map.get_name(&src),
+        "start": range.start(),
+        "end": range.end(),
+        "line": line,
+        "column": column,
+        "end_line": end_line,
+        "end_column": end_column,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use xva_span::SourceMap;
+
+    use super::{message_to_json, to_json, ErrorFormat};
+
+    #[test]
+    fn error_format_from_str() {
+        assert_eq!("human".parse(), Ok(ErrorFormat::Human));
+        assert_eq!("json".parse(), Ok(ErrorFormat::Json));
+        assert!("xml".parse::<ErrorFormat>().is_err());
+    }
+
+    #[test]
+    fn invalid_binary_operands() {
+        let mut source_map = SourceMap::default();
+        let src_id =
+            source_map.load_virtual("main.xva".into(), "let x = 1\nlet y = 2 + true".into());
+
+        let (tree, errors) =
+            xva_parse::parser::parse(source_map.get_raw(&src_id).unwrap().as_ref(), src_id, false);
+        assert!(errors.is_empty());
+
+        let errors = crate::check::Checker::default().check(&tree).1;
+        let span = json!({
+            "file": "main.xva",
+            "start": 18,
+            "end": 26,
+            "line": 2,
+            "column": 9,
+            "end_line": 2,
+            "end_column": 17,
+        });
+
+        assert_eq!(
+            to_json(&errors[0], &source_map),
+            json!({
+                "severity": "error",
+                "code": 202,
+                "message": "The operator + cannot be applied to types int and bool",
+                "span": span,
+                "labels": [{ "message": "These are of types int and bool", "span": span }],
+            })
+        );
+    }
+
+    #[test]
+    fn messages() {
+        assert_eq!(
+            message_to_json("No such file or directory: main.xva"),
+            json!({
+                "severity": "error",
+                "code": null,
+                "message": "No such file or directory: main.xva",
+                "span": null,
+                "labels": [],
+            })
+        );
+    }
+}
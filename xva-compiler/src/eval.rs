@@ -0,0 +1,584 @@
+//! The tree-walking interpreter. Compilation stage 4.
+//!
+//! The interpreter executes a tree that has already passed the checker, so it assumes every expression is well
+//! typed. The only errors it reports are those that can't be known before running, like dividing by zero.
+
+use std::{collections::HashMap, sync::Arc};
+
+use internment::Intern;
+use xva_ast::ast::{
+    BinaryOperator, BindingKind, BindingPattern, Block, Expression, ExpressionKind, Function,
+    InterpolatedPart, Item, ItemKind, LiteralKind, Local, Statement, StatementKind, UnaryOperator,
+};
+use xva_parse::{error::SyntaxErrorKind, SyntaxError};
+
+use crate::builtins::{self, Builtin};
+
+/// A value produced by evaluating an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    String(String),
+    List(Vec<Value>),
+    Function(FunctionRef),
+    Builtin(&'static Builtin),
+    Unit,
+}
+
+/// A function declared in the program. Two references are only equal if they're to the same declaration.
+#[derive(Debug, Clone)]
+pub struct FunctionRef(Arc<Function>);
+
+impl PartialEq for FunctionRef {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Why evaluation stopped before reaching the end of an expression.
+enum Unwind {
+    Error(SyntaxError),
+
+    /// A `return`, with the value being returned to the caller.
+    Return(Value),
+}
+
+impl From<SyntaxError> for Unwind {
+    fn from(err: SyntaxError) -> Self {
+        Unwind::Error(err)
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(i) => write!(f, "{i}"),
+            Value::Float(fl) => write!(f, "{fl:?}"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Char(c) => write!(f, "{c}"),
+            Value::String(s) => write!(f, "{s}"),
+            Value::List(values) => {
+                // Strings are quoted inside lists, so that the boundaries between them can be seen.
+                let values = values
+                    .iter()
+                    .map(|value| match value {
+                        Value::String(s) => format!("{s:?}"),
+                        value => value.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "[{}]", values.join(", "))
+            }
+            Value::Function(function) => write!(f, "<fn {}>", function.0.name.name),
+            Value::Builtin(builtin) => write!(f, "<fn {}>", builtin.name),
+            Value::Unit => write!(f, "()"),
+        }
+    }
+}
+
+/// How deeply calls can be nested before running a program stops with an error, rather than overflowing the stack.
+pub const MAX_CALL_DEPTH: usize = 1000;
+
+/// The stack programs run on. Each call takes a few kilobytes of it in a debug build, so this leaves plenty of room
+/// for [`MAX_CALL_DEPTH`] calls. Most of it is never touched, so it costs nothing until it's needed.
+const STACK_SIZE: usize = MAX_CALL_DEPTH * 64 * 1024;
+
+/// Executes trees. Like the [`Checker`](crate::check::Checker), the interpreter keeps its bindings between
+/// calls to [`Interpreter::run`].
+#[derive(Debug, Clone)]
+pub struct Interpreter {
+    scopes: Vec<HashMap<Intern<String>, Value>>,
+
+    /// The arguments passed to the program being run.
+    args: Vec<String>,
+
+    /// The number of calls currently being executed.
+    depth: usize,
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl Interpreter {
+    pub fn new(args: Vec<String>) -> Self {
+        Self {
+            scopes: vec![HashMap::new()],
+            args,
+            depth: 0,
+        }
+    }
+
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Executes each item in turn.
+    ///
+    /// # Returns
+    /// The value of the last item, or the first runtime error, after which nothing else is executed.
+    pub fn run(&mut self, items: &[Item]) -> Result<Value, SyntaxError> {
+        // The items run on a thread of their own, since the stack of the calling thread might be too small for
+        // `MAX_CALL_DEPTH` calls.
+        std::thread::scope(|scope| {
+            std::thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, || self.items(items))
+                .expect("Could not start a thread to run the program on")
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+        // The checker doesn't allow a return outside of a function, so only errors unwind this far.
+        .or_else(|unwind| match unwind {
+            Unwind::Error(err) => Err(err),
+            Unwind::Return(value) => Ok(value),
+        })
+    }
+
+    fn items(&mut self, items: &[Item]) -> Result<Value, Unwind> {
+        let mut last = Value::Unit;
+        for item in items {
+            last = self.item(item)?;
+        }
+
+        Ok(last)
+    }
+
+    /// Finds the value of the binding with the given name, if it is in scope.
+    pub fn lookup(&self, name: &str) -> Option<&Value> {
+        let name = Intern::from_ref(name);
+        self.scopes.iter().rev().find_map(|scope| scope.get(&name))
+    }
+
+    fn bind(&mut self, name: Intern<String>, value: Value) {
+        // SAFETY: there is always at least the outermost scope.
+        self.scopes.last_mut().unwrap().insert(name, value);
+    }
+
+    fn item(&mut self, item: &Item) -> Result<Value, Unwind> {
+        match &item.kind {
+            ItemKind::Expression(expr) => self.expr(expr),
+            ItemKind::Statement(stmt) => {
+                self.stmt(stmt)?;
+                Ok(Value::Unit)
+            }
+            ItemKind::Module(module) => {
+                self.items(&module.items)?;
+                Ok(Value::Unit)
+            }
+            ItemKind::Function(function) => {
+                let function = FunctionRef(Arc::new(function.clone()));
+                self.bind(function.0.name.name, Value::Function(function));
+                Ok(Value::Unit)
+            }
+            ItemKind::Error(_) => Ok(Value::Unit),
+        }
+    }
+
+    fn call(&mut self, function: FunctionRef, args: Vec<Value>) -> Result<Value, Unwind> {
+        let decl = Arc::clone(&function.0);
+
+        // Like in the checker, a function only sees the outermost scope, its own name and its parameters.
+        let enclosing = self.scopes.split_off(1);
+        let scope = std::iter::once((decl.name.name, Value::Function(function)))
+            .chain(decl.params.iter().map(|param| param.name.name).zip(args))
+            .collect();
+        self.scopes.push(scope);
+        self.depth += 1;
+
+        let result = self.block(&decl.body);
+
+        self.depth -= 1;
+        self.scopes.truncate(1);
+        self.scopes.extend(enclosing);
+
+        let value = match result {
+            Ok(value) | Err(Unwind::Return(value)) => value,
+            Err(err) => return Err(err),
+        };
+
+        // Without a return type, whatever the body ends with is thrown away.
+        match decl.return_ty {
+            Some(_) => Ok(value),
+            None => Ok(Value::Unit),
+        }
+    }
+
+    /// Executes the items of a block in a scope of their own, which is gone again afterwards, even if a runtime error
+    /// or a `return` stopped it part of the way through.
+    fn block(&mut self, block: &Block) -> Result<Value, Unwind> {
+        self.scopes.push(HashMap::new());
+        let result = self.items(&block.items);
+        self.scopes.pop();
+        result
+    }
+
+    fn stmt(&mut self, stmt: &Statement) -> Result<(), Unwind> {
+        match &stmt.kind {
+            StatementKind::Local(local) => self.local(local),
+        }
+    }
+
+    fn local(&mut self, local: &Local) -> Result<(), Unwind> {
+        // Declared locals have nothing to bind until they're assigned.
+        if let BindingKind::Inited(expr) = &local.binding_kind {
+            let value = self.expr(expr)?;
+            match &local.pattern {
+                BindingPattern::Identifier(ident) => self.bind(ident.name, value),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn expr(&mut self, expr: &Expression) -> Result<Value, Unwind> {
+        let error = |kind| Unwind::Error(SyntaxError::new(kind, expr.span));
+
+        match &expr.kind {
+            ExpressionKind::Literal(lit) => Ok(match lit {
+                LiteralKind::Integer(i, _) => {
+                    Value::Int(i128::try_from(*i).map_err(|_| error(SyntaxErrorKind::Overflow))?)
+                }
+                LiteralKind::Boolean(b) => Value::Bool(*b),
+                LiteralKind::Char(c) => Value::Char(*c),
+                LiteralKind::Float(f, _) => Value::Float(*f),
+                LiteralKind::String(s) => Value::String(s.clone()),
+            }),
+
+            // There are no modules yet, so only a path with a single segment can name anything.
+            ExpressionKind::Path(name) => {
+                let name = name.to_string();
+                self.lookup(&name)
+                    .cloned()
+                    .or_else(|| builtins::lookup(&name).map(Value::Builtin))
+                    .ok_or_else(|| error(SyntaxErrorKind::UnresolvedName(Intern::new(name))))
+            }
+
+            // The smallest `int` is written by negating a literal one larger than the largest, which would overflow
+            // on its own, so negated literals are evaluated in one go.
+            ExpressionKind::Unary(UnaryOperator::Negation, operand)
+                if matches!(
+                    operand.kind,
+                    ExpressionKind::Literal(LiteralKind::Integer(..))
+                ) =>
+            {
+                let ExpressionKind::Literal(LiteralKind::Integer(i, _)) = operand.kind else {
+                    unreachable!()
+                };
+                0i128
+                    .checked_sub_unsigned(i)
+                    .map(Value::Int)
+                    .ok_or_else(|| error(SyntaxErrorKind::Overflow))
+            }
+
+            ExpressionKind::Unary(op, operand) => match (op, self.expr(operand)?) {
+                (UnaryOperator::Negation, Value::Int(i)) => i
+                    .checked_neg()
+                    .map(Value::Int)
+                    .ok_or_else(|| error(SyntaxErrorKind::Overflow)),
+                (UnaryOperator::Negation, Value::Float(f)) => Ok(Value::Float(-f)),
+                (UnaryOperator::Not, Value::Bool(b)) => Ok(Value::Bool(!b)),
+                (op, value) => unreachable!("Unchecked unary operation: {op}{value:?}"),
+            },
+
+            // `and` and `or` short-circuit, so the right hand side might never be evaluated.
+            ExpressionKind::Binary(BinaryOperator::LogicalAnd, lhs, rhs) => {
+                match self.expr(lhs)? {
+                    Value::Bool(false) => Ok(Value::Bool(false)),
+                    _ => self.expr(rhs),
+                }
+            }
+            ExpressionKind::Binary(BinaryOperator::LogicalOr, lhs, rhs) => match self.expr(lhs)? {
+                Value::Bool(true) => Ok(Value::Bool(true)),
+                _ => self.expr(rhs),
+            },
+
+            ExpressionKind::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (self.expr(lhs)?, self.expr(rhs)?);
+                Self::binary(op, lhs, rhs).map_err(error)
+            }
+
+            ExpressionKind::Interpolated(parts) => {
+                let mut s = String::new();
+                for part in parts {
+                    match part {
+                        InterpolatedPart::Text(text, _) => s.push_str(text),
+                        InterpolatedPart::Expression(expr) => {
+                            s.push_str(&self.expr(expr)?.to_string())
+                        }
+                    }
+                }
+                Ok(Value::String(s))
+            }
+
+            ExpressionKind::Call(callee, args) => {
+                let callee = self.expr(callee)?;
+                let args = args
+                    .iter()
+                    .map(|arg| self.expr(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match callee {
+                    Value::Function(_) if self.depth == MAX_CALL_DEPTH => {
+                        Err(error(SyntaxErrorKind::RecursionLimit(MAX_CALL_DEPTH)))
+                    }
+                    Value::Function(function) => self.call(function, args),
+                    Value::Builtin(builtin) => (builtin.call)(self, args).map_err(error),
+                    value => unreachable!("Unchecked call of {value:?}"),
+                }
+            }
+
+            ExpressionKind::Return(value) => {
+                let value = match value {
+                    Some(value) => self.expr(value)?,
+                    None => Value::Unit,
+                };
+                Err(Unwind::Return(value))
+            }
+
+            ExpressionKind::Block(block) => self.block(block),
+        }
+    }
+
+    fn binary(op: &BinaryOperator, lhs: Value, rhs: Value) -> Result<Value, SyntaxErrorKind> {
+        use BinaryOperator::*;
+
+        let overflow =
+            |result: Option<i128>| result.map(Value::Int).ok_or(SyntaxErrorKind::Overflow);
+
+        Ok(match (op, lhs, rhs) {
+            (Add, Value::Int(l), Value::Int(r)) => overflow(l.checked_add(r))?,
+            (Subtract, Value::Int(l), Value::Int(r)) => overflow(l.checked_sub(r))?,
+            (Multiply, Value::Int(l), Value::Int(r)) => overflow(l.checked_mul(r))?,
+            (Divide | Modulo, Value::Int(_), Value::Int(0)) => {
+                return Err(SyntaxErrorKind::DivisionByZero)
+            }
+            (Divide, Value::Int(l), Value::Int(r)) => overflow(l.checked_div(r))?,
+            (Modulo, Value::Int(l), Value::Int(r)) => overflow(l.checked_rem(r))?,
+            (Power, Value::Int(_), Value::Int(r)) if r < 0 => {
+                return Err(SyntaxErrorKind::NegativeExponent)
+            }
+            (Power, Value::Int(l), Value::Int(r)) => {
+                overflow(u32::try_from(r).ok().and_then(|r| l.checked_pow(r)))?
+            }
+            (BitwiseAnd, Value::Int(l), Value::Int(r)) => Value::Int(l & r),
+            (BitwiseOr, Value::Int(l), Value::Int(r)) => Value::Int(l | r),
+            (BitwiseXor, Value::Int(l), Value::Int(r)) => Value::Int(l ^ r),
+            (LeftShift, Value::Int(l), Value::Int(r)) => {
+                overflow(u32::try_from(r).ok().and_then(|r| l.checked_shl(r)))?
+            }
+            (RightShift, Value::Int(l), Value::Int(r)) => {
+                overflow(u32::try_from(r).ok().and_then(|r| l.checked_shr(r)))?
+            }
+
+            (Add, Value::Float(l), Value::Float(r)) => Value::Float(l + r),
+            (Subtract, Value::Float(l), Value::Float(r)) => Value::Float(l - r),
+            (Multiply, Value::Float(l), Value::Float(r)) => Value::Float(l * r),
+            (Divide, Value::Float(l), Value::Float(r)) => Value::Float(l / r),
+            (Modulo, Value::Float(l), Value::Float(r)) => Value::Float(l % r),
+            (Power, Value::Float(l), Value::Float(r)) => Value::Float(l.powf(r)),
+
+            (Equal, l, r) => Value::Bool(l == r),
+            (NotEqual, l, r) => Value::Bool(l != r),
+            (LessThan, l, r) => Value::Bool(l.partial_cmp(&r).is_some_and(|o| o.is_lt())),
+            (GreaterThan, l, r) => Value::Bool(l.partial_cmp(&r).is_some_and(|o| o.is_gt())),
+            (LessThanEqual, l, r) => Value::Bool(l.partial_cmp(&r).is_some_and(|o| o.is_le())),
+            (GreaterThanEqual, l, r) => Value::Bool(l.partial_cmp(&r).is_some_and(|o| o.is_ge())),
+
+            (op, l, r) => unreachable!("Unchecked binary operation: {l:?} {op} {r:?}"),
+        })
+    }
+}
+
+// Only values of the same type are ever compared, the checker makes sure of that.
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Value::Int(l), Value::Int(r)) => l.partial_cmp(r),
+            (Value::Float(l), Value::Float(r)) => l.partial_cmp(r),
+            (Value::Char(l), Value::Char(r)) => l.partial_cmp(r),
+            (Value::String(l), Value::String(r)) => l.partial_cmp(r),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use internment::Intern;
+    use xva_parse::{error::SyntaxErrorKind, parser::parse};
+
+    use super::{Interpreter, Value, MAX_CALL_DEPTH};
+
+    fn run(input: &str) -> Result<Value, SyntaxErrorKind> {
+        let (tree, errors) = parse(input, 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+
+        Interpreter::default()
+            .run(&tree)
+            .map_err(|err| err.kind().clone())
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(run("1 + 2 * 3"), Ok(Value::Int(7)));
+        assert_eq!(run("(1 + 2) * 3"), Ok(Value::Int(9)));
+        assert_eq!(run("-4 % 3"), Ok(Value::Int(-1)));
+        assert_eq!(run("1.5 * 2.0"), Ok(Value::Float(3.0)));
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(run("1 / 0"), Err(SyntaxErrorKind::DivisionByZero));
+        assert_eq!(run("1 % 0"), Err(SyntaxErrorKind::DivisionByZero));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            run("170141183460469231731687303715884105727 + 1"),
+            Err(SyntaxErrorKind::Overflow)
+        );
+        assert_eq!(
+            run("340282366920938463463374607431768211455u128"),
+            Err(SyntaxErrorKind::Overflow)
+        );
+    }
+
+    #[test]
+    fn smallest_int() {
+        assert_eq!(
+            run("-170141183460469231731687303715884105728"),
+            Ok(Value::Int(i128::MIN))
+        );
+        assert_eq!(
+            run("-170141183460469231731687303715884105728i128"),
+            Ok(Value::Int(i128::MIN))
+        );
+        assert_eq!(run("- -3"), Ok(Value::Int(3)));
+    }
+
+    #[test]
+    fn last_item_is_result() {
+        assert_eq!(run("let x = 1; 2\n3"), Ok(Value::Int(3)));
+        assert_eq!(run("let x = 1"), Ok(Value::Unit));
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(run("let x = 2\nlet y = x * 3\ny + x"), Ok(Value::Int(8)));
+        assert_eq!(
+            run("std.io"),
+            Err(SyntaxErrorKind::UnresolvedName(Intern::from_ref("std.io")))
+        );
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(
+            run("fn add(a: int, b: int) -> int { a + b }\nadd(1, add(2, 3))"),
+            Ok(Value::Int(6))
+        );
+        assert_eq!(run("fn f() { 1 }\nf()"), Ok(Value::Unit));
+        assert_eq!(
+            run("fn f(n: int) -> int {\n  fn double(n: int) -> int { n * 2 }\n  double(n) + 1\n}\nf(4)"),
+            Ok(Value::Int(9))
+        );
+        assert_eq!(
+            run("fn f() -> int { 1 / 0 }\nf()"),
+            Err(SyntaxErrorKind::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn functions_do_not_see_their_callers_locals() {
+        assert_eq!(
+            run("let x = 1\nfn f() -> int { x }\nfn g(x: int) -> int { f() }\ng(2)"),
+            Ok(Value::Int(1))
+        );
+    }
+
+    #[test]
+    fn returns() {
+        assert_eq!(
+            run("fn f() -> int {\n  return 1\n  1 / 0\n}\nf() + 1"),
+            Ok(Value::Int(2))
+        );
+        assert_eq!(
+            run("fn f() -> int { return 2 * 3 }\nf()"),
+            Ok(Value::Int(6))
+        );
+    }
+
+    #[test]
+    fn recursion_limit() {
+        assert_eq!(
+            run("fn f(n: int) -> int { f(n + 1) }\nf(0)"),
+            Err(SyntaxErrorKind::RecursionLimit(MAX_CALL_DEPTH))
+        );
+
+        // The depth goes back down after an error, so the same interpreter can still make calls.
+        let mut interpreter = Interpreter::default();
+        let (tree, _) = parse("fn f(n: int) -> int { f(n + 1) }", 0u32.into(), false);
+        interpreter.run(&tree).unwrap();
+        for _ in 0..2 {
+            let (tree, _) = parse("f(0)", 0u32.into(), false);
+            assert!(interpreter.run(&tree).is_err());
+        }
+        let (tree, _) = parse("fn g(n: int) -> int { n }\ng(1)", 0u32.into(), false);
+        assert_eq!(interpreter.run(&tree).ok(), Some(Value::Int(1)));
+    }
+
+    #[test]
+    fn blocks() {
+        assert_eq!(
+            run("let x = 1\nlet y = { let x = 2; x * 10 }\nx + y"),
+            Ok(Value::Int(21))
+        );
+        assert_eq!(run("{}"), Ok(Value::Unit));
+        assert_eq!(
+            run("fn f() -> int {\n  { return 1 }\n  2\n}\nf()"),
+            Ok(Value::Int(1))
+        );
+    }
+
+    #[test]
+    fn blocks_do_not_leak_bindings() {
+        let mut interpreter = Interpreter::default();
+        for input in ["{ let a = 1 }", "{ let a = 1; 1 / 0 }"] {
+            let (tree, _) = parse(input, 0u32.into(), false);
+            let _ = interpreter.run(&tree);
+            assert_eq!(interpreter.lookup("a"), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn interpolation() {
+        assert_eq!(
+            run(r#""{1} + {2.5} = {1 + 2}, {'c'}{"!"}""#),
+            Ok(Value::String("1 + 2.5 = 3, c!".into()))
+        );
+        assert_eq!(run(r#""{1 / 0}""#), Err(SyntaxErrorKind::DivisionByZero));
+    }
+
+    #[test]
+    fn args() {
+        let interpreter = Interpreter::new(vec!["a".into(), "b".into()]);
+        assert_eq!(interpreter.args(), ["a", "b"]);
+    }
+
+    #[test]
+    fn builtins() {
+        let (tree, _) = parse("args()", 0u32.into(), false);
+        assert_eq!(
+            Interpreter::new(vec!["a".into()]).run(&tree).ok(),
+            Some(Value::List(vec![Value::String("a".into())]))
+        );
+        assert_eq!(run("args").unwrap().to_string(), "<fn args>");
+        assert_eq!(run("let args = 1\nargs"), Ok(Value::Int(1)));
+    }
+}
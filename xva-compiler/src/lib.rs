@@ -1,21 +1,92 @@
 #![deny(unused_crate_dependencies)]
-use std::{io::Write, sync::Arc};
+//! The compiler driver.
+//!
+//! A [`Compiler`] owns everything a compilation needs: the source map, the options, and the state of each stage.
+//! Compiling a file is a matter of calling each stage in turn, passing along what the previous stage produced:
+//!
+//! ```
+//! # use xva_compiler::Compiler;
+//! let mut compiler = Compiler::default();
+//! let src_id = compiler.load_virtual_file("main.xva".into(), "let x = 1 + 2".into());
+//!
+//! let tree = compiler.parse_file(src_id);
+//! compiler.check(&tree);
+//! if let Some(program) = compiler.lower(tree) {
+//!     compiler.run(&program);
+//! }
+//!
+//! assert!(!compiler.has_errors());
+//! ```
+//!
+//! Diagnostics from every stage are collected in the compiler rather than returned. A stage won't run while there
+//! are errors waiting to be taken, so a later stage never sees the output of one that failed. Warnings don't stop
+//! anything.
 
-use xva_parse::SyntaxError;
+use std::{io::Write, path::PathBuf, sync::Arc};
+
+use xva_ast::ast::Item;
+use xva_parse::{error::Severity, SyntaxError};
 use xva_span::{SourceId, SourceMap};
+
+pub mod builtins;
+pub mod check;
+pub mod diagnostic;
+pub mod eval;
+pub mod options;
+
+use check::{Checker, Ty};
+use diagnostic::ErrorFormat;
+use eval::{Interpreter, Value};
+use options::UnstableOptions;
+
 pub struct Compiler {
     pub source_map: SourceMap,
+    pub error_format: ErrorFormat,
+    pub unstable_options: UnstableOptions,
+
+    /// The checker, which keeps every binding it has checked.
+    pub checker: Checker,
+
+    /// The interpreter, which keeps every binding it has run.
+    pub interpreter: Interpreter,
+
+    /// Diagnostics from every stage, in the order they were reported.
+    diagnostics: Vec<SyntaxError>,
 }
 
 impl Default for Compiler {
     fn default() -> Self {
         Self {
             source_map: Default::default(),
+            error_format: Default::default(),
+            unstable_options: Default::default(),
+            checker: Default::default(),
+            interpreter: Default::default(),
+            diagnostics: vec![],
         }
     }
 }
 
+/// A tree that has passed every check, ready to be run.
+///
+/// The interpreter walks the tree directly, so lowering doesn't transform it yet. Holding a `Program` still
+/// guarantees that the tree was checked without errors.
+#[derive(Debug)]
+pub struct Program {
+    items: Vec<Item>,
+}
+
+impl Program {
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+}
+
 impl Compiler {
+    pub fn load_file(&mut self, path: PathBuf) -> std::io::Result<SourceId> {
+        self.source_map.load(path)
+    }
+
     pub fn load_virtual_file(&mut self, name: String, src: String) -> SourceId {
         self.source_map.load_virtual(name, src)
     }
@@ -24,10 +95,201 @@ impl Compiler {
         self.source_map.get_raw(&id)
     }
 
-    pub fn write_syntax_error(&self, error: SyntaxError, writer: impl Write) {
-        error.write(&self.source_map, writer);
+    /// Lexes and parses a file that has been loaded into the source map. Compilation stages 1 and 2.
+    ///
+    /// # Returns
+    /// The parsed tree. The parser always produces one, even if it had to skip over parts of the file.
+    pub fn parse_file(&mut self, src_id: SourceId) -> Vec<Item> {
+        let src = self
+            .get_file_content(src_id)
+            .expect("Parsing a file that isn't in the source map");
+
+        let (tree, errors) = xva_parse::parser::parse(
+            src.as_ref(),
+            src_id,
+            self.unstable_options.contains("pretty", "lex"),
+        );
+
+        if self.unstable_options.contains("pretty", "ast") {
+            println!("{tree:#?}")
+        }
+
+        self.diagnostics.extend(errors);
+        tree
+    }
+
+    /// Resolves names and infers types. Compilation stage 3.
+    ///
+    /// # Returns
+    /// The type of the value the tree produces, or `None` if there were already diagnostics or checking failed.
+    pub fn check(&mut self, tree: &[Item]) -> Option<Ty> {
+        if self.has_errors() {
+            return None;
+        }
+
+        let (ty, errors) = self.checker.check(tree);
+        self.diagnostics.extend(errors);
+        (!self.has_errors()).then_some(ty)
+    }
+
+    /// Turns a checked tree into a program that can be run.
+    ///
+    /// # Returns
+    /// The program, or `None` if there are diagnostics, since a tree with errors can't be run.
+    pub fn lower(&mut self, tree: Vec<Item>) -> Option<Program> {
+        (!self.has_errors()).then_some(Program { items: tree })
+    }
+
+    /// Executes a program. Compilation stage 4.
+    ///
+    /// # Returns
+    /// The value of the program, or `None` if there were already diagnostics or a runtime error occurred.
+    pub fn run(&mut self, program: &Program) -> Option<Value> {
+        if self.has_errors() {
+            return None;
+        }
+
+        self.interpreter
+            .run(program.items())
+            .map_err(|error| self.diagnostics.push(error))
+            .ok()
+    }
+
+    /// Every diagnostic reported so far.
+    pub fn diagnostics(&self) -> &[SyntaxError] {
+        &self.diagnostics
+    }
+
+    /// Removes every diagnostic reported so far, so that later stages can run again.
+    pub fn take_diagnostics(&mut self) -> Vec<SyntaxError> {
+        std::mem::take(&mut self.diagnostics)
+    }
+
+    /// Whether any diagnostic reported so far is an error, rather than a warning.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity() == Severity::Error)
+    }
+
+    /// Writes, and removes, every diagnostic reported so far.
+    pub fn write_diagnostics(&mut self, mut writer: impl Write) {
+        for error in self.take_diagnostics() {
+            self.write_syntax_error(error, &mut writer);
+        }
+    }
+
+    /// Writes a diagnostic in the compiler's [`ErrorFormat`].
+    pub fn write_syntax_error(&self, error: SyntaxError, mut writer: impl Write) {
+        match self.error_format {
+            ErrorFormat::Human => error.write(&self.source_map, writer),
+            ErrorFormat::Json => {
+                let json = diagnostic::to_json(&error, &self.source_map);
+                writeln!(writer, "{json}").unwrap();
+            }
+        }
+    }
+
+    /// Writes an error that isn't about any source text, like a file that couldn't be read, in the compiler's
+    /// [`ErrorFormat`].
+    pub fn write_message(&self, message: &str, mut writer: impl Write) {
+        match self.error_format {
+            ErrorFormat::Human => writeln!(writer, "{message}").unwrap(),
+            ErrorFormat::Json => {
+                let json = diagnostic::message_to_json(message);
+                writeln!(writer, "{json}").unwrap();
+            }
+        }
     }
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use crate::{
+        check::Ty,
+        eval::{Interpreter, Value},
+        Compiler,
+    };
+
+    fn compile(src: &str) -> (Compiler, Option<Value>) {
+        let mut compiler = Compiler::default();
+        let src_id = compiler.load_virtual_file("test".into(), src.into());
+
+        let tree = compiler.parse_file(src_id);
+        compiler.check(&tree);
+        let value = compiler
+            .lower(tree)
+            .and_then(|program| compiler.run(&program));
+        (compiler, value)
+    }
+
+    #[test]
+    fn stages() {
+        let (compiler, value) = compile("let x = 2; 3 * 4");
+        assert_eq!(value, Some(Value::Int(12)));
+        assert!(compiler.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn check_returns_type() {
+        let mut compiler = Compiler::default();
+        let src_id = compiler.load_virtual_file("test".into(), "1.5 + 2.0".into());
+        let tree = compiler.parse_file(src_id);
+        assert_eq!(compiler.check(&tree), Some(Ty::Float));
+    }
+
+    #[test]
+    fn later_stages_do_not_run_after_errors() {
+        let (compiler, value) = compile("let x = 1 + true");
+        assert_eq!(value, None);
+        assert_eq!(compiler.diagnostics().len(), 1);
+
+        let (compiler, value) = compile("1 / 0");
+        assert_eq!(value, None);
+        assert_eq!(compiler.diagnostics().len(), 1);
+    }
+
+    #[test]
+    fn warnings_do_not_stop_later_stages() {
+        // The `а` is Cyrillic.
+        let (compiler, value) = compile("let p\u{430}y = 1\n2");
+        assert_eq!(value, Some(Value::Int(2)));
+        assert_eq!(compiler.diagnostics().len(), 1);
+        assert!(!compiler.has_errors());
+    }
+
+    #[test]
+    fn scripts_see_their_arguments() {
+        let mut compiler = Compiler {
+            interpreter: Interpreter::new(vec!["a".into(), "b".into()]),
+            ..Default::default()
+        };
+        let src_id =
+            compiler.load_virtual_file("test".into(), "let args = args()\n\"{args}\"".into());
+
+        let tree = compiler.parse_file(src_id);
+        compiler.check(&tree);
+        let value = compiler
+            .lower(tree)
+            .and_then(|program| compiler.run(&program));
+        assert!(
+            compiler.diagnostics().is_empty(),
+            "{:#?}",
+            compiler.diagnostics()
+        );
+        assert_eq!(value, Some(Value::String(r#"["a", "b"]"#.into())));
+    }
+
+    #[test]
+    fn taking_diagnostics_allows_stages_to_run() {
+        let mut compiler = Compiler::default();
+        let bad = compiler.load_virtual_file("bad".into(), "@".into());
+        compiler.parse_file(bad);
+        assert!(compiler.has_errors());
+        assert_eq!(compiler.take_diagnostics().len(), 1);
+
+        let good = compiler.load_virtual_file("good".into(), "1".into());
+        let tree = compiler.parse_file(good);
+        assert_eq!(compiler.check(&tree), Some(Ty::Int));
+    }
+}
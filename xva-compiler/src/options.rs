@@ -0,0 +1,266 @@
+//! Unstable compiler options, given on the command line as `-Z name` or `-Z name=value`.
+//!
+//! Every option must be registered in [`UNSTABLE_OPTIONS`] before it can be used. This way, options are checked
+//! when the command line is parsed rather than silently ignored, and `-Z help` can list all of them.
+
+use std::collections::HashMap;
+
+/// Every unstable option the compiler understands, in the order `-Z help` lists them.
+pub const UNSTABLE_OPTIONS: &[OptionInfo] = &[
+    OptionInfo {
+        name: "help",
+        kind: OptionKind::Flag,
+        description: "Lists every unstable option",
+    },
+    OptionInfo {
+        name: "pretty",
+        kind: OptionKind::List(&["lex", "ast"]),
+        description: "Prints the output of the given compilation stages",
+    },
+];
+
+/// A registered unstable option.
+#[derive(Debug, PartialEq)]
+pub struct OptionInfo {
+    pub name: &'static str,
+    pub kind: OptionKind,
+    pub description: &'static str,
+}
+
+/// The values an unstable option accepts.
+#[derive(Debug, PartialEq)]
+pub enum OptionKind {
+    /// No value, e.g. `-Z help`.
+    Flag,
+
+    /// Exactly one of the allowed values, e.g. `-Z name=value`.
+    Enum(&'static [&'static str]),
+
+    /// Any number of the allowed values, separated by commas, e.g. `-Z name=a,b`.
+    List(&'static [&'static str]),
+}
+
+impl OptionKind {
+    fn allowed(&self) -> &'static [&'static str] {
+        match self {
+            OptionKind::Flag => &[],
+            OptionKind::Enum(allowed) | OptionKind::List(allowed) => allowed,
+        }
+    }
+}
+
+/// A single unstable option from the command line, checked against the registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnstableOption {
+    name: &'static str,
+    values: Vec<&'static str>,
+}
+
+impl std::str::FromStr for UnstableOption {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = match s.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value)),
+            None => (s.trim(), None),
+        };
+
+        let info = UNSTABLE_OPTIONS
+            .iter()
+            .find(|info| info.name == name)
+            .ok_or_else(|| {
+                let names = UNSTABLE_OPTIONS.iter().map(|info| info.name);
+                format!(
+                    "Unknown unstable option `{name}`.{} See `-Z help` for the full list",
+                    suggestion(name, names)
+                )
+            })?;
+
+        let values = match (&info.kind, value) {
+            (OptionKind::Flag, None) => vec![],
+            (OptionKind::Flag, Some(_)) => return Err(format!("`{name}` doesn't take a value")),
+            (_, None) => {
+                return Err(format!(
+                    "`{name}` needs a value, one of: {}",
+                    info.kind.allowed().join(", ")
+                ))
+            }
+            (OptionKind::Enum(_), Some(value)) => vec![allowed_value(info, value.trim())?],
+            (OptionKind::List(_), Some(values)) => values
+                .split(',')
+                .map(|value| allowed_value(info, value.trim()))
+                .collect::<Result<_, _>>()?,
+        };
+
+        Ok(Self {
+            name: info.name,
+            values,
+        })
+    }
+}
+
+/// Finds `value` among the values allowed for an option.
+fn allowed_value(info: &OptionInfo, value: &str) -> Result<&'static str, String> {
+    let allowed = info.kind.allowed();
+    allowed
+        .iter()
+        .find(|allowed| **allowed == value)
+        .copied()
+        .ok_or_else(|| {
+            format!(
+                "`{value}` is not a valid value for `{}`.{} Expected one of: {}",
+                info.name,
+                suggestion(value, allowed.iter().copied()),
+                allowed.join(", ")
+            )
+        })
+}
+
+/// Suggests the closest of `candidates` to `input`, if any of them are close enough to be a likely typo.
+fn suggestion<'a>(input: &str, candidates: impl Iterator<Item = &'a str>) -> String {
+    let threshold = (input.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min()
+        .map_or(String::new(), |(_, candidate)| {
+            format!(" Did you mean `{candidate}`?")
+        })
+}
+
+/// The Levenshtein distance between two strings: the fewest single character insertions, deletions or
+/// substitutions needed to turn one into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Every unstable option that was given, for any compilation stage to query.
+#[derive(Debug, Clone, Default)]
+pub struct UnstableOptions {
+    options: HashMap<&'static str, Vec<&'static str>>,
+}
+
+impl UnstableOptions {
+    /// Collects options from the command line. Giving a list option more than once adds to its values, while
+    /// giving an enum option more than once replaces its value.
+    pub fn new(options: impl IntoIterator<Item = UnstableOption>) -> Self {
+        let mut map = HashMap::<_, Vec<_>>::new();
+        for UnstableOption { name, values } in options {
+            let entry = map.entry(name).or_default();
+            match UNSTABLE_OPTIONS.iter().find(|info| info.name == name) {
+                Some(OptionInfo {
+                    kind: OptionKind::List(_),
+                    ..
+                }) => entry.extend(values),
+                _ => *entry = values,
+            }
+        }
+
+        Self { options: map }
+    }
+
+    /// Whether the option was given at all.
+    pub fn is_set(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    /// Whether the option was given with `value` among its values.
+    pub fn contains(&self, name: &str, value: &str) -> bool {
+        self.options
+            .get(name)
+            .is_some_and(|values| values.contains(&value))
+    }
+
+    /// The value of an enum option, if it was given.
+    pub fn value(&self, name: &str) -> Option<&'static str> {
+        self.options
+            .get(name)
+            .and_then(|values| values.first().copied())
+    }
+
+    /// The text shown by `-Z help`.
+    pub fn help() -> String {
+        let usage = |info: &OptionInfo| match &info.kind {
+            OptionKind::Flag => info.name.to_string(),
+            OptionKind::Enum(allowed) => format!("{}=<{}>", info.name, allowed.join("|")),
+            OptionKind::List(allowed) => format!("{}=<{}>,...", info.name, allowed.join("|")),
+        };
+
+        let width = UNSTABLE_OPTIONS
+            .iter()
+            .map(|info| usage(info).len())
+            .max()
+            .unwrap_or_default();
+
+        let lines = UNSTABLE_OPTIONS
+            .iter()
+            .map(|info| format!("  -Z {:width$}  {}", usage(info), info.description))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!("Unstable options:\n{lines}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edit_distance, UnstableOption, UnstableOptions};
+
+    fn parse(s: &str) -> Result<UnstableOption, String> {
+        s.parse()
+    }
+
+    #[test]
+    fn flags() {
+        let options = UnstableOptions::new([parse("help").unwrap()]);
+        assert!(options.is_set("help"));
+        assert!(!options.is_set("pretty"));
+        assert!(parse("help=yes").is_err());
+    }
+
+    #[test]
+    fn lists() {
+        let options =
+            UnstableOptions::new([parse("pretty=lex").unwrap(), parse("pretty=ast").unwrap()]);
+        assert!(options.contains("pretty", "lex"));
+        assert!(options.contains("pretty", "ast"));
+
+        let options = UnstableOptions::new([parse("pretty=lex, ast").unwrap()]);
+        assert!(options.contains("pretty", "ast"));
+        assert!(parse("pretty").is_err());
+    }
+
+    #[test]
+    fn suggestions() {
+        let unknown = parse("prety=lex").unwrap_err();
+        assert!(unknown.contains("Did you mean `pretty`?"), "{unknown}");
+
+        let bad_value = parse("pretty=lexx").unwrap_err();
+        assert!(bad_value.contains("Did you mean `lex`?"), "{bad_value}");
+
+        let far_off = parse("optimise").unwrap_err();
+        assert!(!far_off.contains("Did you mean"), "{far_off}");
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(edit_distance("pretty", "pretty"), 0);
+        assert_eq!(edit_distance("prety", "pretty"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "ast"), 3);
+    }
+}
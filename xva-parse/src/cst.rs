@@ -0,0 +1,773 @@
+//! The concrete syntax tree: a lossless tree of every token in the source, with its trivia.
+//!
+//! The tree comes in two layers, in the style of Roslyn and rust-analyzer:
+//! - The *green* tree ([`GreenNode`], [`GreenToken`]) holds kinds and text, but no positions. A node only knows its
+//!   width, so an unchanged subtree can be shared between two versions of a file.
+//! - The *red* tree ([`SyntaxNode`], [`SyntaxToken`]) is a thin view over the green tree that knows where each node
+//!   starts and what its parent is. Red nodes are made as the tree is walked, and are cheap to throw away.
+//!
+//! The shape of the tree comes from the abstract syntax tree: every expression, local, type and block of docs gets a
+//! node, holding the tokens between its start and its end. Tokens that no node claims, like the `=` in a local or the
+//! end of input, belong to the closest node around them. Writing out every token with its trivia gives back the
+//! input, byte for byte.
+//!
+//! The abstract syntax tree can be derived again from any node with [`SyntaxNode::ast`].
+
+use std::{fmt::Display, rc::Rc, sync::Arc};
+
+use internment::Intern;
+use xva_ast::{
+    ast::{
+        BindingKind, Block, Docs, Expression, ExpressionKind, Function, InterpolatedPart, Item,
+        ItemKind, Statement, StatementKind, Type,
+    },
+    dump::{Dump, DumpOptions, SExpr},
+};
+use xva_span::{CheapRange, SourceId, SourceSpan};
+
+use crate::{
+    error::SyntaxError,
+    parser::parse_tokens,
+    token::{Token, TokenKind, Trivia, TriviaKind},
+};
+
+/// The kind of a node in the concrete syntax tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    /// The whole file.
+    Root,
+    Module,
+    Local,
+    Function,
+    Parameter,
+    Block,
+    Type,
+    Docs,
+    Literal,
+    Path,
+    Unary,
+    Binary,
+    Interpolated,
+    Call,
+    Return,
+    Error,
+}
+
+impl NodeKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NodeKind::Root => "root",
+            NodeKind::Module => "module",
+            NodeKind::Local => "local",
+            NodeKind::Function => "function",
+            NodeKind::Parameter => "parameter",
+            NodeKind::Block => "block",
+            NodeKind::Type => "type",
+            NodeKind::Docs => "docs",
+            NodeKind::Literal => "literal",
+            NodeKind::Path => "path",
+            NodeKind::Unary => "unary",
+            NodeKind::Binary => "binary",
+            NodeKind::Interpolated => "interpolated",
+            NodeKind::Call => "call",
+            NodeKind::Return => "return",
+            NodeKind::Error => "error",
+        }
+    }
+}
+
+/// Whitespace or a comment in the green tree. Unlike [`Trivia`], it has no span.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GreenTrivia {
+    pub kind: TriviaKind,
+    pub text: Intern<String>,
+}
+
+/// A token in the green tree, with its trivia. Unlike [`Token`], it has no span.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GreenToken {
+    pub kind: TokenKind,
+    pub text: Intern<String>,
+    pub leading: Vec<GreenTrivia>,
+    pub trailing: Vec<GreenTrivia>,
+}
+
+impl GreenToken {
+    /// The length of the token in bytes, trivia included.
+    pub fn width(&self) -> usize {
+        self.leading_width()
+            + self.text.len()
+            + self.trailing.iter().map(|t| t.text.len()).sum::<usize>()
+    }
+
+    fn leading_width(&self) -> usize {
+        self.leading.iter().map(|t| t.text.len()).sum()
+    }
+}
+
+impl From<Token> for GreenToken {
+    fn from(token: Token) -> Self {
+        let green = |trivia: Vec<Trivia>| {
+            trivia
+                .into_iter()
+                .map(|t| GreenTrivia {
+                    kind: t.kind,
+                    text: t.text,
+                })
+                .collect()
+        };
+
+        Self {
+            kind: token.kind,
+            text: token.original,
+            leading: green(token.leading),
+            trailing: green(token.trailing),
+        }
+    }
+}
+
+impl Display for GreenToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.leading.iter().try_for_each(|t| f.write_str(&t.text))?;
+        f.write_str(&self.text)?;
+        self.trailing.iter().try_for_each(|t| f.write_str(&t.text))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GreenElement {
+    Node(Arc<GreenNode>),
+    Token(Arc<GreenToken>),
+}
+
+impl GreenElement {
+    pub fn width(&self) -> usize {
+        match self {
+            GreenElement::Node(node) => node.width(),
+            GreenElement::Token(token) => token.width(),
+        }
+    }
+}
+
+/// A node in the green tree. Its [`Display`] output is the exact source text it was built from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GreenNode {
+    kind: NodeKind,
+    width: usize,
+    children: Vec<GreenElement>,
+}
+
+impl GreenNode {
+    pub fn new(kind: NodeKind, children: Vec<GreenElement>) -> Self {
+        Self {
+            kind,
+            width: children.iter().map(GreenElement::width).sum(),
+            children,
+        }
+    }
+
+    pub fn kind(&self) -> NodeKind {
+        self.kind
+    }
+
+    /// The length of the node in bytes, trivia included.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn children(&self) -> &[GreenElement] {
+        &self.children
+    }
+}
+
+impl Display for GreenNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.children.iter().try_for_each(|child| match child {
+            GreenElement::Node(node) => node.fmt(f),
+            GreenElement::Token(token) => token.fmt(f),
+        })
+    }
+}
+
+/// A node in the red tree: a [`GreenNode`] with a position and a parent.
+#[derive(Clone)]
+pub struct SyntaxNode(Rc<NodeData>);
+
+struct NodeData {
+    green: Arc<GreenNode>,
+    parent: Option<SyntaxNode>,
+
+    /// Where the node starts, leading trivia included.
+    offset: usize,
+    src_id: SourceId,
+}
+
+impl SyntaxNode {
+    pub fn new_root(green: Arc<GreenNode>, src_id: SourceId) -> Self {
+        Self(Rc::new(NodeData {
+            green,
+            parent: None,
+            offset: 0,
+            src_id,
+        }))
+    }
+
+    pub fn green(&self) -> &Arc<GreenNode> {
+        &self.0.green
+    }
+
+    pub fn kind(&self) -> NodeKind {
+        self.0.green.kind
+    }
+
+    pub fn parent(&self) -> Option<&SyntaxNode> {
+        self.0.parent.as_ref()
+    }
+
+    /// The span of the node, from the start of its first token to the end of its last, without the trivia around it.
+    pub fn span(&self) -> SourceSpan {
+        let tokens = self.tokens();
+        match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => {
+                self.span_of(first.span().range().start(), last.span().range().end())
+            }
+            _ => self.span_of(self.0.offset, self.0.offset),
+        }
+    }
+
+    /// The span of the node, with the trivia around it.
+    pub fn full_span(&self) -> SourceSpan {
+        self.span_of(self.0.offset, self.0.offset + self.0.green.width)
+    }
+
+    fn span_of(&self, start: usize, end: usize) -> SourceSpan {
+        SourceSpan::new(self.0.src_id, CheapRange::new(start, end))
+    }
+
+    /// The nodes and tokens directly under this node, in order.
+    pub fn children(&self) -> Vec<SyntaxElement> {
+        let mut offset = self.0.offset;
+        let mut children = vec![];
+
+        for child in &self.0.green.children {
+            children.push(match child {
+                GreenElement::Node(green) => SyntaxElement::Node(SyntaxNode(Rc::new(NodeData {
+                    green: green.clone(),
+                    parent: Some(self.clone()),
+                    offset,
+                    src_id: self.0.src_id,
+                }))),
+                GreenElement::Token(green) => SyntaxElement::Token(SyntaxToken {
+                    green: green.clone(),
+                    parent: self.clone(),
+                    offset,
+                }),
+            });
+            offset += child.width();
+        }
+
+        children
+    }
+
+    /// The nodes directly under this node, in order.
+    pub fn child_nodes(&self) -> Vec<SyntaxNode> {
+        self.children()
+            .into_iter()
+            .filter_map(|child| match child {
+                SyntaxElement::Node(node) => Some(node),
+                SyntaxElement::Token(_) => None,
+            })
+            .collect()
+    }
+
+    /// The child node at `index` among this node's children, given the `offset` it starts at. Unlike
+    /// [`Self::child_nodes`], this doesn't build a node for every other child too.
+    ///
+    /// # Panics
+    /// If there's no child at `index`, or it's a token.
+    pub(crate) fn child_node(&self, index: usize, offset: usize) -> SyntaxNode {
+        let GreenElement::Node(green) = &self.0.green.children[index] else {
+            panic!("child {index} is a token");
+        };
+
+        SyntaxNode(Rc::new(NodeData {
+            green: green.clone(),
+            parent: Some(self.clone()),
+            offset,
+            src_id: self.0.src_id,
+        }))
+    }
+
+    /// Every token under this node, however deep, in order.
+    pub fn tokens(&self) -> Vec<SyntaxToken> {
+        self.children()
+            .into_iter()
+            .flat_map(|child| match child {
+                SyntaxElement::Node(node) => node.tokens(),
+                SyntaxElement::Token(token) => vec![token],
+            })
+            .collect()
+    }
+
+    /// The source text of the node, trivia included.
+    pub fn text(&self) -> String {
+        self.0.green.to_string()
+    }
+
+    /// Parses the node's tokens into the abstract syntax tree.
+    pub fn ast(&self) -> (Vec<Item>, Vec<SyntaxError>) {
+        let tokens = self
+            .tokens()
+            .iter()
+            .map(SyntaxToken::to_token)
+            .collect::<Vec<_>>();
+        let (items, errors) = parse_tokens(&tokens, self.0.src_id);
+        (items.into_iter().map(|(item, _)| item).collect(), errors)
+    }
+}
+
+impl std::fmt::Debug for SyntaxNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyntaxNode")
+            .field("kind", &self.kind())
+            .field("full_span", &self.full_span())
+            .finish()
+    }
+}
+
+/// A token in the red tree: a [`GreenToken`] with a position and a parent.
+#[derive(Clone)]
+pub struct SyntaxToken {
+    green: Arc<GreenToken>,
+    parent: SyntaxNode,
+
+    /// Where the token starts, leading trivia included.
+    offset: usize,
+}
+
+impl SyntaxToken {
+    pub fn green(&self) -> &Arc<GreenToken> {
+        &self.green
+    }
+
+    pub fn kind(&self) -> TokenKind {
+        self.green.kind
+    }
+
+    pub fn parent(&self) -> &SyntaxNode {
+        &self.parent
+    }
+
+    /// The token's own text, without trivia.
+    pub fn text(&self) -> &str {
+        &self.green.text
+    }
+
+    /// The span of the token, without trivia.
+    pub fn span(&self) -> SourceSpan {
+        let start = self.offset + self.green.leading_width();
+        self.parent.span_of(start, start + self.green.text.len())
+    }
+
+    /// The span of the token, with its trivia.
+    pub fn full_span(&self) -> SourceSpan {
+        self.parent
+            .span_of(self.offset, self.offset + self.green.width())
+    }
+
+    pub fn leading(&self) -> Vec<Trivia> {
+        self.trivia(&self.green.leading, self.offset)
+    }
+
+    pub fn trailing(&self) -> Vec<Trivia> {
+        self.trivia(&self.green.trailing, self.span().range().end())
+    }
+
+    fn trivia(&self, green: &[GreenTrivia], mut offset: usize) -> Vec<Trivia> {
+        green
+            .iter()
+            .map(|t| {
+                let span = self.parent.span_of(offset, offset + t.text.len());
+                offset += t.text.len();
+                Trivia {
+                    kind: t.kind,
+                    span,
+                    text: t.text,
+                }
+            })
+            .collect()
+    }
+
+    /// Turns the token back into a [`Token`], as [`lex_lossless`](crate::lex_lossless) made it.
+    pub fn to_token(&self) -> Token {
+        Token {
+            kind: self.green.kind,
+            span: self.span(),
+            original: self.green.text,
+            leading: self.leading(),
+            trailing: self.trailing(),
+        }
+    }
+}
+
+impl std::fmt::Debug for SyntaxToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyntaxToken")
+            .field("kind", &self.kind())
+            .field("span", &self.span())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(SyntaxToken),
+}
+
+/// The outline of a node, taken from the abstract syntax tree: its kind, where it starts and ends, and the nodes
+/// inside it.
+struct Shape {
+    kind: NodeKind,
+    start: usize,
+    end: usize,
+    children: Vec<Shape>,
+}
+
+impl Shape {
+    /// A shape covering `span` and all of its children, which don't always fall inside the span of their parent (a
+    /// local's span only covers `let x`, for example).
+    fn new(kind: NodeKind, span: SourceSpan, mut children: Vec<Shape>) -> Self {
+        children.sort_by_key(|child| child.start);
+        let start = children
+            .iter()
+            .map(|c| c.start)
+            .fold(span.range().start(), usize::min);
+        let end = children
+            .iter()
+            .map(|c| c.end)
+            .fold(span.range().end(), usize::max);
+
+        Self {
+            kind,
+            start,
+            end,
+            children,
+        }
+    }
+
+    /// Grows the node to cover `span`, and adds the docs written before it.
+    fn grow(mut self, span: SourceSpan, docs: &Option<Docs>) -> Self {
+        let range = span.range();
+        let range = CheapRange::new(self.start.min(range.start()), self.end.max(range.end()));
+        let span = SourceSpan::new(span.src(), range);
+        self.children.extend(docs.iter().map(Self::docs));
+        Self::new(self.kind, span, self.children)
+    }
+
+    fn item(item: &Item) -> Self {
+        let shape = match &item.kind {
+            ItemKind::Expression(expr) => Self::expr(expr),
+            ItemKind::Statement(stmt) => Self::stmt(stmt),
+            ItemKind::Module(module) => Self::new(
+                NodeKind::Module,
+                item.span,
+                module.items.iter().map(Self::item).collect(),
+            ),
+            ItemKind::Function(function) => Self::function(function),
+            ItemKind::Error(_) => Self::new(NodeKind::Error, item.span, vec![]),
+        };
+
+        shape.grow(item.span, &item.docs)
+    }
+
+    fn function(function: &Function) -> Self {
+        let params = function
+            .params
+            .iter()
+            .map(|param| Self::new(NodeKind::Parameter, param.span, vec![Self::ty(&param.ty)]));
+        let return_ty = function.return_ty.iter().map(Self::ty);
+
+        Self::new(
+            NodeKind::Function,
+            function.span,
+            params
+                .chain(return_ty)
+                .chain([Self::block(&function.body)])
+                .collect(),
+        )
+        .grow(function.span, &function.docs)
+    }
+
+    fn stmt(stmt: &Statement) -> Self {
+        match &stmt.kind {
+            StatementKind::Local(local) => {
+                let ty = local.ty.iter().map(Self::ty);
+                let init = match &local.binding_kind {
+                    BindingKind::Declared => None,
+                    BindingKind::Inited(expr) => Some(Self::expr(expr)),
+                };
+
+                Self::new(NodeKind::Local, local.span, ty.chain(init).collect())
+                    .grow(stmt.span, &local.docs)
+            }
+        }
+    }
+
+    fn expr(expr: &Expression) -> Self {
+        let (kind, children) = match &expr.kind {
+            ExpressionKind::Literal(_) => (NodeKind::Literal, vec![]),
+            ExpressionKind::Path(_) => (NodeKind::Path, vec![]),
+            ExpressionKind::Unary(_, operand) => (NodeKind::Unary, vec![Self::expr(operand)]),
+            ExpressionKind::Binary(_, lhs, rhs) => {
+                (NodeKind::Binary, vec![Self::expr(lhs), Self::expr(rhs)])
+            }
+            ExpressionKind::Interpolated(parts) => (
+                NodeKind::Interpolated,
+                parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        InterpolatedPart::Text(..) => None,
+                        InterpolatedPart::Expression(expr) => Some(Self::expr(expr)),
+                    })
+                    .collect(),
+            ),
+            ExpressionKind::Call(callee, args) => (
+                NodeKind::Call,
+                std::iter::once(callee.as_ref())
+                    .chain(args)
+                    .map(Self::expr)
+                    .collect(),
+            ),
+            ExpressionKind::Return(value) => (
+                NodeKind::Return,
+                value.iter().map(|value| Self::expr(value)).collect(),
+            ),
+            ExpressionKind::Block(block) => return Self::block(block),
+        };
+
+        Self::new(kind, expr.span, children)
+    }
+
+    fn block(block: &Block) -> Self {
+        Self::new(
+            NodeKind::Block,
+            block.span,
+            block.items.iter().map(Self::item).collect(),
+        )
+    }
+
+    fn ty(ty: &Type) -> Self {
+        Self::new(NodeKind::Type, ty.span, vec![])
+    }
+
+    fn docs(docs: &Docs) -> Self {
+        Self::new(NodeKind::Docs, docs.span, vec![])
+    }
+}
+
+/// Hands out tokens, in order, to the nodes that contain them.
+struct Builder {
+    tokens: std::iter::Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Builder {
+    fn node(&mut self, shape: &Shape) -> GreenNode {
+        let mut children = vec![];
+        for child in &shape.children {
+            self.take_while(&mut children, |tok| tok.start() < child.start);
+            children.push(GreenElement::Node(Arc::new(self.node(child))));
+        }
+
+        self.take_while(&mut children, |tok| tok.end() <= shape.end);
+        GreenNode::new(shape.kind, children)
+    }
+
+    /// Takes tokens while they match, stopping before the end of input, which only the root may hold.
+    fn take_while(&mut self, children: &mut Vec<GreenElement>, f: impl Fn(&Token) -> bool) {
+        while let Some(token) = self
+            .tokens
+            .next_if(|tok| !matches!(tok.kind, TokenKind::Eof) && f(tok))
+        {
+            children.push(GreenElement::Token(Arc::new(token.into())));
+        }
+    }
+}
+
+/// Builds the concrete syntax tree, with the shape of the abstract syntax tree `items` and the tokens from
+/// [`lex_lossless`](crate::lex_lossless). Each item's node holds all of the tokens it was parsed from.
+pub(crate) fn build(
+    items: &[(Item, CheapRange<usize>)],
+    tokens: Vec<Token>,
+    src_id: SourceId,
+) -> SyntaxNode {
+    let mut builder = Builder {
+        tokens: tokens.into_iter().peekable(),
+    };
+
+    let root = Shape::new(
+        NodeKind::Root,
+        SourceSpan::new(src_id, CheapRange::new(0, usize::MAX)),
+        items
+            .iter()
+            .map(|(item, range)| Shape::item(item).grow(SourceSpan::new(src_id, *range), &None))
+            .collect(),
+    );
+
+    let mut green = builder.node(&root);
+    green.children.extend(
+        builder
+            .tokens
+            .map(|token| GreenElement::Token(Arc::new(token.into()))),
+    );
+
+    SyntaxNode::new_root(
+        Arc::new(GreenNode::new(NodeKind::Root, green.children)),
+        src_id,
+    )
+}
+
+impl Dump for SyntaxNode {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        let node = SExpr::new(self.kind().name()).atom(format!("@{}", self.span().range()));
+        self.children()
+            .into_iter()
+            .fold(node, |node, child| match child {
+                SyntaxElement::Node(child) => node.child(child.dump(opts)),
+                SyntaxElement::Token(token) => node.child(token.dump(opts)),
+            })
+    }
+}
+
+/// Tokens are dumped like [`Token`]s, with their trivia as children.
+impl Dump for SyntaxToken {
+    fn dump(&self, opts: &DumpOptions) -> SExpr {
+        let token = self.to_token();
+        token
+            .leading
+            .iter()
+            .chain(&token.trailing)
+            .fold(token.dump(opts), |node, trivia| {
+                node.child(
+                    SExpr::new(trivia.kind.name())
+                        .atom(format!("@{}", trivia.span.range()))
+                        .quoted(trivia.text.as_str()),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use xva_ast::dump::{dump_items, Dump, DumpOptions};
+
+    use super::NodeKind;
+    use crate::parser::{parse, parse_lossless};
+
+    #[test]
+    fn roundtrip() {
+        let inputs = [
+            "",
+            "   \n\t",
+            "let x: int = 1 + 2\n3",
+            "#!/usr/bin/env xva\n// comment\nlet x = 1 /* inline */ + 2 // trailing\n",
+            "/// The answer.\n/** Obviously. */\nlet x\n  = 42\n",
+            r#""a{1}b{2 * 3}""#,
+            "let café = 'é' + 1_000u8",
+            "let x = @ $ 1 +",
+            r#"let s = "unterminated"#,
+            "/* unterminated",
+            "1\r\n2\r\n",
+            "/// Adds.\nfn add(a: int, b: int,) -> int {\n  return a + b // sum\n}\nadd(1, 2)",
+            "fn f( { }",
+            "let x = {\n  let y = 1; y // y\n};;\r\n{}\n",
+            "1 /* one\n */ 2 /// Three.\n3 /// Dangling.",
+        ];
+
+        for input in inputs {
+            let (cst, _) = parse_lossless(input, 0u32.into());
+            assert_eq!(cst.text(), input);
+            assert_eq!(cst.full_span().range().end(), input.len());
+
+            let tokens = cst.tokens();
+            let rebuilt = tokens
+                .iter()
+                .map(|tok| tok.green().to_string())
+                .collect::<String>();
+            assert_eq!(rebuilt, input);
+        }
+    }
+
+    #[test]
+    fn structure() {
+        let (cst, errors) = parse_lossless("let x: int = 1 + 2 // sum\n", 0u32.into());
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(
+            cst.dump(&DumpOptions::default()).to_string(),
+            r#"(root @0..26
+  (local @0..26
+    (let @0..3
+      (whitespace @3..4 " "))
+    (identifier @4..5 x)
+    (colon @5..6
+      (whitespace @6..7 " "))
+    (type @7..10
+      (identifier @7..10 int
+        (whitespace @10..11 " ")))
+    (equals @11..12
+      (whitespace @12..13 " "))
+    (binary @13..18
+      (literal @13..14
+        (integer @13..14 1
+          (whitespace @14..15 " ")))
+      (plus @15..16
+        (whitespace @16..17 " "))
+      (literal @17..18
+        (integer @17..18 2
+          (whitespace @18..19 " ")
+          (comment @19..25 "// sum"))))
+    (newline @25..26))
+  (eof @26..26))"#
+        );
+    }
+
+    #[test]
+    fn navigation() {
+        let (cst, _) = parse_lossless("1 + -2", 0u32.into());
+        let binary = &cst.child_nodes()[0];
+        assert_eq!(binary.kind(), NodeKind::Binary);
+
+        let unary = &binary.child_nodes()[1];
+        assert_eq!(unary.kind(), NodeKind::Unary);
+        assert_eq!(unary.span().range(), (4..6).into());
+        assert_eq!(unary.parent().unwrap().kind(), NodeKind::Binary);
+        assert_eq!(unary.tokens()[0].parent().kind(), NodeKind::Unary);
+
+        let leading = binary.tokens()[1].trailing();
+        assert_eq!(leading[0].span.range(), (3..4).into());
+    }
+
+    #[test]
+    fn ast_is_derivable() {
+        let inputs = [
+            "let x: int = 1 + 2\n3",
+            "// comment\n/// The answer.\nlet x = 42 /* inline */ * 2",
+            r#"var s = "a{1}b""#,
+            "1 + + 2",
+            "/// Adds.\nfn add(a: int, b: int) -> int { a + b }\nadd(1, f(2),)",
+            "let x = { let y = 1; y }; { x }",
+            "1 /* one\n */ 2 /// Three.\n3 /// Dangling.",
+        ];
+
+        for input in inputs {
+            let (tree, errors) = parse(input, 0u32.into(), false);
+            let (cst, lossless_errors) = parse_lossless(input, 0u32.into());
+            let (derived, derived_errors) = cst.ast();
+
+            let opts = DumpOptions::default();
+            assert_eq!(dump_items(&derived, &opts), dump_items(&tree, &opts));
+            assert_eq!(derived_errors.len(), errors.len());
+            assert_eq!(lossless_errors.len(), errors.len());
+        }
+    }
+}
@@ -1,20 +1,71 @@
-use ariadne::{Color, Fmt, Label, Report, ReportKind, Span};
+use ariadne::{Color, Config, Fmt, IndexType, Label, Report, ReportKind, Span};
 use chumsky::util::MaybeRef;
+use internment::Intern;
 use std::io::Write;
 
 use xva_span::{CheapRange, SourceId, SourceSpan, TokenSpan};
 
 use crate::token::{Token, TokenKind};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SyntaxErrorKind {
     UnexpectedEnd,
     UnexpectedPattern(ErrorPattern),
     InvalidUnicode(u32), // UnclosedDelimiter,
+    InvalidEscape(Intern<String>),
+    UnterminatedString,
+    UnclosedInterpolation,
+    UnterminatedComment,
+    DanglingDocComment,
+    ReservedWord(Intern<String>),
+    MissingDigits(Intern<String>),
+    InvalidSuffix(Intern<String>),
+    LiteralOutOfRange(Intern<String>),
+    ChainedComparison,
+    MissingSeparator,
+
+    // Warnings
+    MixedScriptIdentifier(Intern<String>),
+    ConfusableIdentifier {
+        name: Intern<String>,
+        other: Intern<String>,
+    },
     // NoEndBranch,
-    UninitedImmutable { expr_start: SourceSpan },
+    UninitedImmutable {
+        expr_start: SourceSpan,
+    },
+
+    // Semantic errors, reported by the checker
+    TypeMismatch {
+        expected: Intern<String>,
+        found: Intern<String>,
+    },
+    InvalidUnaryOperand {
+        op: Intern<String>,
+        operand: Intern<String>,
+    },
+    InvalidBinaryOperands {
+        op: Intern<String>,
+        lhs: Intern<String>,
+        rhs: Intern<String>,
+    },
+    UnknownType(Intern<String>),
+    UnresolvedName(Intern<String>),
+    TypeAnnotationNeeded,
+    NotCallable(Intern<String>),
+    WrongArgumentCount {
+        expected: usize,
+        found: usize,
+    },
+    ReturnOutsideFunction,
+
+    // Runtime errors, reported while executing
+    DivisionByZero,
+    Overflow,
+    NegativeExponent,
+    RecursionLimit(usize),
 }
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SyntaxError {
     kind: SyntaxErrorKind,
     span: SourceSpan,
@@ -42,68 +93,363 @@ impl SyntaxError {
         }
     }
 
-    pub fn write<C>(self, cache: C, writer: impl Write)
-    where
-        C: ariadne::Cache<SourceId>,
-    {
-        let msg = match &self.kind {
+    pub fn kind(&self) -> &SyntaxErrorKind {
+        &self.kind
+    }
+
+    pub fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    /// Moves the error to a different span, keeping everything else.
+    pub(crate) fn with_span(self, span: SourceSpan) -> Self {
+        Self { span, ..self }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self.kind {
+            SyntaxErrorKind::MixedScriptIdentifier(_)
+            | SyntaxErrorKind::ConfusableIdentifier { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// A number identifying the kind of diagnostic, which stays the same from one version to the next. Syntax errors
+    /// are numbered from 1, warnings from 100, errors found by the checker from 200 and runtime errors from 300.
+    pub fn code(&self) -> u32 {
+        match self.kind {
+            SyntaxErrorKind::UnexpectedEnd => 1,
+            SyntaxErrorKind::UnexpectedPattern(_) => 2,
+            SyntaxErrorKind::InvalidUnicode(_) => 3,
+            SyntaxErrorKind::InvalidEscape(_) => 4,
+            SyntaxErrorKind::UnterminatedString => 5,
+            SyntaxErrorKind::UnclosedInterpolation => 6,
+            SyntaxErrorKind::UnterminatedComment => 7,
+            SyntaxErrorKind::DanglingDocComment => 8,
+            SyntaxErrorKind::ReservedWord(_) => 9,
+            SyntaxErrorKind::MissingDigits(_) => 10,
+            SyntaxErrorKind::InvalidSuffix(_) => 11,
+            SyntaxErrorKind::LiteralOutOfRange(_) => 12,
+            SyntaxErrorKind::ChainedComparison => 13,
+            SyntaxErrorKind::MissingSeparator => 14,
+            SyntaxErrorKind::UninitedImmutable { .. } => 15,
+
+            SyntaxErrorKind::MixedScriptIdentifier(_) => 100,
+            SyntaxErrorKind::ConfusableIdentifier { .. } => 101,
+
+            SyntaxErrorKind::TypeMismatch { .. } => 200,
+            SyntaxErrorKind::InvalidUnaryOperand { .. } => 201,
+            SyntaxErrorKind::InvalidBinaryOperands { .. } => 202,
+            SyntaxErrorKind::UnknownType(_) => 203,
+            SyntaxErrorKind::UnresolvedName(_) => 204,
+            SyntaxErrorKind::TypeAnnotationNeeded => 205,
+            SyntaxErrorKind::NotCallable(_) => 206,
+            SyntaxErrorKind::WrongArgumentCount { .. } => 207,
+            SyntaxErrorKind::ReturnOutsideFunction => 208,
+
+            SyntaxErrorKind::DivisionByZero => 300,
+            SyntaxErrorKind::Overflow => 301,
+            SyntaxErrorKind::NegativeExponent => 302,
+            SyntaxErrorKind::RecursionLimit(_) => 303,
+        }
+    }
+
+    /// The main message of the error, as plain text.
+    pub fn message(&self) -> String {
+        self.message_text(false)
+    }
+
+    /// Every label of the error, as plain text, along with the span each one points at.
+    pub fn labels(&self) -> Vec<(SourceSpan, String)> {
+        vec![self.label_text(false)]
+    }
+
+    fn message_text(&self, colored: bool) -> String {
+        let paint = |text: &dyn std::fmt::Display, color: Color| match colored {
+            true => text.fg(color).to_string(),
+            false => text.to_string(),
+        };
+
+        match &self.kind {
             SyntaxErrorKind::InvalidUnicode(uc) => {
                 format!("Invalid Unicode: the value {uc:#x} is not a valid Unicode scalar value.")
             }
 
+            SyntaxErrorKind::InvalidEscape(seq) => {
+                format!("Invalid escape sequence {}", paint(seq, Color::Red))
+            }
+
+            SyntaxErrorKind::UnterminatedString => {
+                "Unterminated string: the closing quote is missing.".to_string()
+            }
+
+            SyntaxErrorKind::UnclosedInterpolation => {
+                "Unclosed interpolation: the closing brace is missing.".to_string()
+            }
+
+            SyntaxErrorKind::UnterminatedComment => {
+                "Unterminated block comment: the closing `*/` is missing.".to_string()
+            }
+
+            SyntaxErrorKind::DanglingDocComment => {
+                "Doc comments must come before the item they document.".to_string()
+            }
+
+            SyntaxErrorKind::ReservedWord(word) => format!(
+                "{} is a reserved word, and cannot be used as an identifier.",
+                paint(word, Color::Red)
+            ),
+
+            SyntaxErrorKind::MissingDigits(prefix) => format!(
+                "Missing digits: {} must be followed by at least one digit.",
+                paint(prefix, Color::Red)
+            ),
+
+            SyntaxErrorKind::InvalidSuffix(suffix) => format!(
+                "Invalid suffix {} on a numeric literal",
+                paint(suffix, Color::Red)
+            ),
+
+            SyntaxErrorKind::LiteralOutOfRange(ty) => {
+                format!("Literal out of range for {}", paint(ty, Color::Cyan))
+            }
+
+            SyntaxErrorKind::ChainedComparison => {
+                "Comparison operators cannot be chained.".to_string()
+            }
+
+            SyntaxErrorKind::MissingSeparator => {
+                "Items must be separated by a newline or `;`.".to_string()
+            }
+
+            SyntaxErrorKind::MixedScriptIdentifier(name) => format!(
+                "The identifier {} mixes characters from different scripts",
+                paint(name, Color::Yellow)
+            ),
+
+            SyntaxErrorKind::ConfusableIdentifier { name, other } => format!(
+                "The identifier {} looks like {}",
+                paint(name, Color::Yellow),
+                paint(other, Color::Cyan)
+            ),
+
             SyntaxErrorKind::UninitedImmutable { .. } => {
                 "Immutable bindings must be initialised.".to_string()
             }
 
+            SyntaxErrorKind::TypeMismatch { expected, found } => format!(
+                "Mismatched types: expected {}, found {}",
+                paint(expected, Color::Cyan),
+                paint(found, Color::Red)
+            ),
+
+            SyntaxErrorKind::InvalidUnaryOperand { op, operand } => format!(
+                "The operator {} cannot be applied to type {}",
+                paint(op, Color::Cyan),
+                paint(operand, Color::Red)
+            ),
+
+            SyntaxErrorKind::InvalidBinaryOperands { op, lhs, rhs } => format!(
+                "The operator {} cannot be applied to types {} and {}",
+                paint(op, Color::Cyan),
+                paint(lhs, Color::Red),
+                paint(rhs, Color::Red)
+            ),
+
+            SyntaxErrorKind::UnknownType(name) => {
+                format!("Cannot find type {} in this scope", paint(name, Color::Red))
+            }
+
+            SyntaxErrorKind::UnresolvedName(name) => {
+                format!("Cannot find {} in this scope", paint(name, Color::Red))
+            }
+
+            SyntaxErrorKind::TypeAnnotationNeeded => {
+                "Type annotations needed: the binding has no type or initialiser.".to_string()
+            }
+
+            SyntaxErrorKind::NotCallable(ty) => {
+                format!("Values of type {} cannot be called", paint(ty, Color::Red))
+            }
+
+            SyntaxErrorKind::WrongArgumentCount { expected, found } => format!(
+                "This function takes {} {}, but {} {} given",
+                paint(expected, Color::Cyan),
+                if *expected == 1 {
+                    "argument"
+                } else {
+                    "arguments"
+                },
+                paint(found, Color::Red),
+                if *found == 1 { "was" } else { "were" },
+            ),
+
+            SyntaxErrorKind::ReturnOutsideFunction => {
+                "Cannot return from outside of a function.".to_string()
+            }
+
+            SyntaxErrorKind::DivisionByZero => "Attempted to divide by zero.".to_string(),
+            SyntaxErrorKind::Overflow => "Arithmetic overflow.".to_string(),
+            SyntaxErrorKind::NegativeExponent => {
+                "Attempted to raise an integer to a negative power.".to_string()
+            }
+            SyntaxErrorKind::RecursionLimit(limit) => {
+                format!("Recursion limit reached: calls can only be nested {limit} deep.")
+            }
+
             error_kind => format!(
                 "{}{}, expected {}",
                 match error_kind {
                     // SyntaxErrorKind::NoEndBranch => "No end branch",
                     SyntaxErrorKind::UnexpectedEnd => "Unexpected end of input".to_string(),
                     SyntaxErrorKind::UnexpectedPattern(pat) =>
-                        format!("Unexpected pattern: {}", pat.fg(Color::Red)),
+                        format!("Unexpected pattern: {}", paint(pat, Color::Red)),
 
                     _ => unreachable!(),
                 },
                 self.label.map_or("".into(), |label| format!(
                     " while parsing {}",
-                    label.fg(Color::Cyan)
+                    paint(&label, Color::Cyan)
                 )),
                 "something else",
             ),
+        }
+    }
+
+    fn label_text(&self, colored: bool) -> (SourceSpan, String) {
+        match &self.kind {
+            SyntaxErrorKind::UninitedImmutable { expr_start } => {
+                (*expr_start, "Insert an initialiser here".to_string())
+            }
+
+            kind => (
+                self.span,
+                match kind {
+                    SyntaxErrorKind::UnexpectedEnd => "End of input".to_string(),
+
+                    // BUG: labels attaches to both the text and trailing whitespace, instead of just the text
+                    SyntaxErrorKind::UnexpectedPattern(pat) if colored => {
+                        format!("Unexpected pattern: {} ", pat.fg(Color::Red))
+                    }
+                    SyntaxErrorKind::UnexpectedPattern(pat) => format!("Unexpected pattern: {pat}"),
+                    SyntaxErrorKind::InvalidUnicode(_) => "Invalid Unicode value here".into(),
+                    SyntaxErrorKind::InvalidEscape(_) => {
+                        r#"Expected one of \\, \/, \", \', \{, \}, \0, \n, \r, \t or \u{...}"#
+                            .into()
+                    }
+                    SyntaxErrorKind::UnterminatedString => "This string is never closed".into(),
+                    SyntaxErrorKind::UnterminatedComment => "This comment is never closed".into(),
+                    SyntaxErrorKind::DanglingDocComment => "This documents nothing".into(),
+                    SyntaxErrorKind::ReservedWord(_) => "Reserved word".into(),
+                    SyntaxErrorKind::MissingDigits(_) => "Expected digits here".into(),
+                    SyntaxErrorKind::InvalidSuffix(_) => {
+                        "Expected one of i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32 or f64"
+                            .into()
+                    }
+                    SyntaxErrorKind::MixedScriptIdentifier(_) => {
+                        "Check this for lookalike characters".into()
+                    }
+                    SyntaxErrorKind::ConfusableIdentifier { other, .. } => {
+                        format!("This is a different identifier to {other}")
+                    }
+                    SyntaxErrorKind::LiteralOutOfRange(ty) => {
+                        format!("This doesn't fit in {ty}")
+                    }
+                    SyntaxErrorKind::UnclosedInterpolation => {
+                        "This interpolation is never closed".into()
+                    }
+                    SyntaxErrorKind::ChainedComparison => {
+                        "Use `and` to join comparisons, or parentheses to compare the result".into()
+                    }
+                    SyntaxErrorKind::MissingSeparator => "Expected a newline or `;` before this".into(),
+                    SyntaxErrorKind::TypeMismatch { expected, .. } => {
+                        format!("Expected {expected} here")
+                    }
+                    SyntaxErrorKind::InvalidUnaryOperand { operand, .. } => {
+                        format!("This is of type {operand}")
+                    }
+                    SyntaxErrorKind::InvalidBinaryOperands { lhs, rhs, .. } => {
+                        format!("These are of types {lhs} and {rhs}")
+                    }
+                    SyntaxErrorKind::UnknownType(_) => "Not found".into(),
+                    SyntaxErrorKind::UnresolvedName(_) => "Not found".into(),
+                    SyntaxErrorKind::TypeAnnotationNeeded => {
+                        "Add a type annotation or an initialiser".into()
+                    }
+                    SyntaxErrorKind::NotCallable(ty) => format!("This is of type {ty}"),
+                    SyntaxErrorKind::WrongArgumentCount { expected, .. } => match expected {
+                        1 => "Expected 1 argument".into(),
+                        _ => format!("Expected {expected} arguments"),
+                    },
+                    SyntaxErrorKind::ReturnOutsideFunction => "Not inside a function".into(),
+                    SyntaxErrorKind::DivisionByZero => "Divisor is zero".into(),
+                    SyntaxErrorKind::Overflow => "Overflowed here".into(),
+                    SyntaxErrorKind::NegativeExponent => "Exponent is negative".into(),
+                    SyntaxErrorKind::RecursionLimit(_) => "This call is one too many".into(),
+
+                    _ => unreachable!(),
+                },
+            ),
+        }
+    }
+
+    pub fn write<C>(self, cache: C, writer: impl Write)
+    where
+        C: ariadne::Cache<SourceId>,
+    {
+        let (label_span, label_msg) = self.label_text(true);
+        let label_color = match (&self.kind, self.severity()) {
+            (SyntaxErrorKind::UninitedImmutable { .. }, _) => Color::Cyan,
+            (_, Severity::Warning) => Color::Yellow,
+            _ => Color::Red,
         };
 
-        let report = Report::build(ReportKind::Error, self.span.src(), self.span.start())
-            .with_code(3)
-            .with_message(msg)
-            .with_label(match &self.kind {
-                SyntaxErrorKind::UninitedImmutable { expr_start } => Label::new(expr_start.clone())
-                    .with_message("Insert an initialiser here")
-                    .with_color(Color::Cyan),
-
-                _ => {
-                    Label::new(self.span)
-                        .with_message(match &self.kind {
-                            SyntaxErrorKind::UnexpectedEnd => "End of input".to_string(),
-
-                            // BUG: labels attaches to both the text and trailing whitespace, instead of just the text
-                            SyntaxErrorKind::UnexpectedPattern(pat) => {
-                                format!("Unexpected pattern: {} ", pat.fg(Color::Red))
-                            }
-                            SyntaxErrorKind::InvalidUnicode(_) => {
-                                "Invalid Unicode value here".into()
-                            }
-
-                            _ => unreachable!(),
-                        })
-                        .with_color(Color::Red)
-                }
-            });
+        let report = Report::build(
+            self.severity().report_kind(),
+            self.span.src(),
+            self.span.start(),
+        )
+        // Spans are byte offsets, which only match character offsets in ASCII text.
+        .with_config(Config::default().with_index_type(IndexType::Byte))
+        .with_code(self.code())
+        .with_message(self.message_text(true))
+        .with_label(
+            Label::new(label_span)
+                .with_message(label_msg)
+                .with_color(label_color),
+        );
 
         report.finish().write(cache, writer).unwrap();
     }
 }
 
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+
+    /// Something that is probably a mistake, but doesn't stop the program from compiling or running.
+    Warning,
+}
+
+impl Severity {
+    fn report_kind(&self) -> ReportKind<'static> {
+        match self {
+            Severity::Error => ReportKind::Error,
+            Severity::Warning => ReportKind::Warning,
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
 // Lexer error implementation: the input type is &'src str
 impl<'src> chumsky::error::Error<'src, &'src str> for SyntaxError {
     /// `&'a str`'s implementation of [`chumsky::input::Input`] has the following associated types:
@@ -145,21 +491,28 @@ impl<'src> chumsky::error::Error<'src, &'src [Token]> for SyntaxError {
         found: Option<MaybeRef<'src, Token>>,
         span: TokenSpan,
     ) -> Self {
-        Self {
-            kind: found
-                .map(|tok| ErrorPattern::Token(tok.kind))
-                .map(SyntaxErrorKind::UnexpectedPattern)
-                .unwrap_or(SyntaxErrorKind::UnexpectedEnd),
-            span: SourceSpan::new(0.into(), {
-                let TokenSpan { start, end, .. } = span;
-                CheapRange::new(start, end)
-            }),
-            label: None,
+        // The span chumsky gives us is in terms of token indices, not the source text. The found token knows
+        // where it came from, so use its span instead. At the end of input there is no token to ask, so the span
+        // is fixed up once parsing has finished.
+        match found {
+            Some(tok) => Self {
+                kind: SyntaxErrorKind::UnexpectedPattern(ErrorPattern::Token(tok.kind)),
+                span: tok.span,
+                label: None,
+            },
+            None => Self {
+                kind: SyntaxErrorKind::UnexpectedEnd,
+                span: SourceSpan::new(0.into(), {
+                    let TokenSpan { start, end, .. } = span;
+                    CheapRange::new(start, end)
+                }),
+                label: None,
+            },
         }
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ErrorPattern {
     Char(char),
     Token(TokenKind),
@@ -196,3 +549,33 @@ impl std::fmt::Display for ErrorPattern {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use internment::Intern;
+    use xva_span::{CheapRange, SourceSpan};
+
+    use super::{SyntaxError, SyntaxErrorKind};
+
+    #[test]
+    fn codes() {
+        let code = |kind| {
+            SyntaxError::new(kind, SourceSpan::new(0u32.into(), CheapRange::new(0, 1))).code()
+        };
+
+        assert_eq!(code(SyntaxErrorKind::UnterminatedString), 5);
+        assert_eq!(code(SyntaxErrorKind::MissingSeparator), 14);
+        assert_eq!(
+            code(SyntaxErrorKind::MixedScriptIdentifier(Intern::from_ref(
+                "p\u{430}y"
+            ))),
+            100
+        );
+        assert_eq!(
+            code(SyntaxErrorKind::UnresolvedName(Intern::from_ref("x"))),
+            204
+        );
+        assert_eq!(code(SyntaxErrorKind::DivisionByZero), 300);
+        assert_eq!(code(SyntaxErrorKind::RecursionLimit(1)), 303);
+    }
+}
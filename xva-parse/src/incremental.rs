@@ -0,0 +1,761 @@
+//! Incremental reparsing, for editors that reparse on every keystroke.
+//!
+//! A [`Parse`] keeps the text of a file along with its trees. When the text is edited, only the top-level items that
+//! the edit touches are lexed and parsed again. Every other item keeps its tokens, its green node and its
+//! [`NodeId`](xva_ast::node_id::NodeId)s, and only the items after the edit have their spans moved.
+//!
+//! Items aren't quite independent of each other: an edit can make the item before it run on into it (typing `+` at
+//! the end of its line), or make it run on into the item after it. So the item on each side of the edit is parsed
+//! again too, and kept only if it comes out exactly as it was. If it doesn't, the edited region grows by another
+//! item on that side, until both ends agree with the old tree.
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+    sync::Arc,
+};
+
+use internment::Intern;
+use xva_ast::ast::{
+    BindingKind, BindingPattern, Block, Docs, Expression, ExpressionKind, InterpolatedPart, Item,
+    ItemKind, Name, StatementKind, Type, TypeKind,
+};
+use xva_span::{CheapRange, SourceId, SourceSpan};
+
+use crate::{
+    cst::{self, GreenNode, GreenToken, NodeKind, SyntaxNode},
+    error::{SyntaxError, SyntaxErrorKind},
+    lexer::{confusable_identifiers, confusable_skeleton, lex_lossless},
+    parser::parse_tokens,
+    token::{Token, TokenKind},
+};
+
+/// A change to the text of a file: the bytes in `range` are replaced with `text`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    pub range: CheapRange<usize>,
+    pub text: String,
+}
+
+impl TextEdit {
+    pub fn new(range: impl Into<CheapRange<usize>>, text: impl Into<String>) -> Self {
+        Self {
+            range: range.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// A parsed file, with both of its trees, that can be edited and reparsed.
+pub struct Parse {
+    text: String,
+    src_id: SourceId,
+    cst: SyntaxNode,
+    items: Vec<Item>,
+    errors: Vec<SyntaxError>,
+
+    /// How many interpolated strings each item starts inside of. See [`string_depths`].
+    depths: Vec<usize>,
+
+    /// Where each item starts, trivia included, followed by where the end of input starts. The items an edit
+    /// touches are found by binary search, without building the red node for every item.
+    starts: Vec<usize>,
+    identifiers: Identifiers,
+}
+
+impl Parse {
+    /// Lexes and parses the whole of `text`.
+    pub fn new(text: impl Into<String>, src_id: SourceId) -> Self {
+        let text = text.into();
+        let (tokens, mut errors) = lex_lossless(&text, src_id);
+        let (items, parse_errors) = parse_tokens(&tokens, src_id);
+        errors.extend(parse_errors);
+
+        let depths = string_depths(&items, &tokens);
+        let cst = cst::build(&items, tokens, src_id);
+        let mut identifiers = Identifiers::default();
+        identifiers.splice(0..0, &cst.child_nodes());
+
+        Self {
+            depths,
+            starts: starts(0, cst.green()),
+            identifiers,
+            cst,
+            items: items.into_iter().map(|(item, _)| item).collect(),
+            text,
+            src_id,
+            errors,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn cst(&self) -> &SyntaxNode {
+        &self.cst
+    }
+
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    pub fn errors(&self) -> &[SyntaxError] {
+        &self.errors
+    }
+
+    pub fn into_parts(self) -> (Vec<Item>, Vec<SyntaxError>) {
+        (self.items, self.errors)
+    }
+
+    /// Applies `edit` to the text, and reparses the items it touches.
+    ///
+    /// # Panics
+    /// If the range of the edit is out of bounds, or doesn't fall on a char boundary.
+    pub fn edit(&mut self, edit: &TextEdit) {
+        let (start, end) = (edit.range.start(), edit.range.end());
+        let old_len = self.text.len();
+        self.text.replace_range(start..end, &edit.text);
+
+        // Where an offset after the edit ends up.
+        let moved = |offset: usize| offset - end + start + edit.text.len();
+
+        // The root holds a node for each item, then the end of input.
+        let len = self.items.len();
+        let full = |i: usize| CheapRange::new(self.starts[i], self.starts[i + 1]);
+        debug_assert_eq!(self.starts.len(), len + 1);
+        debug_assert_eq!(self.cst.green().children().len(), len + 1);
+
+        // The items that the edit touches are `first..last`. Start with one more on each side.
+        let first = self.starts[1..].partition_point(|&item_end| item_end < start);
+        let last = self.starts[..len].partition_point(|&item_start| item_start <= end);
+        let mut lo = first.saturating_sub(1);
+        let mut hi = (last.max(first) + 1).min(len);
+
+        let (tail, kept_before, kept_after, items, tokens, errors) = loop {
+            // A window that reaches the last item goes on to the end of input.
+            let tail = hi == len;
+            let from = match lo < len {
+                true => full(lo).start(),
+                false => 0,
+            };
+            let to = match tail {
+                true => self.text.len(),
+                false => moved(full(hi - 1).end()),
+            };
+
+            // The lexer can only start again outside of strings, and a shebang is only allowed at the very start
+            // of the file. Neither end can be an error either: the item next to it might have tried to run on
+            // through it, and been stopped by something that the edit changed.
+            let error = |i: usize| matches!(self.items[i].kind, ItemKind::Error(_));
+            if lo > 0
+                && (self.depths[lo] > 0
+                    || (lo < first && error(lo))
+                    || self.text[from..to].starts_with("#!"))
+            {
+                lo -= 1;
+                continue;
+            } else if !tail && (self.depths[hi] > 0 || error(hi - 1)) {
+                hi += 1;
+                continue;
+            }
+
+            let (mut tokens, mut errors) = lex_lossless(&self.text[from..to], self.src_id);
+            let shift = |span: SourceSpan| move_span(span, |offset| offset + from);
+            for token in &mut tokens {
+                token.span = shift(token.span);
+                for trivia in token.leading.iter_mut().chain(&mut token.trailing) {
+                    trivia.span = shift(trivia.span);
+                }
+            }
+            errors = errors
+                .into_iter()
+                .map(|err| {
+                    let span = shift(err.span());
+                    err.with_span(span)
+                })
+                .collect();
+
+            if !tail {
+                tokens.pop();
+
+                // The window has to end outside of a string, like it started.
+                let depths = string_depths(&[], &tokens);
+                if depths.last() != Some(&0) {
+                    hi += 1;
+                    continue;
+                }
+            }
+
+            let (items, parse_errors) = parse_tokens(&tokens, self.src_id);
+            errors.extend(parse_errors);
+
+            let new = (&tokens[..], &errors[..]);
+            let old = |i: usize| (&self.errors[..], self.cst.child_node(i, self.starts[i]));
+            let kept_before = lo < first && unchanged(old(lo), items.first(), new, |o| o);
+            let kept_after = !tail && unchanged(old(hi - 1), items.last(), new, moved);
+
+            if lo < first && !kept_before && lo > 0 {
+                lo -= 1;
+            } else if !tail && !kept_after {
+                hi += 1;
+            } else {
+                break (tail, kept_before, kept_after, items, tokens, errors);
+            }
+        };
+
+        // Everything between the kept items is replaced: `from..old_to` in the old text, `from..to` in the new.
+        let (kept_before, kept_after) = (kept_before as usize, kept_after as usize);
+        let from = match (kept_before, lo < len) {
+            (1, _) => full(lo).end(),
+            (_, true) => full(lo).start(),
+            _ => 0,
+        };
+        let (old_to, to) = match tail {
+            true => (old_len, usize::MAX),
+            false => (full(hi - 1).start(), moved(full(hi - 1).start())),
+        };
+
+        let mut depths = string_depths(&items, &tokens);
+        let end_depth = depths.pop();
+        depths.truncate(depths.len() - kept_after);
+        let depths = depths.split_off(kept_before);
+
+        let mut items = items;
+        items.truncate(items.len() - kept_after);
+        let items = items.split_off(kept_before);
+        let tokens = tokens
+            .into_iter()
+            .filter(|tok| tok.start() >= from && tok.start() < to)
+            .collect();
+
+        // The green tree: old nodes on either side, new ones in between.
+        let old = self.cst.green().children();
+        let middle = cst::build(&items, tokens, self.src_id);
+        let mut children = old[..lo + kept_before].to_vec();
+        children.extend(middle.green().children().iter().cloned());
+        if !tail {
+            children.extend(old[hi - kept_after..].iter().cloned());
+        }
+        self.cst = SyntaxNode::new_root(
+            Arc::new(GreenNode::new(NodeKind::Root, children)),
+            self.src_id,
+        );
+
+        let after = self.starts.split_off(hi - kept_after);
+        self.starts.truncate(lo + kept_before);
+        self.starts.extend(starts(from, middle.green()));
+        if !tail {
+            self.starts.extend(after.into_iter().map(moved));
+        }
+        debug_assert_eq!(self.starts, starts(0, self.cst.green()));
+
+        let moved_span = |span: SourceSpan| move_span(span, moved);
+        let mut after = self.items.split_off(hi - kept_after);
+        for item in &mut after {
+            move_item(item, &moved_span);
+        }
+        self.items.truncate(lo + kept_before);
+        self.items.extend(items.into_iter().map(|(item, _)| item));
+        self.items.extend(after);
+
+        let after = self.depths.split_off(hi - kept_after);
+        self.depths.truncate(lo + kept_before);
+        self.depths.extend(depths);
+        match tail {
+            true => self.depths.extend(end_depth),
+            false => self.depths.extend(after),
+        }
+
+        // Confusable identifiers are found across the whole file, so they're looked for again wherever a name that
+        // was added or removed could be mistaken for another one. Warnings about other names stay as they are.
+        let touched = self
+            .identifiers
+            .splice(lo + kept_before..hi - kept_after, &middle.child_nodes());
+        let confusable = |err: &SyntaxError| match err.kind() {
+            SyntaxErrorKind::ConfusableIdentifier { name, .. } => Some(confusable_skeleton(name)),
+            _ => None,
+        };
+        let old_errors = std::mem::take(&mut self.errors)
+            .into_iter()
+            .filter(|err| !confusable(err).is_some_and(|skeleton| touched.contains(&skeleton)))
+            .filter_map(|err| {
+                let offset = err.span().range().start();
+                if offset < from {
+                    Some(err)
+                } else if !tail && offset >= old_to {
+                    let span = moved_span(err.span());
+                    Some(err.with_span(span))
+                } else {
+                    None
+                }
+            });
+        let new_errors = errors.into_iter().filter(|err| {
+            let offset = err.span().range().start();
+            confusable(err).is_none() && offset >= from && offset < to
+        });
+        self.errors = old_errors.chain(new_errors).collect();
+
+        if touched
+            .iter()
+            .any(|skeleton| self.identifiers.confusable(skeleton))
+        {
+            let identifiers = self
+                .cst
+                .tokens()
+                .into_iter()
+                .filter(|tok| matches!(tok.kind(), TokenKind::Identifier(_)))
+                .map(|tok| tok.to_token())
+                .collect::<Vec<_>>();
+            self.errors.extend(
+                confusable_identifiers(&identifiers)
+                    .into_iter()
+                    .filter(|err| {
+                        confusable(err).is_some_and(|skeleton| touched.contains(&skeleton))
+                    }),
+            );
+        }
+    }
+}
+
+/// Every identifier in the file, so that an edit only has to look for confusable identifiers again when it adds or
+/// removes a name that could be mistaken for another.
+#[derive(Default)]
+struct Identifiers {
+    /// The names used in each item, in order.
+    items: Vec<Vec<Intern<String>>>,
+
+    /// How many times each name is used, grouped by skeleton.
+    skeletons: HashMap<String, HashMap<Intern<String>, usize>>,
+}
+
+impl Identifiers {
+    /// Replaces the names used in the items in `range` with those used in `nodes`, and returns the skeletons of
+    /// every name that was added or removed.
+    fn splice(&mut self, range: Range<usize>, nodes: &[SyntaxNode]) -> HashSet<String> {
+        let mut touched = HashSet::new();
+        let names = nodes
+            .iter()
+            .map(|node| {
+                node.tokens()
+                    .iter()
+                    .filter_map(|tok| match tok.kind() {
+                        TokenKind::Identifier(name) => Some(name),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for &name in names.iter().flatten() {
+            let skeleton = confusable_skeleton(&name);
+            *self
+                .skeletons
+                .entry(skeleton.clone())
+                .or_default()
+                .entry(name)
+                .or_default() += 1;
+            touched.insert(skeleton);
+        }
+
+        for name in self.items.splice(range, names).flatten() {
+            let skeleton = confusable_skeleton(&name);
+            let counts = self.skeletons.get_mut(&skeleton).unwrap();
+            let count = counts.get_mut(&name).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&name);
+            }
+            if counts.is_empty() {
+                self.skeletons.remove(&skeleton);
+            }
+            touched.insert(skeleton);
+        }
+
+        touched
+    }
+
+    /// Whether any of the names with this skeleton could be mistaken for each other: there's more than one, and
+    /// they aren't all ASCII.
+    fn confusable(&self, skeleton: &str) -> bool {
+        self.skeletons
+            .get(skeleton)
+            .is_some_and(|names| names.len() > 1 && !names.keys().all(|name| name.is_ascii()))
+    }
+}
+
+/// Where each child of `node` starts, given where the node does.
+fn starts(mut offset: usize, node: &GreenNode) -> Vec<usize> {
+    node.children()
+        .iter()
+        .map(|child| {
+            let start = offset;
+            offset += child.width();
+            start
+        })
+        .collect()
+}
+
+/// Whether an old item's node was parsed again into the same item, from the same tokens and with the same errors.
+/// Errors count too, since some depend on text outside the item (like a string interpolation that's never closed).
+/// `offset` moves an offset in the old text to the new.
+fn unchanged(
+    (old_errors, node): (&[SyntaxError], SyntaxNode),
+    item: Option<&(Item, CheapRange<usize>)>,
+    (tokens, errors): (&[Token], &[SyntaxError]),
+    offset: impl Fn(usize) -> usize,
+) -> bool {
+    let Some((_, range)) = item else {
+        return false;
+    };
+
+    let old = node.span().range();
+    if offset(old.start()) != range.start() || offset(old.end()) != range.end() {
+        return false;
+    }
+
+    let new = tokens
+        .iter()
+        .filter(|tok| tok.start() >= range.start() && tok.end() <= range.end())
+        .map(|tok| GreenToken::from(tok.clone()))
+        .collect::<Vec<_>>();
+    let same_tokens = node
+        .tokens()
+        .iter()
+        .map(|tok| tok.green().as_ref())
+        .eq(new.iter());
+
+    let within =
+        |errors: &[SyntaxError], start: usize, end: usize, offset: &dyn Fn(usize) -> usize| {
+            let mut errors = errors
+                .iter()
+                .filter(|err| !matches!(err.kind(), SyntaxErrorKind::ConfusableIdentifier { .. }))
+                .map(|err| (err.span().range(), err.kind()))
+                .filter(|(span, _)| (start..=end).contains(&span.start()))
+                .map(|(span, kind)| {
+                    (
+                        format!("{:?}", kind),
+                        offset(span.start()),
+                        offset(span.end()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            errors.sort();
+            errors
+        };
+
+    same_tokens
+        && within(old_errors, old.start(), old.end(), &offset)
+            == within(errors, range.start(), range.end(), &|o| o)
+}
+
+/// How many interpolated strings each item starts inside of, followed by how many the tokens end inside of. Error
+/// recovery can split a string into several items, but the lexer can only start again between two items that aren't
+/// inside one.
+fn string_depths(items: &[(Item, CheapRange<usize>)], tokens: &[Token]) -> Vec<usize> {
+    let mut depth = 0usize;
+    let mut tokens = tokens.iter().peekable();
+    let mut step = |before: usize| {
+        while let Some(tok) = tokens.next_if(|tok| tok.start() < before) {
+            depth = match tok.kind {
+                TokenKind::StringStart(_) => depth + 1,
+                TokenKind::StringEnd(_) => depth.saturating_sub(1),
+                _ => depth,
+            };
+        }
+        depth
+    };
+
+    let mut depths = items
+        .iter()
+        .map(|(_, range)| step(range.start()))
+        .collect::<Vec<_>>();
+    depths.push(step(usize::MAX));
+    depths
+}
+
+fn move_span(span: SourceSpan, offset: impl Fn(usize) -> usize) -> SourceSpan {
+    let range = span.range();
+    SourceSpan::new(
+        span.src(),
+        CheapRange::new(offset(range.start()), offset(range.end())),
+    )
+}
+
+fn move_item(item: &mut Item, f: &impl Fn(SourceSpan) -> SourceSpan) {
+    item.span = f(item.span);
+    move_docs(&mut item.docs, f);
+
+    match &mut item.kind {
+        ItemKind::Expression(expr) => move_expr(expr, f),
+        ItemKind::Statement(stmt) => {
+            stmt.span = f(stmt.span);
+            match &mut stmt.kind {
+                StatementKind::Local(local) => {
+                    local.span = f(local.span);
+                    let BindingPattern::Identifier(ident) = &mut local.pattern;
+                    ident.span = f(ident.span);
+                    if let Some(ty) = &mut local.ty {
+                        move_type(ty, f);
+                    }
+                    if let BindingKind::Inited(expr) = &mut local.binding_kind {
+                        move_expr(expr, f);
+                    }
+                    move_docs(&mut local.docs, f);
+                }
+            }
+        }
+        ItemKind::Module(module) => {
+            for item in &mut module.items {
+                move_item(item, f);
+            }
+        }
+        ItemKind::Function(function) => {
+            function.span = f(function.span);
+            function.name.span = f(function.name.span);
+            for param in &mut function.params {
+                param.span = f(param.span);
+                param.name.span = f(param.name.span);
+                move_type(&mut param.ty, f);
+            }
+            if let Some(ty) = &mut function.return_ty {
+                move_type(ty, f);
+            }
+            move_block(&mut function.body, f);
+            move_docs(&mut function.docs, f);
+        }
+        ItemKind::Error(_) => (),
+    }
+}
+
+fn move_expr(expr: &mut Expression, f: &impl Fn(SourceSpan) -> SourceSpan) {
+    expr.span = f(expr.span);
+    match &mut expr.kind {
+        ExpressionKind::Literal(_) => (),
+        ExpressionKind::Path(name) => move_name(name, f),
+        ExpressionKind::Unary(_, operand) => move_expr(operand, f),
+        ExpressionKind::Binary(_, lhs, rhs) => {
+            move_expr(lhs, f);
+            move_expr(rhs, f);
+        }
+        ExpressionKind::Interpolated(parts) => {
+            for part in parts {
+                match part {
+                    InterpolatedPart::Text(_, span) => *span = f(*span),
+                    InterpolatedPart::Expression(expr) => move_expr(expr, f),
+                }
+            }
+        }
+        ExpressionKind::Call(callee, args) => {
+            move_expr(callee, f);
+            for arg in args {
+                move_expr(arg, f);
+            }
+        }
+        ExpressionKind::Return(value) => {
+            if let Some(value) = value {
+                move_expr(value, f);
+            }
+        }
+        ExpressionKind::Block(block) => move_block(block, f),
+    }
+}
+
+fn move_block(block: &mut Block, f: &impl Fn(SourceSpan) -> SourceSpan) {
+    block.span = f(block.span);
+    for item in &mut block.items {
+        move_item(item, f);
+    }
+}
+
+fn move_type(ty: &mut Type, f: &impl Fn(SourceSpan) -> SourceSpan) {
+    ty.span = f(ty.span);
+    if let TypeKind::Named(name) = &mut ty.kind {
+        move_name(name, f);
+    }
+}
+
+fn move_name(name: &mut Name, f: &impl Fn(SourceSpan) -> SourceSpan) {
+    name.span = f(name.span);
+    for segment in &mut name.segments {
+        segment.ident.span = f(segment.ident.span);
+    }
+}
+
+fn move_docs(docs: &mut Option<Docs>, f: &impl Fn(SourceSpan) -> SourceSpan) {
+    if let Some(docs) = docs {
+        docs.span = f(docs.span);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use xva_ast::dump::{dump_items, Dump, DumpOptions};
+
+    use super::{Parse, TextEdit};
+
+    /// Everything about a parse that should come out the same whether it was made incrementally or not.
+    fn describe(parse: &Parse) -> (String, String, String, Vec<String>) {
+        let mut errors = parse
+            .errors()
+            .iter()
+            .map(|err| format!("{} {:?}", err.span().range(), err.kind()))
+            .collect::<Vec<_>>();
+        errors.sort();
+
+        (
+            parse.cst().text(),
+            parse.cst().dump(&DumpOptions::default()).to_string(),
+            dump_items(parse.items(), &DumpOptions::default()),
+            errors,
+        )
+    }
+
+    /// Applies each edit in turn, checking the result against parsing the edited text from scratch.
+    fn check(input: &str, edits: &[(usize, usize, &str)]) -> Parse {
+        let mut parse = Parse::new(input, 0u32.into());
+        for &(start, end, text) in edits {
+            parse.edit(&TextEdit::new(start..end, text));
+            assert_eq!(parse.text(), parse.cst().text());
+            assert_eq!(
+                describe(&parse),
+                describe(&Parse::new(parse.text(), 0u32.into())),
+                "after replacing {start}..{end} with {text:?}"
+            );
+        }
+        parse
+    }
+
+    #[test]
+    fn edits_match_a_full_parse() {
+        let input = "let x = 1\nlet y: int = 2 * 3 // y\n\n/// Docs\nvar z = \"a{x}b\"\n4\n";
+        let edits = [
+            (8, 9, "10"),
+            (0, 0, "// header\n"),
+            (0, 0, " "),
+            (18, 18, "+ "),
+            (19, 22, ""),
+            (31, 32, "x"),
+            (0, 0, "#!/usr/bin/env xva\n"),
+        ];
+
+        for (start, end, text) in edits {
+            check(input, &[(start, end, text)]);
+        }
+    }
+
+    #[test]
+    fn every_offset() {
+        let input = "let x = 1 // c\n+ 2\nvar y: int = \"a{x * (3)}b\"\n-4 /* e */ 5\n";
+        for start in 0..input.len() {
+            for (end, text) in [(start, "+"), (start, "\""), (start, "{"), (start + 1, "")] {
+                check(input, &[(start, end, text)]);
+            }
+        }
+    }
+
+    #[test]
+    fn typing() {
+        let input = "let x = 1\n2\n";
+        let mut edits = vec![];
+        for (i, c) in "let café = x + \"{y}\" * -3\n".char_indices() {
+            edits.push((
+                10 + i,
+                10 + i,
+                &"let café = x + \"{y}\" * -3\n"[i..i + c.len_utf8()],
+            ));
+        }
+        check(input, &edits);
+    }
+
+    #[test]
+    fn items_can_join_and_split() {
+        // An operator at the end of a line carries the item on to the next one, and a `;` splits it in two.
+        check("1\n2\n3\n4", &[(1, 1, " +"), (1, 3, ""), (5, 5, " -")]);
+        check(
+            "let x = 1\n2\n3",
+            &[(9, 9, " *"), (9, 11, ""), (9, 9, "; 4")],
+        );
+        check("1 + 2\n3", &[(2, 3, ";"), (2, 3, "")]);
+    }
+
+    #[test]
+    fn strings_and_comments_can_swallow_the_rest() {
+        check("1\n2\n3\n", &[(2, 2, "\""), (2, 3, "")]);
+        check("1\n2\n3\n", &[(2, 2, "/*"), (8, 8, "*/")]);
+        check("1\n2\n3", &[(4, 5, "\"{")]);
+    }
+
+    #[test]
+    fn functions() {
+        // Removing any one character can unbalance the braces, or pull later items into the body.
+        let input = "fn f(a: int) -> int {\n  a + g(1, 2)\n}\nf(2)\n";
+        for start in 0..input.len() {
+            check(input, &[(start, start + 1, "")]);
+        }
+
+        check(
+            input,
+            &[(26, 26, "return "), (0, 0, "/// Docs\n"), (39, 39, "}")],
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        // Removing any one character can join items, or let a block run on to the end of the input.
+        let input = "let x = {\n  let y = 1; y\n}\n{ x }\n";
+        for start in 0..input.len() {
+            check(input, &[(start, start + 1, "")]);
+        }
+
+        check(
+            input,
+            &[(23, 23, "\n  y * 2"), (10, 10, "{"), (0, 0, "{\n")],
+        );
+    }
+
+    #[test]
+    fn edges() {
+        check("", &[(0, 0, "1"), (1, 1, " + 2"), (0, 5, "")]);
+        check("1\n2\n", &[(4, 4, "  \n"), (0, 7, "")]);
+        check("1 2 3", &[(2, 3, ""), (1, 3, "")]);
+        check("let = 1\n@\n2", &[(8, 9, "")]);
+    }
+
+    #[test]
+    fn untouched_items_are_kept() {
+        let mut parse = Parse::new("1\n2\n3\n4\n5", 0u32.into());
+        let ids = parse.items().iter().map(|item| item.id).collect::<Vec<_>>();
+        let greens = parse.cst().child_nodes();
+
+        parse.edit(&TextEdit::new(4..5, "30"));
+
+        let items = parse.items();
+        assert_eq!(items[0].id, ids[0]);
+        assert_eq!(items[4].id, ids[4]);
+        assert_ne!(items[2].id, ids[2]);
+        assert_eq!(items[4].span.range(), (9..10).into());
+
+        // The green nodes of untouched items are shared, not copied.
+        let nodes = parse.cst().child_nodes();
+        assert!(std::sync::Arc::ptr_eq(nodes[0].green(), greens[0].green()));
+        assert!(std::sync::Arc::ptr_eq(nodes[4].green(), greens[4].green()));
+    }
+
+    #[test]
+    fn confusables_are_found_across_items() {
+        let parse = check("let a = 1\nlet b = 2", &[(14, 15, "а")]);
+        assert_eq!(parse.errors().len(), 1);
+    }
+
+    #[test]
+    fn confusables_follow_edits() {
+        // The second `а` is Cyrillic.
+        let input = "let a = 1\nlet b = а\nlet c = 2\n";
+        let edits = [(29, 30, "3"), (0, 0, "let x = 0\n"), (28, 30, "a")];
+        let parse = check(input, &edits[..2]);
+        assert_eq!(parse.errors().len(), 1);
+        let parse = check(input, &edits);
+        assert_eq!(parse.errors().len(), 0);
+    }
+}
@@ -46,37 +46,91 @@ use xva_span::{CheapRange, SourceId, SourceSpan, TokenSpan};
 mod comment;
 mod ident;
 mod literals;
+mod newline;
 mod operators;
+mod trivia;
 
 use crate::{
     error::SyntaxError,
-    token::{Token, TokenKind},
+    token::{Delimiter, Token, TokenKind},
 };
 
-use self::{comment::comment, ident::ident_or_keyword, literals::literal};
+use self::{
+    comment::comment,
+    ident::ident_or_keyword,
+    literals::{literal, string},
+};
+
+pub use self::ident::{is_ident_continue, KEYWORDS};
+pub(crate) use self::ident::{confusable_identifiers, confusable_skeleton};
 
 /// Convenience type to avoid writing out the wrapping type from Chumsky.
 pub(crate) type LexerExtra = extra::Err<SyntaxError>;
 
-pub(crate) fn lexer<'src>(
-) -> impl Parser<'src, &'src str, Vec<(TokenKind, TokenSpan, &'src str)>, LexerExtra> {
-    let control = operators::control();
-    let operator = operators::operator();
-    let literal = literal();
-    let comment = comment();
-    let ident_or_keyword = ident_or_keyword();
-
-    let error = any::<'src, &'src str, LexerExtra>()
-        .to_slice()
-        .map(|err| TokenKind::Error(Intern::new(err.into())));
-
-    let token_kind = choice((control, operator, literal, comment, ident_or_keyword, error));
-
-    token_kind
-        .padded()
-        .map_with(|kind, extra| (kind, extra.span(), extra.slice()))
+/// A token kind, along with its span and the text it came from.
+pub(crate) type Spanned<'src> = (TokenKind, TokenSpan, &'src str);
+
+pub(crate) fn lexer<'src>() -> impl Parser<'src, &'src str, Vec<Spanned<'src>>, LexerExtra> {
+    let token = || {
+        let operator = operators::operator();
+        let control = operators::control();
+        let literal = literal();
+        let comment = comment();
+        let ident_or_keyword = ident_or_keyword();
+
+        let error = any::<'src, &'src str, LexerExtra>()
+            .to_slice()
+            .map(|err| TokenKind::Error(Intern::new(err.into())));
+
+        choice((operator, control, literal, comment, ident_or_keyword, error))
+            .map_with(|kind, extra| (kind, extra.span(), extra.slice()))
+            .padded()
+    };
+
+    // The tokens of an interpolation inside a string, up to its closing brace. Braces inside have to be balanced, so
+    // that the closing brace can be told apart from any others.
+    let interpolated = recursive(|interpolated| {
+        let brace = |brace, kind| {
+            just(brace)
+                .map_with(move |_, extra| (kind, extra.span(), extra.slice()))
+                .padded()
+        };
+
+        let braced = brace('{', TokenKind::OpenDelim(Delimiter::Braces))
+            .then(interpolated.clone())
+            .then(brace('}', TokenKind::CloseDelim(Delimiter::Braces)).or_not())
+            .map(|((open, inner), close): ((_, Vec<_>), _)| {
+                std::iter::once(open).chain(inner).chain(close).collect()
+            });
+
+        let closing_brace = text::whitespace().then(just('}'));
+
+        choice((
+            string(interpolated).padded(),
+            braced,
+            token().and_is(closing_brace.not()).map(|token| vec![token]),
+        ))
         .repeated()
-        .collect()
+        .collect::<Vec<Vec<_>>>()
+        .map(|tokens| tokens.into_iter().flatten().collect())
+        .boxed()
+    });
+
+    // A shebang line, like `#!/usr/bin/env xva`, lets a file be run as a script. It's only allowed at the very
+    // start of the file, and means nothing to the language.
+    let shebang = just("#!").then(none_of('\n').repeated()).or_not();
+
+    shebang
+        .ignore_then(
+            string(interpolated)
+                .padded()
+                .or(token().map(|token| vec![token]))
+                .repeated()
+                .collect::<Vec<_>>()
+                .map(|tokens| tokens.into_iter().flatten().collect()),
+        )
+        // Tokens eat the whitespace around them, so this is only ever needed when there are no tokens at all.
+        .then_ignore(text::whitespace())
 }
 
 /// Transforms the input into a series of [`Token`]s that may be analysed further.
@@ -89,7 +143,8 @@ pub(crate) fn lexer<'src>(
 /// `debug` - TODO remove this
 ///
 /// # Returns
-/// `Vec<Token>`, plus a `Vec` of any errors that occurred while processing the input.
+/// `Vec<Token>`, plus a `Vec` of any errors that occurred while processing the input. Line breaks are only kept
+/// where they end an item, as [`TokenKind::Newline`]s.
 pub fn lex<'src>(
     input: &'src str,
     src_id: SourceId,
@@ -111,22 +166,59 @@ pub fn lex<'src>(
                 SourceSpan::new(src_id, CheapRange::new(start, end))
             },
             original: Intern::new(original.into()),
+            leading: vec![],
+            trailing: vec![],
         })
-        .collect();
+        .collect::<Vec<_>>();
+    let tokens = newline::insert(input, src_id, tokens);
 
     if debug {
         println!("tokens: {tokens:#?}");
     }
 
+    // The lexer doesn't know which file it's lexing, so attach that now.
+    let mut errors = errors
+        .into_iter()
+        .map(|err| {
+            let span = SourceSpan::new(src_id, err.span().range());
+            err.with_span(span)
+        })
+        .collect::<Vec<_>>();
+
+    errors.extend(ident::confusable_identifiers(&tokens));
+
     (tokens, errors)
 }
 
+/// Like [`lex`], but keeps the whitespace and comments around every token as [`Trivia`](crate::token::Trivia), so
+/// that the input can be rebuilt exactly from the tokens. Comments are only kept as trivia, rather than as tokens
+/// of their own, and a [`TokenKind::Eof`] token is added to hold the trivia at the end of the input.
+pub fn lex_lossless(input: &str, src_id: SourceId) -> (Vec<Token>, Vec<SyntaxError>) {
+    let (tokens, errors) = lex(input, src_id, false);
+    (trivia::attach(input, src_id, tokens), errors)
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use chumsky::Parser;
 
     use super::{lexer, TokenKind};
 
+    #[test]
+    fn shebang() {
+        let (tokens, errors) = super::lex("#!/usr/bin/env xva\nlet", 0u32.into(), false);
+        assert!(errors.is_empty());
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].kind, TokenKind::Let);
+        assert_eq!(tokens[0].end(), 22);
+    }
+
+    #[test]
+    fn shebang_only_at_start() {
+        let (tokens, _) = super::lex("let\n#!", 0u32.into(), false);
+        assert!(matches!(tokens[1].kind, TokenKind::Error(_)));
+    }
+
     pub(crate) fn assert_single_no_errors(input: &str, expected: &TokenKind) {
         let result = lexer().parse(input);
         let result_has_errors = result.has_errors();
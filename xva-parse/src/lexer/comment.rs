@@ -1,11 +1,17 @@
 use super::{LexerExtra, TokenKind};
 use chumsky::prelude::*;
 use internment::Intern;
+use xva_span::{CheapRange, SourceSpan, TokenSpan};
+
+use crate::{error::SyntaxErrorKind, SyntaxError};
 
 const LINE_COMMENT_SYMBOL: &str = "//";
 const DOC_COMMENT_SYMBOL: &str = "///";
+const BLOCK_COMMENT_START: &str = "/*";
+const BLOCK_DOC_COMMENT_START: &str = "/**";
+const BLOCK_COMMENT_END: &str = "*/";
 
-pub(crate) fn comment<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
+fn line_comment<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
     just(DOC_COMMENT_SYMBOL) // `///`, doc comment symbol has higher precedence
         .or(just(LINE_COMMENT_SYMBOL)) // `//`
         .then(any().and_is(just("\n").not()).repeated().to_slice()) // Then repeat until newline, into slice
@@ -16,11 +22,86 @@ pub(crate) fn comment<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerEx
         })
 }
 
+/// A block comment, `/* ... */`, including any block comments nested inside it. Produces whether the comment was
+/// closed.
+fn nested_block<'src>() -> impl Parser<'src, &'src str, bool, LexerExtra> {
+    recursive(|block| {
+        let text = any()
+            .and_is(just(BLOCK_COMMENT_START).not())
+            .and_is(just(BLOCK_COMMENT_END).not())
+            .ignored();
+
+        just(BLOCK_COMMENT_START)
+            .ignore_then(block.ignored().or(text).repeated())
+            .ignore_then(just(BLOCK_COMMENT_END).or_not())
+            .map(|end| end.is_some())
+    })
+}
+
+/// Block comments, `/* ... */`, and block doc comments, `/** ... */`. Block comments can be nested, so that code
+/// that already contains them can be commented out.
+fn block_comment<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
+    nested_block().validate(|closed, extra, emitter| {
+        let comment: &str = extra.slice();
+        let TokenSpan { start, .. } = extra.span();
+
+        // An unterminated comment runs to the end of the file, so it's reported where it starts. Any comments nested
+        // inside it are unterminated too, but only the outermost one is reported.
+        if !closed {
+            emitter.emit(SyntaxError::new(
+                SyntaxErrorKind::UnterminatedComment,
+                SourceSpan::new(
+                    0u32.into(),
+                    CheapRange::new(start, start + BLOCK_COMMENT_START.len()),
+                ),
+            ));
+        }
+
+        let content = comment.strip_suffix(BLOCK_COMMENT_END).filter(|_| closed);
+        let content = content.unwrap_or(comment);
+
+        // `/**/` is an empty comment, and `/***` starts a comment made to stand out, not doc comments.
+        match content.strip_prefix(BLOCK_DOC_COMMENT_START) {
+            Some(docs) if comment != "/**/" && !docs.starts_with('*') => {
+                TokenKind::DocComment(Intern::new(block_docs(docs)))
+            }
+            _ => TokenKind::Comment(Intern::new(content[BLOCK_COMMENT_START.len()..].into())),
+        }
+    })
+}
+
+/// Tidies the text of a block doc comment, which is often written with a `*` at the start of every line:
+/// ```text
+/// /**
+///  * Like this.
+///  */
+/// ```
+fn block_docs(docs: &str) -> String {
+    let lines = docs
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = line.strip_prefix('*').unwrap_or(line);
+            line.strip_prefix(' ').unwrap_or(line)
+        })
+        .collect::<Vec<_>>();
+
+    lines.join("\n").trim().to_string()
+}
+
+pub(crate) fn comment<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
+    block_comment().or(line_comment())
+}
+
 #[cfg(test)]
 mod tests {
     use internment::Intern;
+    use xva_span::CheapRange;
 
-    use crate::lexer::{tests::assert_single_no_errors, TokenKind};
+    use crate::{
+        error::SyntaxErrorKind,
+        lexer::{tests::assert_single_no_errors, TokenKind},
+    };
 
     #[test]
     fn comment() {
@@ -41,4 +122,49 @@ mod tests {
             &TokenKind::DocComment(interned),
         );
     }
+
+    #[test]
+    fn block_comments() {
+        assert_single_no_errors(
+            "/* a\nb */",
+            &TokenKind::Comment(Intern::from_ref(" a\nb ")),
+        );
+        assert_single_no_errors("/**/", &TokenKind::Comment(Intern::from_ref("")));
+    }
+
+    #[test]
+    fn nested_block_comments() {
+        let (tokens, errors) = crate::lex("/* a /* b */ c */ 1", 0u32.into(), false);
+        assert!(errors.is_empty());
+        assert_eq!(
+            tokens.iter().map(|tok| tok.kind).collect::<Vec<_>>(),
+            [
+                TokenKind::Comment(Intern::from_ref(" a /* b */ c ")),
+                TokenKind::Integer(1, None)
+            ]
+        );
+    }
+
+    #[test]
+    fn block_doc_comments() {
+        assert_single_no_errors(
+            "/** Short. */",
+            &TokenKind::DocComment(Intern::from_ref("Short.")),
+        );
+        assert_single_no_errors(
+            "/**\n * First line.\n *\n * Second line.\n */",
+            &TokenKind::DocComment(Intern::from_ref("First line.\n\nSecond line.")),
+        );
+    }
+
+    #[test]
+    fn unterminated_block_comments() {
+        let (tokens, errors) = crate::lex("1 /* a /* b */", 0u32.into(), false);
+        assert_eq!(tokens.len(), 2);
+
+        // Only the outermost comment is reported.
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind(), &SyntaxErrorKind::UnterminatedComment);
+        assert_eq!(errors[0].span().range(), CheapRange::new(2, 4));
+    }
 }
@@ -1,15 +1,214 @@
-use crate::utils::intern_str;
+use std::collections::{HashMap, HashSet};
 
-use super::{LexerExtra, TokenKind};
+use crate::{error::SyntaxErrorKind, token::Token, utils::intern_str, SyntaxError};
+
+use super::{literals::source_span, LexerExtra, TokenKind};
 use chumsky::prelude::*;
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::{confusable_detection::skeleton, MixedScript};
+
+/// Every reserved word, and the token it lexes as.
+///
+/// Keywords are only matched as whole identifiers, so `order` and `notice` are identifiers rather than `or` or
+/// `not` followed by something else.
+pub const KEYWORDS: &[(&str, TokenKind)] = &[
+    ("let", TokenKind::Let),
+    ("var", TokenKind::Var),
+    ("fn", TokenKind::Fn),
+    ("if", TokenKind::If),
+    ("else", TokenKind::Else),
+    ("while", TokenKind::While),
+    ("for", TokenKind::For),
+    ("in", TokenKind::In),
+    ("return", TokenKind::Return),
+    ("break", TokenKind::Break),
+    ("continue", TokenKind::Continue),
+    ("match", TokenKind::Match),
+    ("struct", TokenKind::Struct),
+    ("enum", TokenKind::Enum),
+    ("module", TokenKind::Module),
+    ("import", TokenKind::Import),
+    ("pub", TokenKind::Pub),
+    ("const", TokenKind::Const),
+    ("true", TokenKind::Boolean(true)),
+    ("false", TokenKind::Boolean(false)),
+    ("never", TokenKind::Never),
+    ("unit", TokenKind::Unit),
+    ("and", TokenKind::And),
+    ("or", TokenKind::Or),
+    ("not", TokenKind::Not),
+];
+
+/// The token for `word` if it is a reserved word.
+pub(crate) fn keyword(word: &str) -> Option<TokenKind> {
+    KEYWORDS
+        .iter()
+        .find(|(keyword, _)| *keyword == word)
+        .map(|(_, kind)| *kind)
+}
 
-const KEYWORD_LET: &str = "let";
-const KEYWORD_VAR: &str = "var";
+/// Whether `c` can come after the first character of an identifier: whether it has the `XID_Continue` property.
+pub fn is_ident_continue(c: char) -> bool {
+    unicode_ident::is_xid_continue(c)
+}
+
+/// An identifier, as defined by [UAX #31](https://www.unicode.org/reports/tr31/): a character with the
+/// `XID_Start` property or an underscore, followed by any number of characters with the `XID_Continue` property.
+fn ident<'src>() -> impl Parser<'src, &'src str, &'src str, LexerExtra> {
+    any()
+        .filter(|c: &char| unicode_ident::is_xid_start(*c) || *c == '_')
+        .then(any().filter(|c: &char| is_ident_continue(*c)).repeated())
+        .to_slice()
+}
 
+/// An identifier or a keyword.
+///
+/// Identifiers are normalized to NFC, so that two spellings of the same text, like `é` as one character or as `e`
+/// and a combining accent, are the same identifier. An identifier that mixes scripts, like Latin and Cyrillic, is
+/// reported with a warning, since it is likely to contain a lookalike character by mistake.
 pub(crate) fn ident_or_keyword<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    text::ident().map(|ident| match ident {
-        KEYWORD_LET => TokenKind::Let,
-        KEYWORD_VAR => TokenKind::Var,
-        _ => TokenKind::Identifier(intern_str(ident)),
-    }) //.and_is(keyword().not()) TODO
+    ident().validate(|ident: &str, extra, emitter| {
+        let ident = ident.nfc().collect::<String>();
+        if let Some(keyword) = keyword(&ident) {
+            return keyword;
+        }
+
+        if !ident.as_str().is_single_script() {
+            emitter.emit(SyntaxError::new(
+                SyntaxErrorKind::MixedScriptIdentifier(intern_str(&ident)),
+                source_span(extra.span()),
+            ));
+        }
+
+        TokenKind::Identifier(intern_str(&ident))
+    })
+}
+
+/// Warns about identifiers that are different, but could be mistaken for each other, like `pay` and `раy` (whose
+/// first two letters are Cyrillic). Each identifier is reported once, where it is first used.
+///
+/// Identifiers that are both ASCII are never reported, so that names like `l` and `I` can still be used together.
+pub(crate) fn confusable_identifiers(tokens: &[Token]) -> Vec<SyntaxError> {
+    let mut seen = HashMap::new();
+    let mut reported = HashSet::new();
+    let mut warnings = vec![];
+
+    for token in tokens {
+        let TokenKind::Identifier(name) = token.kind else {
+            continue;
+        };
+
+        let other = *seen.entry(confusable_skeleton(&name)).or_insert(name);
+
+        if other != name && !(name.is_ascii() && other.is_ascii()) && reported.insert(name) {
+            warnings.push(SyntaxError::new(
+                SyntaxErrorKind::ConfusableIdentifier { name, other },
+                token.span,
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// What `name` looks like, as far as telling it apart from other identifiers goes. Two identifiers with the same
+/// skeleton could be mistaken for each other.
+pub(crate) fn confusable_skeleton(name: &str) -> String {
+    skeleton(name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::SyntaxErrorKind, lexer::tests::assert_single_no_errors, token::TokenKind,
+        utils::intern_str,
+    };
+
+    use super::KEYWORDS;
+
+    #[test]
+    fn keywords() {
+        for (word, kind) in KEYWORDS {
+            assert_single_no_errors(word, kind);
+        }
+    }
+
+    #[test]
+    fn keywords_match_whole_words() {
+        for word in [
+            "order", "notice", "android", "iffy", "letter", "fnord", "true_", "unit2",
+        ] {
+            let (tokens, errors) = crate::lex(word, 0u32.into(), false);
+            assert!(errors.is_empty(), "{word}: {errors:?}");
+            assert_eq!(
+                tokens.into_iter().map(|tok| tok.kind).collect::<Vec<_>>(),
+                [TokenKind::Identifier(intern_str(word))],
+                "{word}"
+            );
+        }
+    }
+
+    #[test]
+    fn unicode_identifiers() {
+        for word in ["λ", "café", "名前", "größe", "_1", "_"] {
+            assert_single_no_errors(word, &TokenKind::Identifier(intern_str(word)));
+        }
+    }
+
+    #[test]
+    fn identifiers_are_normalized() {
+        let (decomposed, _) = crate::lex("cafe\u{301}", 0u32.into(), false);
+        let (composed, _) = crate::lex("caf\u{e9}", 0u32.into(), false);
+        assert_eq!(decomposed[0].kind, composed[0].kind);
+        assert_eq!(composed[0].kind, TokenKind::Identifier(intern_str("café")));
+    }
+
+    #[test]
+    fn mixed_script_identifiers() {
+        // The `а` is Cyrillic.
+        let (tokens, errors) = crate::lex("p\u{430}y", 0u32.into(), false);
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(
+            errors
+                .iter()
+                .map(|err| (err.kind().clone(), err.severity()))
+                .collect::<Vec<_>>(),
+            [(
+                SyntaxErrorKind::MixedScriptIdentifier(intern_str("p\u{430}y")),
+                crate::error::Severity::Warning
+            )]
+        );
+
+        // Digits and underscores belong to every script.
+        assert!(crate::lex("λ_1", 0u32.into(), false).1.is_empty());
+    }
+
+    #[test]
+    fn confusable_identifiers() {
+        let warnings = |input| {
+            super::confusable_identifiers(&crate::lex(input, 0u32.into(), false).0)
+                .into_iter()
+                .map(|err| {
+                    let range = err.span().range();
+                    (err.kind().clone(), range.start(), range.end())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // The `о` in the second name is Cyrillic.
+        assert_eq!(
+            warnings("foo f\u{43e}o"),
+            [(
+                SyntaxErrorKind::ConfusableIdentifier {
+                    name: intern_str("f\u{43e}o"),
+                    other: intern_str("foo")
+                },
+                4,
+                8
+            )]
+        );
+        // Each identifier is only reported where it is first used.
+        assert_eq!(warnings("foo f\u{43e}o f\u{43e}o").len(), 1);
+        assert_eq!(warnings("l I"), []);
+    }
 }
@@ -1,48 +1,57 @@
-use chumsky::{prelude::*, Parser};
+use chumsky::{input::Emitter, prelude::*, Parser};
 use internment::Intern;
+use xva_ast::ast::{FloatSuffix, IntegerSuffix};
 use xva_span::{CheapRange, SourceSpan, TokenSpan};
 
 use crate::{error::SyntaxErrorKind, SyntaxError};
 
-use super::{LexerExtra, TokenKind};
+use super::{LexerExtra, Spanned, TokenKind};
 
-fn hex_pattern<'src>() -> impl Parser<'src, &'src str, &'src str, LexerExtra> {
-    any() // Match any char
-        .filter(|c| match *c {
-            '0'..='9' | 'a'..='f' | 'A'..='F' => true, // Filter to only valid hexadecimal characters
-            _ => false,
-        })
-        .repeated() // Repeated N times
-        .to_slice() // Collect (::<String> by inference)
+/// Converts the span of something being lexed into a [`SourceSpan`], to report errors with.
+pub(super) fn source_span(span: TokenSpan) -> SourceSpan {
+    // todo pass down source id. Until then, lex() moves every error into the right file.
+    let TokenSpan { start, end, .. } = span;
+    SourceSpan::new(0u32.into(), CheapRange::new(start, end))
 }
 
+/// A Unicode escape: `\u` followed by the scalar value in hexadecimal, either in braces like `\u{1F600}`, or bare
+/// like `\u2502`. A bare value takes up to 6 digits, so the braces are needed when the next character is also a
+/// hexadecimal digit.
 fn unicode_sequence<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
+    let digits = || {
+        any()
+            .filter(char::is_ascii_hexdigit)
+            .repeated()
+            .at_most(6)
+            .to_slice()
+    };
+
     let token = just('\\')
         .ignore_then(just('u'))
-        .ignore_then(hex_pattern())
-        .validate(|val, extra, emitter| match u32::from_str_radix(&val, 16) {
-            Ok(uni) => match char::from_u32(uni) {
-                Some(c) => TokenKind::Char(c),
-                None => {
+        .ignore_then(digits().delimited_by(just('{'), just('}')).or(digits()))
+        .validate(|val: &str, extra, emitter| {
+            let slice: &str = extra.slice();
+            match u32::from_str_radix(val, 16) {
+                Ok(uni) => match char::from_u32(uni) {
+                    Some(c) => TokenKind::Char(c),
+                    None => {
+                        emitter.emit(SyntaxError::new(
+                            SyntaxErrorKind::InvalidUnicode(uni),
+                            source_span(extra.span()),
+                        ));
+
+                        TokenKind::Error(Intern::new(slice.into()))
+                    }
+                },
+                // There were no digits at all.
+                Err(_) => {
                     emitter.emit(SyntaxError::new(
-                        SyntaxErrorKind::InvalidUnicode(uni),
-                        {
-                            // todo pass down source id
-                            let TokenSpan { start, end, .. } = extra.span();
-                            SourceSpan::new(0u32.into(), CheapRange::new(start, end))
-                        }, // None,
+                        SyntaxErrorKind::InvalidEscape(Intern::new(slice.into())),
+                        source_span(extra.span()),
                     ));
 
-                    TokenKind::Error(Intern::new(extra.slice().into()))
+                    TokenKind::Error(Intern::new(slice.into()))
                 }
-            },
-            Err(_) => {
-                // emit_rich(
-                //     emitter,
-                //     extra.span(),
-                //     format!("`{val}` is an invalid integer representation."),
-                // );
-                TokenKind::Error(Intern::new(extra.slice().into()))
             }
         });
     token
@@ -55,6 +64,8 @@ fn ascii_sequence<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra>
                 .or(just('/')) // Forward slash
                 .or(just('"')) // Double quote
                 .or(just('\'')) // Single quote
+                .or(just('{')) // Braces, which would otherwise start an interpolation in a string
+                .or(just('}'))
                 .or(just('0').to('\0')) // Null char
                 .or(just('n').to('\n')) // Line feed
                 .or(just('r').to('\r')) // Carriage return
@@ -63,111 +74,395 @@ fn ascii_sequence<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra>
         .map(TokenKind::Char) // Map to type constructor
 }
 
-const BOOLEAN_TRUE: &str = "true";
-const BOOLEAN_FALSE: &str = "false";
-fn bool<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    just(BOOLEAN_TRUE)
-        .or(just(BOOLEAN_FALSE))
-        .map(|w| TokenKind::Boolean(w == BOOLEAN_TRUE))
+/// An escape sequence inside a string. Unknown escapes are reported, and leave nothing in the string.
+fn string_escape<'src>() -> impl Parser<'src, &'src str, Option<char>, LexerExtra> {
+    let known = unicode_sequence()
+        .or(ascii_sequence())
+        .map(|kind| match kind {
+            TokenKind::Char(c) => Some(c),
+            // The error has already been reported.
+            _ => None,
+        });
+
+    let unknown = just('\\')
+        .then(none_of("\r\n").or_not())
+        .to_slice()
+        .validate(|seq: &str, extra, emitter| {
+            emitter.emit(SyntaxError::new(
+                SyntaxErrorKind::InvalidEscape(Intern::new(seq.into())),
+                source_span(extra.span()),
+            ));
+            None
+        });
+
+    known.or(unknown)
+}
+
+const RAW_PREFIX: &str = "r";
+const QUOTE: &str = "\"";
+const TRIPLE_QUOTE: &str = "\"\"\"";
+
+/// A piece of a string literal.
+#[derive(Clone)]
+enum Part<'src> {
+    /// A character of text, or `None` if it was an escape that has already been reported as invalid.
+    Text(Option<char>),
+
+    /// An interpolation: the span of its opening brace, the tokens inside it, and the span of its closing brace if
+    /// there is one.
+    Interpolation(TokenSpan, Vec<Spanned<'src>>, Option<TokenSpan>),
+}
+
+/// A string between `quote`s, made of `body` characters and, wherever `body` doesn't allow a `{`, interpolations
+/// lexed by `interpolated`.
+///
+/// A string without interpolations is a single [`TokenKind::String`]. Otherwise, it's split into a
+/// [`TokenKind::StringStart`], a [`TokenKind::StringMiddle`] between each pair of interpolations and a
+/// [`TokenKind::StringEnd`], with the tokens of each interpolation between them.
+///
+/// If the closing quote is missing, the string runs as far as `body` allows and an error points at the opening quote.
+fn quoted<'src>(
+    prefix: &'static str,
+    quote: &'static str,
+    body: impl Parser<'src, &'src str, Option<char>, LexerExtra>,
+    interpolated: impl Parser<'src, &'src str, Vec<Spanned<'src>>, LexerExtra>,
+) -> impl Parser<'src, &'src str, Vec<Spanned<'src>>, LexerExtra> {
+    let opening = prefix.len() + quote.len();
+
+    let brace = |brace| just(brace).map_with(|_, extra| -> TokenSpan { extra.span() });
+    let interpolation = brace('{')
+        .then(interpolated)
+        .then(brace('}').or_not())
+        .map(|((open, tokens), close)| Part::Interpolation(open, tokens, close));
+
+    just(prefix)
+        .ignore_then(just(quote))
+        .ignore_then(
+            body.map(Part::Text)
+                .or(interpolation)
+                .repeated()
+                .collect::<Vec<_>>(),
+        )
+        .then(just(quote).or_not())
+        .validate(move |(parts, closing), extra, emitter| {
+            let span: TokenSpan = extra.span();
+            let slice: &'src str = extra.slice();
+            let start = span.start;
+
+            if closing.is_none() {
+                emitter.emit(SyntaxError::new(
+                    SyntaxErrorKind::UnterminatedString,
+                    source_span(TokenSpan::new(start, start + opening)),
+                ));
+            }
+
+            // A multi-line string that starts on a new line doesn't include that first line break, so that its
+            // contents can line up.
+            let skip = match &slice[opening..] {
+                rest if quote == TRIPLE_QUOTE && rest.starts_with("\r\n") => 2,
+                rest if quote == TRIPLE_QUOTE && rest.starts_with('\n') => 1,
+                _ => 0,
+            };
+
+            let mut tokens = vec![];
+            let mut text = String::new();
+            // Where the token for the current run of text starts, including the quote or brace before it.
+            let mut text_start = start;
+
+            for part in parts.into_iter().skip(skip) {
+                match part {
+                    Part::Text(c) => text.extend(c),
+                    Part::Interpolation(open, inner, close) => {
+                        let text = Intern::new(std::mem::take(&mut text));
+                        let kind = match tokens.is_empty() {
+                            true => TokenKind::StringStart(text),
+                            false => TokenKind::StringMiddle(text),
+                        };
+
+                        let text_span = TokenSpan::new(text_start, open.end);
+                        tokens.push((
+                            kind,
+                            text_span,
+                            &slice[text_start - start..open.end - start],
+                        ));
+                        text_start = match close {
+                            Some(close) => close.start,
+                            None => {
+                                emitter.emit(SyntaxError::new(
+                                    SyntaxErrorKind::UnclosedInterpolation,
+                                    source_span(open),
+                                ));
+                                inner.last().map_or(open.end, |(_, span, _)| span.end)
+                            }
+                        };
+                        tokens.extend(inner);
+                    }
+                }
+            }
+
+            let text = Intern::new(text);
+            let kind = match tokens.is_empty() {
+                true => TokenKind::String(text),
+                false => TokenKind::StringEnd(text),
+            };
+            tokens.push((
+                kind,
+                TokenSpan::new(text_start, span.end),
+                &slice[text_start - start..],
+            ));
+            tokens
+        })
+}
+
+/// String literals:
+/// - `"..."`, which can contain the same escapes as chars, but can't span multiple lines,
+/// - `"""..."""`, which can span multiple lines,
+/// - `r"..."` and `r"""..."""`, raw strings, where backslashes are just backslashes.
+///
+/// Strings other than raw strings can interpolate expressions between braces, like `"{a} + {b} = {a + b}"`. The
+/// tokens of each interpolation are lexed by `interpolated`. To write a brace without interpolating, escape it with a
+/// backslash: `"\{"`.
+pub(super) fn string<'src>(
+    interpolated: impl Parser<'src, &'src str, Vec<Spanned<'src>>, LexerExtra> + Clone,
+) -> impl Parser<'src, &'src str, Vec<Spanned<'src>>, LexerExtra> {
+    let not_triple_quote = || just(TRIPLE_QUOTE).not();
+
+    choice((
+        quoted(
+            RAW_PREFIX,
+            TRIPLE_QUOTE,
+            not_triple_quote().ignore_then(any().map(Some)),
+            interpolated.clone(),
+        ),
+        quoted(
+            RAW_PREFIX,
+            QUOTE,
+            none_of("\"\r\n").map(Some),
+            interpolated.clone(),
+        ),
+        quoted(
+            "",
+            TRIPLE_QUOTE,
+            not_triple_quote().ignore_then(string_escape().or(none_of("\\{").map(Some))),
+            interpolated.clone(),
+        ),
+        quoted(
+            "",
+            QUOTE,
+            string_escape().or(none_of("\"\\{\r\n").map(Some)),
+            interpolated,
+        ),
+    ))
 }
 
 fn char<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    let normal_char = any().filter(|c: &char| c.is_ascii()).map(TokenKind::Char);
+    let normal_char = any().map(TokenKind::Char);
     just('\'')
         .ignore_then(choice((unicode_sequence(), ascii_sequence(), normal_char)))
         .then_ignore(just('\''))
 }
 
-fn digits1<'src>() -> impl Parser<'src, &'src str, &'src str, LexerExtra> {
-    chumsky::text::digits(10).at_least(1).to_slice()
+/// Digits in the given radix, which may be separated by underscores, e.g. `1_000`. This may match only
+/// underscores, or nothing at all.
+fn separated_digits<'src>(radix: u32) -> impl Parser<'src, &'src str, &'src str, LexerExtra> {
+    any()
+        .filter(move |c: &char| c.is_digit(radix) || *c == '_')
+        .repeated()
+        .to_slice()
 }
 
-fn dot<'src>() -> impl Parser<'src, &'src str, (), LexerExtra> {
-    just('.').ignored()
+/// Decimal digits, starting with a digit rather than an underscore, so that `_1` is still an identifier.
+fn decimal_digits<'src>() -> impl Parser<'src, &'src str, &'src str, LexerExtra> {
+    any()
+        .filter(char::is_ascii_digit)
+        .then(separated_digits(10))
+        .to_slice()
 }
 
-fn maybe_dot<'src>() -> impl Parser<'src, &'src str, bool, LexerExtra> {
-    dot().or_not().map(|x| x.is_some())
+/// A decimal point. Two dots in a row are a range, like `0..10`, and a dot followed by a name is an access, like
+/// `1.max`, so neither of those count.
+fn dot<'src>() -> impl Parser<'src, &'src str, (), LexerExtra> {
+    just('.')
+        .then(
+            any()
+                .filter(|c: &char| *c == '.' || *c == '_' || c.is_alphabetic())
+                .not(),
+        )
+        .ignored()
 }
 
-fn maybe_digits1<'src>() -> impl Parser<'src, &'src str, Option<&'src str>, LexerExtra> {
-    digits1().or_not()
+/// A type suffix, e.g. `u8` in `10u8`. Anything that could continue the literal is taken, so that `10xyz` reports a
+/// bad suffix rather than lexing as `10` then `xyz`.
+fn suffix<'src>() -> impl Parser<'src, &'src str, &'src str, LexerExtra> {
+    any()
+        .filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_')
+        .repeated()
+        .to_slice()
 }
 
-const HEX_PREFIX: &str = "0x";
-fn hex_int<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    just(HEX_PREFIX)
-        .ignore_then(hex_pattern())
-        .map(|x| i128::from_str_radix(x, 16).unwrap())
-        .map(TokenKind::Integer)
-}
+/// Turns the pieces of a numeric literal into a token, reporting anything wrong with it.
+///
+/// `text` is the whole literal, `digits` is the number itself without any prefix or suffix, and `float` is whether
+/// it had a decimal point or an exponent. A literal with an error still lexes as a zero of the same kind, so that
+/// the parser doesn't report it again.
+fn number(
+    text: &str,
+    digits: &str,
+    radix: u32,
+    float: bool,
+    suffix: &str,
+    span: SourceSpan,
+    emitter: &mut Emitter<SyntaxError>,
+) -> TokenKind {
+    let mut error = |kind| {
+        emitter.emit(SyntaxError::new(kind, span));
+        match float {
+            true => TokenKind::Float(0.0, None),
+            false => TokenKind::Integer(0, None),
+        }
+    };
+
+    let digits = digits.replace('_', "");
+    if digits.is_empty() {
+        let prefix = &text[..text.len() - suffix.len()];
+        return error(SyntaxErrorKind::MissingDigits(Intern::new(prefix.into())));
+    }
 
-const OCTAL_PREFIX: &str = "0o";
-fn octal_int<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    let octal_pattern = any()
-        .filter(|c| match *c {
-            '0'..='7' => true,
-            _ => false,
-        })
-        .repeated()
-        .to_slice();
+    enum Suffix {
+        Integer(IntegerSuffix),
+        Float(FloatSuffix),
+    }
 
-    just(OCTAL_PREFIX)
-        .ignore_then(octal_pattern)
-        .map(|text| i128::from_str_radix(text, 8).unwrap())
-        .map(TokenKind::Integer)
+    let suffix = match suffix {
+        "" => None,
+        _ => match (
+            IntegerSuffix::from_name(suffix),
+            FloatSuffix::from_name(suffix),
+        ) {
+            (Some(int), _) if !float => Some(Suffix::Integer(int)),
+            (_, Some(fl)) if radix == 10 => Some(Suffix::Float(fl)),
+            _ => return error(SyntaxErrorKind::InvalidSuffix(Intern::new(suffix.into()))),
+        },
+    };
+
+    match suffix {
+        // A float suffix makes a float even without a decimal point, e.g. `2f32`.
+        Some(Suffix::Float(suffix)) => {
+            let value = digits.parse::<f64>().unwrap_or(f64::INFINITY);
+            let in_range = match suffix {
+                FloatSuffix::F32 => (value as f32).is_finite(),
+                FloatSuffix::F64 => value.is_finite(),
+            };
+
+            match in_range {
+                true => TokenKind::Float(value, Some(suffix)),
+                false => error(SyntaxErrorKind::LiteralOutOfRange(Intern::new(
+                    suffix.name().into(),
+                ))),
+            }
+        }
+        None if float => match digits.parse::<f64>() {
+            Ok(value) if value.is_finite() => TokenKind::Float(value, None),
+            _ => error(SyntaxErrorKind::LiteralOutOfRange(Intern::new(
+                "float".into(),
+            ))),
+        },
+        Some(Suffix::Integer(suffix)) => match u128::from_str_radix(&digits, radix) {
+            Ok(value) if value <= suffix.max_literal() => TokenKind::Integer(value, Some(suffix)),
+            _ => error(SyntaxErrorKind::LiteralOutOfRange(Intern::new(
+                suffix.name().into(),
+            ))),
+        },
+        // Integers without a suffix are `i128`s.
+        None => match u128::from_str_radix(&digits, radix) {
+            Ok(value) if value <= IntegerSuffix::I128.max_literal() => {
+                TokenKind::Integer(value, None)
+            }
+            _ => error(SyntaxErrorKind::LiteralOutOfRange(Intern::new(
+                "int".into(),
+            ))),
+        },
+    }
 }
 
+const HEX_PREFIX: &str = "0x";
+const OCTAL_PREFIX: &str = "0o";
 const BINARY_PREFIX: &str = "0b";
-fn binary_int<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    let binary_pattern = any()
-        .filter(|c| *c == '0' || *c == '1')
-        .repeated()
-        .to_slice();
 
-    just(BINARY_PREFIX)
-        .ignore_then(binary_pattern)
-        .map(|text| i128::from_str_radix(text, 2).unwrap())
-        .map(TokenKind::Integer)
+/// An integer with a radix prefix, e.g. `0xff`, `0o17` or `0b1010_1010`.
+fn prefixed_int<'src>(
+    prefix: &'static str,
+    radix: u32,
+) -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
+    just(prefix)
+        .ignore_then(separated_digits(radix))
+        .then(suffix())
+        .validate(move |(digits, suffix), extra, emitter| {
+            let text: &str = extra.slice();
+            let span = source_span(extra.span());
+            number(text, digits, radix, false, suffix, span, emitter)
+        })
 }
 
 fn hex_or_octal_or_binary_int<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    choice((hex_int(), octal_int(), binary_int()))
+    choice((
+        prefixed_int(HEX_PREFIX, 16),
+        prefixed_int(OCTAL_PREFIX, 8),
+        prefixed_int(BINARY_PREFIX, 2),
+    ))
 }
 
+/// A decimal integer or float, e.g. `1_000`, `1.5`, `2.` or `1.5e-3`.
 fn float_or_int<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    digits1() // Any digit, at least 1
-        .then(maybe_dot()) // Maybe a dot
-        .then(maybe_digits1()) // Maybe any digit, at least 1
-        .try_map_with(|((whole, dot), frac), _extra| {
-            if !dot {
-                // No dot, must be an int
-                Ok(TokenKind::Integer(str::parse::<i128>(whole).unwrap()))
-            } else {
-                if let Some(frac) = frac {
-                    // Dot found and fractional part found, must be a float
-                    // Allocation below is super expensive
-                    let mut temp = String::with_capacity(whole.len() + ".".len() + frac.len());
-                    temp.push_str(whole);
-                    temp.push('.');
-                    temp.push_str(frac);
-
-                    Ok(TokenKind::Float(str::parse::<f64>(temp.as_str()).unwrap()))
-                } else {
-                    // Dot found but no fractional part, still parses as a float.
-                    Ok(TokenKind::Float(str::parse::<f64>(whole).unwrap()))
-                }
-            }
+    let fraction = dot().then(separated_digits(10));
+    let exponent = one_of("eE")
+        .then(one_of("+-").or_not())
+        .then(decimal_digits());
+
+    decimal_digits()
+        .then(fraction.or_not())
+        .then(exponent.or_not())
+        .map_with(|((_, fraction), exponent), extra| {
+            let digits: &str = extra.slice();
+            (digits, fraction.is_some() || exponent.is_some())
+        })
+        .then(suffix())
+        .validate(|((digits, float), suffix), extra, emitter| {
+            let text: &str = extra.slice();
+            let span = source_span(extra.span());
+            number(text, digits, 10, float, suffix, span, emitter)
         })
 }
 
 pub(crate) fn literal<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
-    choice((bool(), char(), hex_or_octal_or_binary_int(), float_or_int()))
+    choice((char(), hex_or_octal_or_binary_int(), float_or_int()))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::lexer::{tests::assert_single_no_errors, TokenKind};
+    use internment::Intern;
+
+    use crate::{
+        error::SyntaxErrorKind,
+        lexer::{tests::assert_single_no_errors, TokenKind},
+        token::Delimiter,
+    };
+
+    fn string(s: &str) -> TokenKind {
+        TokenKind::String(Intern::from_ref(s))
+    }
+
+    /// The kinds and byte ranges of every error reported while lexing `input`.
+    fn errors(input: &str) -> Vec<(SyntaxErrorKind, usize, usize)> {
+        crate::lex(input, 0u32.into(), false)
+            .1
+            .into_iter()
+            .map(|err| {
+                let range = err.span().range();
+                (err.kind().clone(), range.start(), range.end())
+            })
+            .collect()
+    }
 
     #[test]
     fn bool_true() {
@@ -184,6 +479,13 @@ mod tests {
         assert_single_no_errors("'c'", &TokenKind::Char('c'));
     }
 
+    #[test]
+    fn unicode_chars() {
+        assert_single_no_errors("'é'", &TokenKind::Char('é'));
+        assert_single_no_errors("'λ'", &TokenKind::Char('λ'));
+        assert_single_no_errors("'😀'", &TokenKind::Char('😀'));
+    }
+
     #[test]
     fn ascii_carriage_return() {
         assert_single_no_errors(r"'\r'", &TokenKind::Char('\r'))
@@ -209,6 +511,124 @@ mod tests {
         assert_single_no_errors(r"'\u2502'", &TokenKind::Char('\u{2502}'));
     }
 
+    #[test]
+    fn braced_unicode_escapes() {
+        assert_single_no_errors(r"'\u{1F600}'", &TokenKind::Char('\u{1F600}'));
+    }
+
+    #[test]
+    fn strings() {
+        assert_single_no_errors(r#""""#, &string(""));
+        assert_single_no_errors(r#""hello world""#, &string("hello world"));
+    }
+
+    #[test]
+    fn string_escapes() {
+        assert_single_no_errors(
+            r#""\"quoted\"\t\\\n\u2502\u{1F600}1""#,
+            &string("\"quoted\"\t\\\n\u{2502}\u{1F600}1"),
+        );
+    }
+
+    #[test]
+    fn raw_strings() {
+        assert_single_no_errors(r#"r"C:\path\n""#, &string(r"C:\path\n"));
+    }
+
+    #[test]
+    fn multi_line_strings() {
+        assert_single_no_errors(
+            "\"\"\"\nline \"one\"\n\\tline two\n\"\"\"",
+            &string("line \"one\"\n\tline two\n"),
+        );
+        assert_single_no_errors("\"\"\"a\"\"\"", &string("a"));
+        assert_single_no_errors("r\"\"\"\r\n\\t\n\"\"\"", &string("\\t\n"));
+    }
+
+    #[test]
+    fn interpolated_strings() {
+        let kinds = |input| {
+            crate::lex(input, 0u32.into(), false)
+                .0
+                .into_iter()
+                .map(|token| token.kind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kinds(r#""a {1} b { {2} } c""#),
+            [
+                TokenKind::StringStart(Intern::from_ref("a ")),
+                TokenKind::Integer(1, None),
+                TokenKind::StringMiddle(Intern::from_ref(" b ")),
+                TokenKind::OpenDelim(Delimiter::Braces),
+                TokenKind::Integer(2, None),
+                TokenKind::CloseDelim(Delimiter::Braces),
+                TokenKind::StringEnd(Intern::from_ref(" c")),
+            ]
+        );
+        assert_eq!(
+            kinds(r#""{"{1}"}""#),
+            [
+                TokenKind::StringStart(Intern::from_ref("")),
+                TokenKind::StringStart(Intern::from_ref("")),
+                TokenKind::Integer(1, None),
+                TokenKind::StringEnd(Intern::from_ref("")),
+                TokenKind::StringEnd(Intern::from_ref("")),
+            ]
+        );
+
+        // Escaped braces and raw strings don't interpolate.
+        assert_single_no_errors(r#""\{1\}""#, &string("{1}"));
+        assert_single_no_errors(r#"r"{1}""#, &string("{1}"));
+    }
+
+    #[test]
+    fn unclosed_interpolation() {
+        assert_eq!(
+            errors(r#""a {1"#),
+            [
+                (SyntaxErrorKind::UnterminatedString, 0, 1),
+                (SyntaxErrorKind::UnclosedInterpolation, 3, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_strings() {
+        assert_eq!(
+            errors("let s = \"abc\nlet t = 1"),
+            [(SyntaxErrorKind::UnterminatedString, 8, 9)]
+        );
+        assert_eq!(
+            errors("r\"\"\"abc\n"),
+            [(SyntaxErrorKind::UnterminatedString, 0, 4)]
+        );
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        assert_eq!(
+            errors(r#""a\qb\u""#),
+            [
+                (
+                    SyntaxErrorKind::InvalidEscape(Intern::from_ref(r"\q")),
+                    2,
+                    4
+                ),
+                (
+                    SyntaxErrorKind::InvalidEscape(Intern::from_ref(r"\u")),
+                    5,
+                    7
+                ),
+            ]
+        );
+        assert_eq!(
+            errors(r#""\u{110000}""#),
+            [(SyntaxErrorKind::InvalidUnicode(0x110000), 1, 11)]
+        );
+    }
+
     // #[test]
     // fn invalid_unicode() {
     //     let input = r"'\u12ffff'";
@@ -218,82 +638,82 @@ mod tests {
 
     #[test]
     fn int_zero() {
-        assert_single_no_errors("0", &TokenKind::Integer(0));
+        assert_single_no_errors("0", &TokenKind::Integer(0, None));
     }
 
     #[test]
     fn int_one() {
-        assert_single_no_errors("1", &TokenKind::Integer(1))
+        assert_single_no_errors("1", &TokenKind::Integer(1, None))
     }
 
     #[test]
     fn int_multi() {
-        assert_single_no_errors("123", &TokenKind::Integer(123))
+        assert_single_no_errors("123", &TokenKind::Integer(123, None))
     }
 
     #[test]
     fn int_leading_zero() {
-        assert_single_no_errors("0123", &TokenKind::Integer(123))
+        assert_single_no_errors("0123", &TokenKind::Integer(123, None))
     }
 
     #[test]
     fn hex_int_zero() {
-        assert_single_no_errors("0x0", &TokenKind::Integer(0));
+        assert_single_no_errors("0x0", &TokenKind::Integer(0, None));
     }
 
     #[test]
     fn hex_int_one() {
-        assert_single_no_errors("0x1", &TokenKind::Integer(1))
+        assert_single_no_errors("0x1", &TokenKind::Integer(1, None))
     }
 
     #[test]
     fn hex_int_multi() {
-        assert_single_no_errors("0x1aB", &TokenKind::Integer(427))
+        assert_single_no_errors("0x1aB", &TokenKind::Integer(427, None))
     }
 
     #[test]
     fn hex_int_leading_zero() {
-        assert_single_no_errors("0x01Ab", &TokenKind::Integer(427))
+        assert_single_no_errors("0x01Ab", &TokenKind::Integer(427, None))
     }
 
     #[test]
     fn octal_int_zero() {
-        assert_single_no_errors("0o0", &TokenKind::Integer(0));
+        assert_single_no_errors("0o0", &TokenKind::Integer(0, None));
     }
 
     #[test]
     fn octal_int_one() {
-        assert_single_no_errors("0o1", &TokenKind::Integer(1))
+        assert_single_no_errors("0o1", &TokenKind::Integer(1, None))
     }
 
     #[test]
     fn octal_int_multi() {
-        assert_single_no_errors("0o123", &TokenKind::Integer(83))
+        assert_single_no_errors("0o123", &TokenKind::Integer(83, None))
     }
 
     #[test]
     fn octal_int_leading_zero() {
-        assert_single_no_errors("0o0123", &TokenKind::Integer(83))
+        assert_single_no_errors("0o0123", &TokenKind::Integer(83, None))
     }
 
     #[test]
     fn binary_int_zero() {
-        assert_single_no_errors("0b0", &TokenKind::Integer(0));
+        assert_single_no_errors("0b0", &TokenKind::Integer(0, None));
     }
 
     #[test]
     fn binary_int_one() {
-        assert_single_no_errors("0b1", &TokenKind::Integer(1))
+        assert_single_no_errors("0b1", &TokenKind::Integer(1, None))
     }
 
     #[test]
     fn binary_int_multi() {
-        assert_single_no_errors("0b1010", &TokenKind::Integer(10))
+        assert_single_no_errors("0b1010", &TokenKind::Integer(10, None))
     }
 
     #[test]
     fn binary_int_leading_zero() {
-        assert_single_no_errors("0b01010", &TokenKind::Integer(10))
+        assert_single_no_errors("0b01010", &TokenKind::Integer(10, None))
     }
 
     #[test]
@@ -302,36 +722,120 @@ mod tests {
         // let (tokens, errs) = lexer().parse(input).into_output_errors();
         // println!("tokens: {tokens:#?}");
         // println!("erorrs: {errs:#?}");
-        assert_single_no_errors("0.0", &TokenKind::Float(0.0));
+        assert_single_no_errors("0.0", &TokenKind::Float(0.0, None));
     }
 
     #[test]
     fn float_zero_no_fraction() {
-        assert_single_no_errors("0.", &TokenKind::Float(0.0))
+        assert_single_no_errors("0.", &TokenKind::Float(0.0, None))
     }
 
     #[test]
     fn float_one() {
-        assert_single_no_errors("1.0", &TokenKind::Float(1.0))
+        assert_single_no_errors("1.0", &TokenKind::Float(1.0, None))
     }
 
     #[test]
     fn float_one_no_fraction() {
-        assert_single_no_errors("1.", &TokenKind::Float(1.))
+        assert_single_no_errors("1.", &TokenKind::Float(1., None))
     }
 
     #[test]
     fn float() {
-        assert_single_no_errors("1.2", &TokenKind::Float(1.2))
+        assert_single_no_errors("1.2", &TokenKind::Float(1.2, None))
     }
 
     #[test]
     fn float_multi() {
-        assert_single_no_errors("1.2340", &TokenKind::Float(1.234))
+        assert_single_no_errors("1.2340", &TokenKind::Float(1.234, None))
     }
 
     #[test]
     fn float_leading_zero() {
-        assert_single_no_errors("0.1234", &TokenKind::Float(0.1234))
+        assert_single_no_errors("0.1234", &TokenKind::Float(0.1234, None))
+    }
+
+    #[test]
+    fn digit_separators() {
+        assert_single_no_errors("1_000_000", &TokenKind::Integer(1_000_000, None));
+        assert_single_no_errors("0xff_ff", &TokenKind::Integer(0xffff, None));
+        assert_single_no_errors("0b1010_1010", &TokenKind::Integer(0b1010_1010, None));
+        assert_single_no_errors("1_000.000_5", &TokenKind::Float(1000.0005, None));
+    }
+
+    #[test]
+    fn exponents() {
+        assert_single_no_errors("1.5e-3", &TokenKind::Float(1.5e-3, None));
+        assert_single_no_errors("1e10", &TokenKind::Float(1e10, None));
+        assert_single_no_errors("2E+2", &TokenKind::Float(2e2, None));
+    }
+
+    #[test]
+    fn suffixes() {
+        use xva_ast::ast::{FloatSuffix, IntegerSuffix};
+
+        assert_single_no_errors("10u8", &TokenKind::Integer(10, Some(IntegerSuffix::U8)));
+        assert_single_no_errors("3i16", &TokenKind::Integer(3, Some(IntegerSuffix::I16)));
+        assert_single_no_errors(
+            "0xffu64",
+            &TokenKind::Integer(255, Some(IntegerSuffix::U64)),
+        );
+        assert_single_no_errors("2.0f32", &TokenKind::Float(2.0, Some(FloatSuffix::F32)));
+        assert_single_no_errors("2f64", &TokenKind::Float(2.0, Some(FloatSuffix::F64)));
+        assert_single_no_errors("1e3_f64", &TokenKind::Float(1e3, Some(FloatSuffix::F64)));
+
+        // The smallest value of a signed type is written by negating one past its maximum, which only the parser
+        // can tell apart from a literal that's too large.
+        assert_single_no_errors("128i8", &TokenKind::Integer(128, Some(IntegerSuffix::I8)));
+    }
+
+    #[test]
+    fn numbers_next_to_other_tokens() {
+        let kinds = |input| {
+            crate::lex(input, 0u32.into(), false)
+                .0
+                .into_iter()
+                .map(|token| token.kind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kinds("1.max"),
+            [
+                TokenKind::Integer(1, None),
+                TokenKind::Dot,
+                TokenKind::Identifier(Intern::from_ref("max"))
+            ]
+        );
+    }
+
+    #[test]
+    fn numeric_errors() {
+        let missing = |prefix: &str| SyntaxErrorKind::MissingDigits(Intern::from_ref(prefix));
+        let invalid = |suffix: &str| SyntaxErrorKind::InvalidSuffix(Intern::from_ref(suffix));
+        let range = |ty: &str| SyntaxErrorKind::LiteralOutOfRange(Intern::from_ref(ty));
+
+        assert_eq!(errors("0x"), [(missing("0x"), 0, 2)]);
+        assert_eq!(errors("0b_"), [(missing("0b_"), 0, 3)]);
+        assert_eq!(errors("0o9"), [(missing("0o"), 0, 3)]);
+        assert_eq!(errors("10xyz"), [(invalid("xyz"), 0, 5)]);
+        assert_eq!(errors("1e"), [(invalid("e"), 0, 2)]);
+        assert_eq!(errors("1.5u8"), [(invalid("u8"), 0, 5)]);
+        assert_eq!(errors("0xff32"), []);
+        assert_eq!(errors("0x1f32"), []);
+        assert_eq!(errors("0b12"), [(invalid("2"), 0, 4)]);
+        assert_eq!(errors("0x1_f64g"), [(invalid("g"), 0, 8)]);
+        assert_eq!(errors("256u8"), [(range("u8"), 0, 5)]);
+        assert_eq!(errors("129i8"), [(range("i8"), 0, 5)]);
+        assert_eq!(
+            errors("340282366920938463463374607431768211456u128"),
+            [(range("u128"), 0, 43)]
+        );
+        assert_eq!(
+            errors("1_000_000_000_000_000_000_000_000_000_000_000_000_000"),
+            [(range("int"), 0, 53)]
+        );
+        assert_eq!(errors("1e400"), [(range("float"), 0, 5)]);
+        assert_eq!(errors("1e39f32"), [(range("f32"), 0, 7)]);
     }
 }
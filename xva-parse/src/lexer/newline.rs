@@ -0,0 +1,192 @@
+//! Line breaks that end items.
+//!
+//! Items are separated by a `;` or by the end of a line, but only where an item could end: an expression can carry
+//! on over a line break after an operator, like
+//! ```text
+//! let x = 1 +
+//!     2
+//! ```
+//! Line breaks inside parentheses, brackets and interpolations never end anything. Inside braces they do, since
+//! braces hold items of their own.
+//!
+//! Comments don't get in the way: a line break inside a block comment counts as well, and since a doc comment always
+//! starts a new item, one after an item on the same line ends that item. Neither has a line break to be the
+//! [`TokenKind::Newline`], so it's an empty one instead.
+
+use internment::Intern;
+use xva_span::{CheapRange, SourceId, SourceSpan};
+
+use crate::token::{Delimiter, Token, TokenKind};
+
+/// Whether an item can end with a token of this kind. Error tokens can, so that a line with a mistake in it doesn't
+/// run on into the next one.
+fn ends_item(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Boolean(_)
+            | TokenKind::Char(_)
+            | TokenKind::String(_)
+            | TokenKind::StringEnd(_)
+            | TokenKind::Integer(..)
+            | TokenKind::Float(..)
+            | TokenKind::Identifier(_)
+            | TokenKind::CloseDelim(_)
+            | TokenKind::Return
+            | TokenKind::Break
+            | TokenKind::Continue
+            | TokenKind::Never
+            | TokenKind::Unit
+            | TokenKind::CharError(_)
+            | TokenKind::Error(_)
+    )
+}
+
+/// Adds a [`TokenKind::Newline`] at the first line break after each token that can end an item, when that token is
+/// at the top level or directly inside braces. Comments in between are skipped over, so `1 // one` still ends at the
+/// end of its line.
+pub(super) fn insert(input: &str, src_id: SourceId, tokens: Vec<Token>) -> Vec<Token> {
+    let newline = |from: usize, to: usize| Token {
+        kind: TokenKind::Newline,
+        span: SourceSpan::new(src_id, CheapRange::new(from, to)),
+        original: Intern::new(input[from..to].into()),
+        leading: vec![],
+        trailing: vec![],
+    };
+
+    // The first line break between `start` and `end`, with the `\r` before it if there is one.
+    let line_break = |start: usize, end: usize| {
+        let at = start + input[start..end].find('\n')?;
+        let from = match input[start..at].ends_with('\r') {
+            true => at - 1,
+            false => at,
+        };
+
+        Some(newline(from, at + 1))
+    };
+
+    // What each token is nested inside of, innermost last. `None` is an interpolated string.
+    let mut open: Vec<Option<Delimiter>> = vec![];
+    // The end of the last token that can end an item, or of the comments after it, and whether there was a line
+    // break in any of those comments.
+    let mut pending: Option<(usize, bool)> = None;
+    let mut result = Vec::with_capacity(tokens.len());
+
+    for token in tokens {
+        if let Some((end, broken)) = pending {
+            let separator = match line_break(end, token.start()) {
+                Some(newline) => Some(newline),
+                None if broken || matches!(token.kind, TokenKind::DocComment(_)) => {
+                    Some(newline(end, end))
+                }
+                None => None,
+            };
+
+            if let Some(separator) = separator {
+                result.push(separator);
+                pending = None;
+            }
+        }
+
+        match token.kind {
+            TokenKind::Comment(_) => {
+                let broken = token.original.contains('\n');
+                pending = pending.map(|(_, before)| (token.end(), before || broken));
+                result.push(token);
+                continue;
+            }
+            TokenKind::OpenDelim(delim) => open.push(Some(delim)),
+            TokenKind::CloseDelim(_) if matches!(open.last(), Some(Some(_))) => {
+                open.pop();
+            }
+            TokenKind::StringStart(_) => open.push(None),
+            TokenKind::StringEnd(_) => while let Some(Some(_)) = open.pop() {},
+            _ => {}
+        }
+
+        let items_allowed = matches!(open.last(), None | Some(Some(Delimiter::Braces)));
+        pending = (items_allowed && ends_item(&token.kind)).then(|| (token.end(), false));
+        result.push(token);
+    }
+
+    // The end of the input ends an item anyway, so only an actual line break is needed here.
+    if let Some(newline) = pending.and_then(|(end, _)| line_break(end, input.len())) {
+        result.push(newline);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use xva_span::CheapRange;
+
+    use crate::{lexer::lex, token::TokenKind};
+
+    /// The text of the tokens lexed from `input`, with `Newline`s written as `\n`.
+    fn text(input: &str) -> String {
+        let (tokens, errors) = lex(input, 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+        tokens
+            .iter()
+            .map(|tok| match tok.kind {
+                TokenKind::Newline => "\\n".to_string(),
+                _ => tok.original.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn after_items() {
+        assert_eq!(text("let x = 1\nx\n"), "let x = 1 \\n x \\n");
+        assert_eq!(text("f()\n\n\nreturn\n"), "f ( ) \\n return \\n");
+    }
+
+    #[test]
+    fn not_after_operators() {
+        assert_eq!(text("1 +\n2"), "1 + 2");
+        assert_eq!(text("let x =\n  1"), "let x = 1");
+        assert_eq!(text("/// Docs.\nfn f() {\n}"), "/// Docs. fn f ( ) { }");
+    }
+
+    #[test]
+    fn only_where_items_are() {
+        assert_eq!(text("f(1,\n2\n)"), "f ( 1 , 2 )");
+        assert_eq!(text("{\n1\n}\n"), "{ 1 \\n } \\n");
+        assert_eq!(text("(1 + {\n2\n}\n)"), "( 1 + { 2 \\n } )");
+        assert_eq!(text("\"a{1\n+ 2}b\"\n"), "\"a{ 1 + 2 }b\" \\n");
+    }
+
+    #[test]
+    fn after_comments() {
+        assert_eq!(
+            text("1 // one\n2 /* two */\n"),
+            "1 // one \\n 2 /* two */ \\n"
+        );
+        assert_eq!(text("1 /* one\n */ 2"), "1 /* one\n */ \\n 2");
+        assert_eq!(
+            text("1 /* one */ 2 /* two\n */\n"),
+            "1 /* one */ 2 /* two\n */ \\n"
+        );
+    }
+
+    #[test]
+    fn before_doc_comments() {
+        assert_eq!(text("1 /// Two.\n2"), "1 \\n /// Two. 2");
+        assert_eq!(text("1 /** Two. */ 2"), "1 \\n /** Two. */ 2");
+        assert_eq!(text("1\n/// Two.\n2"), "1 \\n /// Two. 2");
+        assert_eq!(text("let x =\n/// Docs.\n1"), "let x = /// Docs. 1");
+
+        let (tokens, _) = lex("1 /// Two.\n2", 0u32.into(), false);
+        assert_eq!(tokens[1].kind, TokenKind::Newline);
+        assert_eq!(tokens[1].span.range(), CheapRange::new(1, 1));
+    }
+
+    #[test]
+    fn crlf() {
+        let (tokens, _) = lex("1\r\n2", 0u32.into(), false);
+        assert_eq!(tokens[1].kind, TokenKind::Newline);
+        assert_eq!(tokens[1].span.range(), CheapRange::new(1, 3));
+        assert_eq!(tokens[1].original.as_str(), "\r\n");
+    }
+}
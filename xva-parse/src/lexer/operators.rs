@@ -4,18 +4,21 @@ use crate::token::Delimiter;
 
 use super::{LexerExtra, TokenKind};
 
-const VALID_CONTROL_CHARS: &str = r#"(){}:,."#;
-const VALID_SINGLE_CHAR_OPERATORS: &str = r#"+-*%<>&|^"#;
+const VALID_CONTROL_CHARS: &str = r#"(){}[]:,.;"#;
+const VALID_SINGLE_CHAR_OPERATORS: &str = r#"+-*%<>&|^?"#;
 
 pub(crate) fn control<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
     one_of(VALID_CONTROL_CHARS).map(|c| match c {
         '(' => TokenKind::OpenDelim(Delimiter::Parentheses),
         ')' => TokenKind::CloseDelim(Delimiter::Parentheses),
         '{' => TokenKind::OpenDelim(Delimiter::Braces),
-        '}' => TokenKind::CloseDelim(Delimiter::Parentheses),
+        '}' => TokenKind::CloseDelim(Delimiter::Braces),
+        '[' => TokenKind::OpenDelim(Delimiter::SquareBrackets),
+        ']' => TokenKind::CloseDelim(Delimiter::SquareBrackets),
         ':' => TokenKind::Colon,
         ',' => TokenKind::Comma,
         '.' => TokenKind::Dot,
+        ';' => TokenKind::Semicolon,
         // '"' => Token::DoubleQuote,       // Quotes are handled by literals
         // '\'' => Token::SingleQuote,
         // '=' => Token::Equals,
@@ -23,6 +26,10 @@ pub(crate) fn control<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerEx
     })
 }
 
+/// Operators, and punctuation made of more than one character.
+///
+/// Where one operator starts with another, like `<<=`, `<<` and `<`, the longest one that matches wins. This
+/// parser has to be tried before [`control`], so that `::` and `..` aren't lexed as two `:`s or `.`s.
 pub(crate) fn operator<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerExtra> {
     let single_char_op = one_of(VALID_SINGLE_CHAR_OPERATORS).map(|op| match op {
         '+' => TokenKind::Plus,
@@ -35,9 +42,16 @@ pub(crate) fn operator<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerE
         '&' => TokenKind::Ampersand,
         '|' => TokenKind::Pipe,
         '^' => TokenKind::Caret,
+        '?' => TokenKind::Question,
         _ => unreachable!("Not an operator"),
     });
 
+    let three_char_op = choice((
+        just("<<=").to(TokenKind::DoubleLeftAngleEquals),
+        just(">>=").to(TokenKind::DoubleRightAngleEquals),
+        just("..=").to(TokenKind::DoubleDotEquals),
+    ));
+
     let multi_char_op = choice((
         just("**").to(TokenKind::DoubleStar),
         just("++").to(TokenKind::Increment),
@@ -48,23 +62,40 @@ pub(crate) fn operator<'src>() -> impl Parser<'src, &'src str, TokenKind, LexerE
         just("<=").to(TokenKind::LessThanEquals),
         // just("==").to(Token::DoubleEquals),
         just("!=").to(TokenKind::NotEquals),
-        just("and").to(TokenKind::And),
-        just("or").to(TokenKind::Or),
-        just("not").to(TokenKind::Not),
+        just("->").to(TokenKind::Arrow),
+        just("::").to(TokenKind::DoubleColon),
+        just("..").to(TokenKind::DoubleDot),
     ));
 
-    // `=` and `==` are a weird conflicting case, they are handled specially outside
+    let compound_assignment = choice((
+        just("+=").to(TokenKind::PlusEquals),
+        just("-=").to(TokenKind::MinusEquals),
+        just("*=").to(TokenKind::StarEquals),
+        just("/=").to(TokenKind::SlashEquals),
+        just("%=").to(TokenKind::PercentEquals),
+        just("&=").to(TokenKind::AmpersandEquals),
+        just("|=").to(TokenKind::PipeEquals),
+        just("^=").to(TokenKind::CaretEquals),
+    ));
+
+    // `=`, `==` and `=>` are a weird conflicting case, they are handled specially outside
     // the parsers above.
-    let double_or_single_equals = just('=')
-        .then(just('=').or_not())
-        .map(|(_, second)| second.map_or_else(|| TokenKind::Equals, |_| TokenKind::DoubleEquals));
+    let equals = just('=')
+        .ignore_then(one_of("=>").or_not())
+        .map(|second| match second {
+            Some('=') => TokenKind::DoubleEquals,
+            Some('>') => TokenKind::FatArrow,
+            _ => TokenKind::Equals,
+        });
 
-    // `/` conflicts with comments
-    let slash = just('/').then(just('/').not()).to(TokenKind::Slash);
+    // `/` conflicts with comments, both `//` and `/*`
+    let slash = just('/').then(one_of("/*").not()).to(TokenKind::Slash);
 
     choice((
+        three_char_op,
+        compound_assignment,
         slash,
-        double_or_single_equals,
+        equals,
         multi_char_op,
         single_char_op,
     ))
@@ -116,5 +147,104 @@ mod tests {
         assert_single_no_errors("and", &TokenKind::And);
         assert_single_no_errors("or", &TokenKind::Or);
         assert_single_no_errors("not", &TokenKind::Not);
+        assert_single_no_errors("->", &TokenKind::Arrow);
+        assert_single_no_errors("=>", &TokenKind::FatArrow);
+        assert_single_no_errors("::", &TokenKind::DoubleColon);
+        assert_single_no_errors("..", &TokenKind::DoubleDot);
+        assert_single_no_errors("..=", &TokenKind::DoubleDotEquals);
+    }
+
+    #[test]
+    fn punctuation() {
+        assert_single_no_errors("[", &TokenKind::OpenDelim(Delimiter::SquareBrackets));
+        assert_single_no_errors("]", &TokenKind::CloseDelim(Delimiter::SquareBrackets));
+        assert_single_no_errors(".", &TokenKind::Dot);
+        assert_single_no_errors(";", &TokenKind::Semicolon);
+        assert_single_no_errors("?", &TokenKind::Question);
+    }
+
+    #[test]
+    fn compound_assignments() {
+        assert_single_no_errors("+=", &TokenKind::PlusEquals);
+        assert_single_no_errors("-=", &TokenKind::MinusEquals);
+        assert_single_no_errors("*=", &TokenKind::StarEquals);
+        assert_single_no_errors("/=", &TokenKind::SlashEquals);
+        assert_single_no_errors("%=", &TokenKind::PercentEquals);
+        assert_single_no_errors("<<=", &TokenKind::DoubleLeftAngleEquals);
+        assert_single_no_errors(">>=", &TokenKind::DoubleRightAngleEquals);
+        assert_single_no_errors("&=", &TokenKind::AmpersandEquals);
+        assert_single_no_errors("|=", &TokenKind::PipeEquals);
+        assert_single_no_errors("^=", &TokenKind::CaretEquals);
+    }
+
+    #[test]
+    fn longest_match() {
+        let kinds = |input| {
+            crate::lex(input, 0u32.into(), false)
+                .0
+                .into_iter()
+                .map(|tok| tok.kind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kinds("<<= << <= <"),
+            [
+                TokenKind::DoubleLeftAngleEquals,
+                TokenKind::DoubleLeftAngle,
+                TokenKind::LessThanEquals,
+                TokenKind::LeftAngle
+            ]
+        );
+        assert_eq!(
+            kinds("..=..:::"),
+            [
+                TokenKind::DoubleDotEquals,
+                TokenKind::DoubleDot,
+                TokenKind::DoubleColon,
+                TokenKind::Colon
+            ]
+        );
+        assert_eq!(
+            kinds("==>=>"),
+            [
+                TokenKind::DoubleEquals,
+                TokenKind::GreaterThanEquals,
+                TokenKind::RightAngle
+            ]
+        );
+        assert_eq!(
+            kinds("--->-="),
+            [
+                TokenKind::Decrement,
+                TokenKind::Arrow,
+                TokenKind::MinusEquals
+            ]
+        );
+        assert_eq!(
+            kinds("**=/=//"),
+            [
+                TokenKind::DoubleStar,
+                TokenKind::Equals,
+                TokenKind::SlashEquals,
+                TokenKind::Comment(internment::Intern::from_ref(""))
+            ]
+        );
+    }
+
+    #[test]
+    fn ranges_are_not_floats() {
+        let (tokens, _) = crate::lex("0..10 1.5..=2.", 0u32.into(), false);
+        assert_eq!(
+            tokens.into_iter().map(|tok| tok.kind).collect::<Vec<_>>(),
+            [
+                TokenKind::Integer(0, None),
+                TokenKind::DoubleDot,
+                TokenKind::Integer(10, None),
+                TokenKind::Float(1.5, None),
+                TokenKind::DoubleDotEquals,
+                TokenKind::Float(2., None),
+            ]
+        );
     }
 }
@@ -0,0 +1,207 @@
+use internment::Intern;
+use xva_span::{CheapRange, SourceId, SourceSpan};
+
+use crate::token::{Token, TokenKind, Trivia, TriviaKind};
+
+const SHEBANG: &str = "#!";
+
+/// Splits the text between two tokens into trivia.
+fn gap(input: &str, src_id: SourceId, start: usize, end: usize) -> Vec<Trivia> {
+    let mut trivia = vec![];
+    let mut push = |kind, start: usize, end: usize| {
+        trivia.push(Trivia {
+            kind,
+            span: SourceSpan::new(src_id, CheapRange::new(start, end)),
+            text: Intern::new(input[start..end].into()),
+        })
+    };
+
+    let mut pos = start;
+    if start == 0 && input[..end].starts_with(SHEBANG) {
+        pos = input[..end].find('\n').unwrap_or(end);
+        push(TriviaKind::Shebang, 0, pos);
+    }
+
+    while pos < end {
+        let rest = &input[pos..end];
+        let (kind, len) = match rest.chars().next() {
+            Some(c) if c.is_whitespace() => {
+                let run = rest
+                    .find(|c: char| !c.is_whitespace())
+                    .unwrap_or(rest.len());
+                let len = rest[..run].find('\n').map_or(run, |newline| newline + 1);
+                (TriviaKind::Whitespace, len)
+            }
+            _ => {
+                let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                (TriviaKind::Skipped, len)
+            }
+        };
+
+        push(kind, pos, pos + len);
+        pos += len;
+    }
+
+    trivia
+}
+
+/// Attaches the text between tokens, and comments, to the tokens as trivia. A [`TokenKind::Eof`] token is added to
+/// the end to hold whatever comes after the last token.
+pub(super) fn attach(input: &str, src_id: SourceId, tokens: Vec<Token>) -> Vec<Token> {
+    let mut pieces = vec![];
+    let mut offset = 0;
+
+    for token in tokens {
+        pieces.extend(
+            gap(input, src_id, offset, token.start())
+                .into_iter()
+                .map(Err),
+        );
+        offset = token.end();
+
+        pieces.push(match token.kind {
+            TokenKind::Comment(_) => Err(Trivia {
+                kind: TriviaKind::Comment,
+                span: token.span,
+                text: token.original,
+            }),
+            _ => Ok(token),
+        });
+    }
+
+    pieces.extend(gap(input, src_id, offset, input.len()).into_iter().map(Err));
+    pieces.push(Ok(Token {
+        kind: TokenKind::Eof,
+        span: SourceSpan::new(src_id, CheapRange::new(input.len(), input.len())),
+        original: Intern::new(String::new()),
+        leading: vec![],
+        trailing: vec![],
+    }));
+
+    let mut tokens = vec![];
+    let mut leading = vec![];
+    let mut pieces = pieces.into_iter().peekable();
+
+    while let Some(piece) = pieces.next() {
+        let mut token = match piece {
+            Ok(token) => token,
+            Err(trivia) => {
+                leading.push(trivia);
+                continue;
+            }
+        };
+
+        // A line break is the end of its own line, so whatever comes after it leads into the next token.
+        token.leading = std::mem::take(&mut leading);
+        while let Some(Err(trivia)) = pieces.next_if(|piece| {
+            piece.is_err() && !matches!(token.kind, TokenKind::Newline)
+        }) {
+            let newline = trivia.text.contains('\n');
+            token.trailing.push(trivia);
+            if newline {
+                break;
+            }
+        }
+
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::token::{TokenKind, TriviaKind};
+
+    /// The kind of each token, with the kind and text of its leading and trailing trivia.
+    fn trivia(input: &str) -> Vec<(&'static str, Vec<String>, Vec<String>)> {
+        let describe = |trivia: &[crate::token::Trivia]| {
+            trivia
+                .iter()
+                .map(|trivia| format!("{} {:?}", trivia.kind.name(), trivia.text.as_str()))
+                .collect()
+        };
+
+        crate::lex_lossless(input, 0u32.into())
+            .0
+            .iter()
+            .map(|token| {
+                (
+                    token.kind.name(),
+                    describe(&token.leading),
+                    describe(&token.trailing),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn trailing_trivia_ends_at_the_newline() {
+        assert_eq!(
+            trivia("let x = // x\n\n  1 "),
+            [
+                ("let", vec![], vec![r#"whitespace " ""#.into()]),
+                ("identifier", vec![], vec![r#"whitespace " ""#.into()]),
+                (
+                    "equals",
+                    vec![],
+                    vec![
+                        r#"whitespace " ""#.into(),
+                        r#"comment "// x""#.into(),
+                        r#"whitespace "\n""#.into()
+                    ]
+                ),
+                (
+                    "integer",
+                    vec![r#"whitespace "\n""#.into(), r#"whitespace "  ""#.into()],
+                    vec![r#"whitespace " ""#.into()]
+                ),
+                ("eof", vec![], vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn newlines_have_no_trailing_trivia() {
+        assert_eq!(
+            trivia("1 // one\n  2"),
+            [
+                (
+                    "integer",
+                    vec![],
+                    vec![r#"whitespace " ""#.into(), r#"comment "// one""#.into()]
+                ),
+                ("newline", vec![], vec![]),
+                ("integer", vec![r#"whitespace "  ""#.into()], vec![]),
+                ("eof", vec![], vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn shebang_and_trailing_comments() {
+        assert_eq!(
+            trivia("#!/usr/bin/env xva\n1\n/* end */"),
+            [
+                (
+                    "integer",
+                    vec![
+                        r##"shebang "#!/usr/bin/env xva""##.into(),
+                        r#"whitespace "\n""#.into()
+                    ],
+                    vec![]
+                ),
+                ("newline", vec![], vec![]),
+                ("eof", vec![r#"comment "/* end */""#.into()], vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn doc_comments_are_tokens() {
+        let (tokens, _) = crate::lex_lossless("/// Docs\nlet", 0u32.into());
+        assert!(matches!(tokens[0].kind, TokenKind::DocComment(_)));
+        assert_eq!(tokens[0].trailing[0].kind, TriviaKind::Whitespace);
+        assert_eq!(tokens[1].kind, TokenKind::Let);
+    }
+}
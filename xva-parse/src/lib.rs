@@ -1,6 +1,8 @@
 #![deny(unused_crate_dependencies)]
 
+pub mod cst;
 pub mod error;
+pub mod incremental;
 
 pub(crate) mod lexer;
 pub(crate) mod macros;
@@ -10,6 +12,7 @@ pub(crate) mod traits;
 pub(crate) mod utils;
 
 pub use error::SyntaxError;
+pub use lexer::{is_ident_continue, lex, lex_lossless, KEYWORDS};
 
 #[cfg(test)]
 mod tests {}
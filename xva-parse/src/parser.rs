@@ -16,19 +16,28 @@
 use chumsky::prelude::*;
 use std::sync::atomic::{AtomicI64, Ordering};
 use xva_ast::{ast::Item, node_id::NodeId};
-use xva_span::SourceId;
+use xva_span::{CheapRange, SourceId, SourceSpan};
 
+mod docs;
 mod expr;
+mod func;
 mod ident;
 mod sigil;
 mod stmt;
 mod ty;
 
-use self::{expr::expression, stmt::statement};
+use self::{
+    docs::{attach_docs, docs},
+    expr::expression,
+    func::function,
+    sigil::{close_brace, separator},
+    stmt::statement,
+};
 use crate::{
+    cst::{self, SyntaxNode},
     error::{ErrorPattern, SyntaxError, SyntaxErrorKind},
-    lexer::lex,
-    token::Token,
+    lexer::{lex, lex_lossless},
+    token::{Delimiter, Token, TokenKind},
 };
 
 pub(self) static NODE_ID_SEED: AtomicI64 = AtomicI64::new(0);
@@ -43,37 +52,344 @@ pub fn parse<'src>(
     debug_lexer: bool,
 ) -> (Vec<Item>, Vec<SyntaxError>) {
     let (tokens, lex_errors) = lex(input, src_id, debug_lexer);
+    let (tree, parse_errors) = parse_tokens(&tokens, src_id);
+    (
+        tree.into_iter().map(|(item, _)| item).collect(),
+        lex_errors.into_iter().chain(parse_errors).collect(),
+    )
+}
+
+/// Like [`parse`], but keeps every byte of the input in a [`SyntaxNode`], a concrete syntax tree that can rebuild the
+/// input exactly. The abstract syntax tree can be derived from it with [`SyntaxNode::ast`].
+pub fn parse_lossless(input: &str, src_id: SourceId) -> (SyntaxNode, Vec<SyntaxError>) {
+    let (tokens, lex_errors) = lex_lossless(input, src_id);
+    let (tree, parse_errors) = parse_tokens(&tokens, src_id);
+    let cst = cst::build(&tree, tokens, src_id);
+    (cst, lex_errors.into_iter().chain(parse_errors).collect())
+}
+
+/// Parses tokens that have already been lexed, from either [`lex`] or [`lex_lossless`]. Each item comes with the
+/// range of the tokens it was parsed from, which can be wider than its span (the parentheses around an expression,
+/// for example).
+pub(crate) fn parse_tokens(
+    tokens: &[Token],
+    src_id: SourceId,
+) -> (Vec<(Item, CheapRange<usize>)>, Vec<SyntaxError>) {
+    // Comments mean nothing to the parser. Doc comments are kept, to be attached to whatever they document. Trivia is
+    // dropped too, since the parser copies tokens around a lot.
+    let tokens = tokens
+        .iter()
+        .filter(|tok| !matches!(tok.kind, TokenKind::Comment(_) | TokenKind::Eof))
+        .map(|tok| Token {
+            leading: vec![],
+            trailing: vec![],
+            ..tok.clone()
+        })
+        .collect::<Vec<_>>();
 
     let (tree, parse_errors) = parser()
+        .map_with(|item, extra| {
+            let span: SimpleSpan = extra.span();
+            (item, span.end)
+        })
         .repeated()
-        .collect()
+        .collect::<Vec<_>>()
         .parse(tokens.as_slice())
         .into_output_errors();
 
+    // Errors at the end of input have no token to take a span from, so point them just past the last token.
+    let eoi = {
+        let end = tokens.last().map_or(0, Token::end);
+        SourceSpan::new(src_id, CheapRange::new(end, end))
+    };
+    let parse_errors = parse_errors
+        .into_iter()
+        .map(|err| match err.kind() {
+            SyntaxErrorKind::UnexpectedEnd => err.with_span(eoi),
+            _ => err,
+        })
+        .collect();
+
+    // Items are parsed one after the other, so each one was parsed from the tokens between the end of the one before
+    // it and its own end. (The start of an item's span from Chumsky can skip tokens, like an opening parenthesis.)
+    let mut start = 0;
     // SAFETY: the parser is infallible - it will always produce a tree, even if the tree is empty.
-    (
-        tree.unwrap(),
-        lex_errors
-            .into_iter()
-            .chain(parse_errors.into_iter())
-            .collect(),
-    )
+    let tree = tree
+        .unwrap()
+        .into_iter()
+        .map(|(item, end)| {
+            let range = match &tokens[start..end] {
+                [first, .., last] => CheapRange::new(first.start(), last.end()),
+                [only] => only.span.range(),
+                [] => item.span.range(),
+            };
+            start = end;
+            (item, range)
+        })
+        .collect();
+
+    (tree, parse_errors)
 }
 
 pub(crate) fn parser<'src>() -> impl Parser<'src, &'src [Token], Item, extra::Err<SyntaxError>> {
-    choice((expression(), statement())).or(any().validate(|tok: Token, _extra, emitter| {
-        emitter.emit(SyntaxError::unexpected_pattern(
-            ErrorPattern::Token(tok.kind),
-            tok.span,
-            None,
-        ));
-
-        Item::error(tok.span, tok.original.into())
-    }))
+    // A closing brace ends a block. Anywhere else, it's as unexpected as any other token.
+    item().or(unexpected(any()))
+}
+
+/// A single item, which can be a function or a block with more items inside it, and the separator after it.
+fn item<'src>() -> impl Parser<'src, &'src [Token], Item, extra::Err<SyntaxError>> + Clone {
+    recursive(|item| {
+        let declaration = docs()
+            .or_not()
+            .then(choice((
+                function(item.clone()),
+                expression(item.clone()),
+                statement(item),
+            )))
+            .map(|(docs, item)| attach_docs(item, docs))
+            .then_ignore(end_of_item());
+
+        // Doc comments with nothing after them to document.
+        let dangling_docs = docs().validate(|docs, _extra, emitter| {
+            emitter.emit(SyntaxError::new(
+                SyntaxErrorKind::DanglingDocComment,
+                docs.span,
+            ));
+
+            Item::error(docs.span, docs.text.into())
+        });
+
+        let not_closing_brace = any()
+            .filter(|tok: &Token| !matches!(tok.kind, TokenKind::CloseDelim(Delimiter::Braces)));
+
+        declaration
+            .or(dangling_docs)
+            .or(unexpected(not_closing_brace))
+    })
+}
+
+/// The end of an item: newlines or `;`s, or nothing at all before a closing brace or the end of the input. Anything
+/// else is reported, but the item still ends there, so that `1 2` is two items.
+fn end_of_item<'src>() -> impl Parser<'src, &'src [Token], (), extra::Err<SyntaxError>> + Clone {
+    let missing = any().rewind().validate(|tok: Token, _extra, emitter| {
+        // Tokens that the lexer couldn't make sense of have been reported already.
+        if !matches!(tok.kind, TokenKind::CharError(_) | TokenKind::Error(_)) {
+            emitter.emit(SyntaxError::new(
+                SyntaxErrorKind::MissingSeparator,
+                tok.span,
+            ));
+        }
+    });
+
+    choice((
+        separator().repeated().at_least(1),
+        close_brace().ignored().rewind(),
+        end(),
+        missing,
+    ))
+}
+
+/// Reports the token parsed by `token` as unexpected, and produces an error item in its place. Separators after it
+/// are skipped, since there's nothing for them to end.
+fn unexpected<'src>(
+    token: impl Parser<'src, &'src [Token], Token, extra::Err<SyntaxError>> + Clone,
+) -> impl Parser<'src, &'src [Token], Item, extra::Err<SyntaxError>> + Clone {
+    token
+        .validate(|tok: Token, _extra, emitter| {
+            emitter.emit(SyntaxError::unexpected_pattern(
+                ErrorPattern::Token(tok.kind),
+                tok.span,
+                None,
+            ));
+
+            Item::error(tok.span, tok.original.into())
+        })
+        .then_ignore(separator().repeated())
 }
 
+// Inputs tested here are mirrored in the tree-sitter grammar's corpus, `xva-tree-sitter/test/corpus`, which is checked
+// against this parser. Add valid ones there too.
 #[cfg(test)]
 mod tests {
+    use xva_ast::dump::{dump_items, DumpOptions};
+    use xva_span::{CheapRange, SourceId, SourceSpan};
+
+    use super::parse;
+    use crate::error::SyntaxErrorKind;
+
+    #[test]
+    fn error_spans_are_source_spans() {
+        let src_id: SourceId = 5u32.into();
+        let (_, errors) = parse("let x = 1\n   @", src_id, false);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].span(),
+            SourceSpan::new(src_id, CheapRange::new(13, 14))
+        );
+    }
+
+    #[test]
+    fn dump() {
+        let (tree, errors) = parse("let x: int = 1 + 2\n(3)", 0u32.into(), false);
+        assert!(errors.is_empty());
+        assert_eq!(
+            dump_items(&tree, &DumpOptions::default()),
+            "(local @0..5 let x
+  (type @7..10 int)
+  (binary @13..18 +
+    (int @13..14 1)
+    (int @17..18 2)))
+(int @20..21 3)
+"
+        );
+    }
+
+    #[test]
+    fn paths() {
+        let (tree, errors) = parse("let y = x + 1\nstd.io.stdout", 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(
+            dump_items(&tree, &DumpOptions::default()),
+            "(local @0..5 let y
+  (binary @8..13 +
+    (path @8..9 x)
+    (int @12..13 1)))
+(path @14..27 std.io.stdout)
+"
+        );
+    }
+
+    #[test]
+    fn functions() {
+        let (tree, errors) = parse(
+            "/// Adds.\nfn add(a: int, b: int) -> int {\n  return a + b\n}\nadd(1, 2,)",
+            0u32.into(),
+            false,
+        );
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(
+            dump_items(&tree, &DumpOptions::default()),
+            r#"(fn @10..58 add
+  (param @17..23 a
+    (type @20..23 int))
+  (param @25..31 b
+    (type @28..31 int))
+  (type @36..39 int)
+  (block @40..58
+    (return @44..56
+      (binary @51..56 +
+        (path @51..52 a)
+        (path @55..56 b))))
+  (docs @0..9 "Adds."))
+(call @59..69
+  (path @59..62 add)
+  (int @63..64 1)
+  (int @66..67 2))
+"#
+        );
+    }
+
+    #[test]
+    fn separators() {
+        let (tree, errors) = parse("1; 2\n3;;\n\n4\nlet x = 5 +\n  6", 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(tree.len(), 5);
+
+        // A line break in a block comment separates items, and a doc comment starts a new one.
+        let (tree, errors) = parse("1 /* one\n */ 2 /// Three.\nlet x = 3", 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(tree.len(), 3);
+        assert_eq!(
+            dump_items(&tree[2..], &DumpOptions::default()),
+            r#"(local @26..31 let x
+  (int @34..35 3)
+  (docs @15..25 "Three."))
+"#
+        );
+
+        let (tree, errors) = parse("1 2", 0u32.into(), false);
+        assert_eq!(tree.len(), 2);
+        assert_eq!(errors.len(), 1, "{errors:#?}");
+        assert_eq!(errors[0].kind(), &SyntaxErrorKind::MissingSeparator);
+        assert_eq!(errors[0].span().range(), CheapRange::new(2, 3));
+    }
+
+    #[test]
+    fn blocks() {
+        let (tree, errors) = parse("let x = {\n  let y = 1; y\n}\n{}", 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(
+            dump_items(&tree, &DumpOptions::default()),
+            r#"(local @0..5 let x
+  (block @8..26
+    (local @12..17 let y
+      (int @20..21 1))
+    (path @23..24 y)))
+(block @27..29)
+"#
+        );
+    }
+
+    #[test]
+    fn interpolated_string() {
+        let (tree, errors) = parse(r#""a{1}b{2 * 3}""#, 0u32.into(), false);
+        assert!(errors.is_empty());
+        assert_eq!(
+            dump_items(&tree, &DumpOptions::default()),
+            r#"(interpolated @0..14
+  (text @0..3 "a")
+  (int @3..4 1)
+  (text @4..7 "b")
+  (binary @7..12 *
+    (int @7..8 2)
+    (int @11..12 3))
+  (text @12..14 ""))
+"#
+        );
+    }
+
+    #[test]
+    fn comments() {
+        let (tree, errors) = parse(
+            "// comment\n/// The answer.\n/** Obviously. */\nlet x = /* inline */ 42",
+            0u32.into(),
+            false,
+        );
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(
+            dump_items(&tree, &DumpOptions::default()),
+            r#"(local @45..50 let x
+  (int @66..68 42)
+  (docs @11..44 "The answer.\nObviously."))
+"#
+        );
+    }
+
+    #[test]
+    fn dangling_doc_comment() {
+        let (tree, errors) = parse("1\n/// Nothing", 0u32.into(), false);
+        assert_eq!(tree.len(), 2);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind(), &SyntaxErrorKind::DanglingDocComment);
+    }
+
+    #[test]
+    fn reserved_word_as_identifier() {
+        let (tree, errors) = parse(
+            "let fn = 1
+var order = 2",
+            0u32.into(),
+            false,
+        );
+        assert_eq!(tree.len(), 2);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind(),
+            &SyntaxErrorKind::ReservedWord(crate::utils::intern_str("fn"))
+        );
+        assert_eq!(errors[0].span().range(), CheapRange::new(4, 6));
+    }
 
     // use crate::{lexer::lex, parser::parser};
 
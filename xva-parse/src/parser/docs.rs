@@ -0,0 +1,42 @@
+//! Doc comments, and attaching them to what they document.
+
+use chumsky::{prelude::*, primitive::select};
+use xva_ast::ast::{Docs, Item, ItemKind, Statement, StatementKind};
+use xva_span::SourceSpan;
+
+use crate::token::{Token, TokenKind};
+
+use super::ParserExtras;
+
+/// One or more doc comments in a row, joined into the docs for whatever comes next.
+pub(super) fn docs<'src>() -> impl Parser<'src, &'src [Token], Docs, ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::DocComment(text) => Some((text, tok.span)),
+        _ => None,
+    })
+    .repeated()
+    .at_least(1)
+    .collect::<Vec<_>>()
+    .map(|comments| Docs {
+        text: comments
+            .iter()
+            .map(|(text, _)| text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        span: SourceSpan::from_start_end(comments[0].1, comments[comments.len() - 1].1),
+    })
+}
+
+/// Attaches docs to an item, or to what the item declares, if anything.
+pub(super) fn attach_docs(mut item: Item, docs: Option<Docs>) -> Item {
+    match &mut item.kind {
+        ItemKind::Statement(Statement {
+            kind: StatementKind::Local(local),
+            ..
+        }) => local.docs = docs,
+        ItemKind::Function(function) => function.docs = docs,
+        _ => item.docs = docs,
+    }
+
+    item
+}
@@ -1,18 +1,30 @@
-use chumsky::{prelude::*, primitive::select, Parser};
-use xva_ast::ast::{Expression, ExpressionKind, Item, ItemKind, LiteralKind};
+use chumsky::{input::Emitter, prelude::*, primitive::select, Parser};
+use internment::Intern;
+use xva_ast::ast::{
+    BinaryOperator, Expression, ExpressionKind, IntegerSuffix, InterpolatedPart, Item, ItemKind,
+    LiteralKind, UnaryOperator,
+};
+use xva_span::SourceSpan;
 
 mod utils;
 
-use self::utils::left_fold_into_binary_expr;
+use self::utils::{left_associative, left_fold_into_binary_expr};
 
 use super::{
+    func::block,
+    ident::name,
     next_node_id,
-    sigil::{close_paren, open_paren, product_op, sum_op, unary_op},
+    sigil::{
+        bitwise_and_op, bitwise_or_op, bitwise_xor_op, close_paren, comma, comparison_op,
+        equality_op, logical_and_op, logical_or_op, open_paren, power_op, product_op, shift_op,
+        sum_op, unary_op,
+    },
+    stmt::{keyword, Kw},
     ParserExtras,
 };
 
 use crate::{
-    error::SyntaxError,
+    error::{SyntaxError, SyntaxErrorKind},
     token::{Token, TokenKind},
 };
 
@@ -21,8 +33,9 @@ fn literal<'src>() -> impl Parser<'src, &'src [Token], Expression, extra::Err<Sy
     select(move |token: Token, _| match token.kind {
         TokenKind::Boolean(b) => Some((LiteralKind::Boolean(b), token.span)),
         TokenKind::Char(c) => Some((LiteralKind::Char(c), token.span)),
-        TokenKind::Integer(i) => Some((LiteralKind::Integer(i), token.span)),
-        TokenKind::Float(f) => Some((LiteralKind::Float(f), token.span)),
+        TokenKind::Integer(i, suffix) => Some((LiteralKind::Integer(i, suffix), token.span)),
+        TokenKind::Float(f, suffix) => Some((LiteralKind::Float(f, suffix), token.span)),
+        TokenKind::String(s) => Some((LiteralKind::String(s.to_string()), token.span)),
         _ => None,
     })
     .map(|(lit, span)| Expression {
@@ -32,11 +45,120 @@ fn literal<'src>() -> impl Parser<'src, &'src [Token], Expression, extra::Err<Sy
     })
 }
 
+/// Reports an integer literal that's larger than its type's maximum. The lexer allows signed literals to be one
+/// larger, which is only in range when they're negated, so this is for literals that aren't. Literals without a
+/// suffix are `i128`s.
+fn check_literal_range(expr: &Expression, emitter: &mut Emitter<SyntaxError>) {
+    if let ExpressionKind::Literal(LiteralKind::Integer(value, suffix)) = expr.kind {
+        if value > suffix.unwrap_or(IntegerSuffix::I128).max() {
+            let name = suffix.map_or("int", |suffix| suffix.name());
+            emitter.emit(SyntaxError::new(
+                SyntaxErrorKind::LiteralOutOfRange(Intern::from_ref(name)),
+                expr.span,
+            ));
+        }
+    }
+}
+
+/// A name used as an expression, like `x` or `std.io.stdout`.
+fn path<'src>() -> impl Parser<'src, &'src [Token], Expression, ParserExtras> + Clone {
+    name().map(|name| Expression {
+        id: next_node_id(),
+        span: name.span,
+        kind: ExpressionKind::Path(name),
+    })
+}
+
+/// An interpolated string: a [`TokenKind::StringStart`], then expressions separated by [`TokenKind::StringMiddle`]s,
+/// then a [`TokenKind::StringEnd`].
+fn interpolated<'src>(
+    expr: impl Parser<'src, &'src [Token], Expression, ParserExtras> + Clone,
+) -> impl Parser<'src, &'src [Token], Expression, ParserExtras> + Clone {
+    let text = |kind: fn(Token) -> Option<_>| {
+        select(move |token: Token, _| kind(token)).map(
+            |(text, span): (Intern<String>, SourceSpan)| {
+                InterpolatedPart::Text(text.to_string(), span)
+            },
+        )
+    };
+
+    let start = text(|token| match token.kind {
+        TokenKind::StringStart(s) => Some((s, token.span)),
+        _ => None,
+    });
+    let middle = text(|token| match token.kind {
+        TokenKind::StringMiddle(s) => Some((s, token.span)),
+        _ => None,
+    });
+    let end = text(|token| match token.kind {
+        TokenKind::StringEnd(s) => Some((s, token.span)),
+        _ => None,
+    });
+
+    let interpolation = expr.map(InterpolatedPart::Expression);
+
+    start
+        .then(
+            interpolation
+                .clone()
+                .then(middle)
+                .repeated()
+                .collect::<Vec<_>>(),
+        )
+        .then(interpolation)
+        .then(end)
+        .map(|(((start, middles), last), end)| {
+            let parts = std::iter::once(start)
+                .chain(middles.into_iter().flat_map(|(expr, text)| [expr, text]))
+                .chain([last, end])
+                .collect::<Vec<_>>();
+
+            let span = |part: &InterpolatedPart| match part {
+                InterpolatedPart::Text(_, span) => *span,
+                InterpolatedPart::Expression(expr) => expr.span,
+            };
+
+            Expression {
+                id: next_node_id(),
+                span: SourceSpan::from_start_end(span(&parts[0]), span(&parts[parts.len() - 1])),
+                kind: ExpressionKind::Interpolated(parts),
+            }
+        })
+}
+
+/// An expression. `item` parses the items inside of block expressions.
 pub(super) fn expression_inner<'src>(
+    item: impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone + 'src,
 ) -> impl Parser<'src, &'src [Token], Expression, ParserExtras> + Clone {
     recursive(|expr| {
+        // Arguments, between parentheses and separated by commas, which can have a trailing comma.
+        let args = open_paren()
+            .ignore_then(
+                expr.clone()
+                    .separated_by(comma())
+                    .allow_trailing()
+                    .collect::<Vec<_>>(),
+            )
+            .then(close_paren());
+
+        // A call is a path followed by its arguments. What a call returns can be called again, like `f(x)(y)`.
+        let call = path().foldl(args.repeated(), |callee, (args, close_span)| Expression {
+            id: next_node_id(),
+            span: SourceSpan::from_start_end(callee.span, close_span),
+            kind: ExpressionKind::Call(Box::from(callee), args),
+        });
+
+        let block = block(item).map(|block| Expression {
+            id: next_node_id(),
+            span: block.span,
+            kind: ExpressionKind::Block(block),
+        });
+
         // An atom is a completely unambigious expression:
-        let atom = literal() // Literals, or
+        let atom = literal() // Literals,
+            .or(call) // names, like `x` or `std.io.stdout`, and calls of them,
+            .or(interpolated(expr.clone())) // interpolated strings,
+            .or(block) // blocks, or
             .or(expr.clone().delimited_by(open_paren(), close_paren())); // expressions enclosed in parentheses
 
         // With parser combinators, precedence is done by defining a parser in terms of the parser with the
@@ -45,34 +167,99 @@ pub(super) fn expression_inner<'src>(
         // producing an Expression node after each fold. The direction in which we fold is the same as the
         // associativity of the expression.
 
-        // Unary expressions are right-associative: a repeated unary operator, right-folded on to an atom.
-        let unary = unary_op().repeated().foldr(atom.clone(), |op, rhs| {
-            let span = rhs.span.clone();
-            Expression {
-                id: next_node_id(),
-                kind: ExpressionKind::Unary(op, Box::from(rhs)),
-                span,
-            }
+        // Unary expressions are right-associative: a repeated unary operator, right-folded on to a power.
+        //
+        // Powers bind tighter than unary operators, so `-2 ** 2` is `-(2 ** 2)`, but an exponent can have its own
+        // unary operator, like `2 ** -1`. Parsing the exponent as a unary expression, which contains powers again,
+        // makes powers right-associative: `2 ** 3 ** 2` is `2 ** (3 ** 2)`.
+        let unary = recursive(|unary| {
+            let power = atom
+                .clone()
+                .then(power_op().ignore_then(unary).or_not())
+                .validate(|(base, exponent), _, emitter| match exponent {
+                    // `-128i8 ** 2` is `-(128i8 ** 2)`, so the base is never negated on its own.
+                    Some(exponent) => {
+                        check_literal_range(&base, emitter);
+                        left_fold_into_binary_expr(base, (BinaryOperator::Power, exponent))
+                    }
+                    None => base,
+                });
+
+            // A signed literal one past its type's maximum is only in range right after a `-`.
+            unary_op()
+                .repeated()
+                .collect::<Vec<_>>()
+                .then(power)
+                .validate(|(ops, operand), _, emitter| {
+                    if !matches!(ops.last(), Some((UnaryOperator::Negation, _))) {
+                        check_literal_range(&operand, emitter);
+                    }
+
+                    ops.into_iter()
+                        .rfold(operand, |rhs, (op, op_span)| Expression {
+                            id: next_node_id(),
+                            span: SourceSpan::from_start_end(op_span, rhs.span),
+                            kind: ExpressionKind::Unary(op, Box::from(rhs)),
+                        })
+                })
         });
 
         // Binary expressions are similar to unaries, but they are left-associative. The first expression type with
         // a higher precedence are unaries,  so we define products in terms of unaries: unary, followed by a
         // product operator, repeating, folding left.
-        let product = unary.clone().foldl(
-            product_op().then(unary).repeated(),
-            left_fold_into_binary_expr,
-        );
+        let product = left_associative(unary, product_op());
+
+        // Every other level works the same way, each **in terms of** the level above it.
+        let sum = left_associative(product, sum_op());
+        let shift = left_associative(sum, shift_op());
+        let bitwise_and = left_associative(shift, bitwise_and_op());
+        let bitwise_xor = left_associative(bitwise_and, bitwise_xor_op());
+        let bitwise_or = left_associative(bitwise_xor, bitwise_or_op());
+
+        // Except comparisons, which don't associate at all: `a < b < c` doesn't check that `b` is between `a` and
+        // `c`, it compares a bool with `c`. Chains are reported, but still folded left so that parsing carries on.
+        let comparison = bitwise_or
+            .clone()
+            .then(
+                comparison_op()
+                    .then(bitwise_or)
+                    .repeated()
+                    .collect::<Vec<_>>(),
+            )
+            .validate(|(first, rest), _, emitter| {
+                for ((_, op_span), _) in rest.iter().skip(1) {
+                    emitter.emit(SyntaxError::new(
+                        SyntaxErrorKind::ChainedComparison,
+                        *op_span,
+                    ));
+                }
 
-        // Same as products, but now we're in **terms of** products
-        let sum = product.clone().foldl(
-            sum_op().then(product).repeated(),
-            left_fold_into_binary_expr,
+                rest.into_iter()
+                    .map(|((op, _), rhs)| (op, rhs))
+                    .fold(first, left_fold_into_binary_expr)
+            });
+
+        let equality = left_associative(comparison, equality_op());
+        let logical_and = left_associative(equality, logical_and_op());
+        let logical_or = left_associative(logical_and, logical_or_op());
+
+        // `return` takes everything after it as its value, so it has the lowest precedence of all.
+        let return_expr = keyword(Kw::Return).then(expr.or_not()).map(
+            |((_, kw_span), value): (_, Option<Expression>)| Expression {
+                id: next_node_id(),
+                span: value.as_ref().map_or(kw_span, |value| {
+                    SourceSpan::from_start_end(kw_span, value.span)
+                }),
+                kind: ExpressionKind::Return(value.map(Box::from)),
+            },
         );
 
-        sum
+        return_expr.or(logical_or)
     })
 }
-pub(crate) fn expression<'src>() -> impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone {
+pub(crate) fn expression<'src>(
+    item: impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone + 'src,
+) -> impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone {
     // recursive(|expr| {
     //     // An atom is a completely unambigious expression:
     //     let atom = literal() // Literals, or
@@ -110,12 +297,206 @@ pub(crate) fn expression<'src>() -> impl Parser<'src, &'src [Token], Item, Parse
 
     //     sum
     // })
-    expression_inner().map(|expr| {
+    expression_inner(item).map(|expr| {
         let span = expr.span.clone();
         Item {
             id: next_node_id(),
             kind: ItemKind::Expression(expr),
             span,
+            docs: None,
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use internment::Intern;
+    use xva_ast::ast::{Expression, ExpressionKind, ItemKind, LiteralKind};
+    use xva_span::CheapRange;
+
+    use crate::{error::SyntaxErrorKind, parser::parse};
+
+    /// An expression with parentheses around every operation, to show how it was grouped.
+    fn grouped(expr: &Expression) -> String {
+        match &expr.kind {
+            ExpressionKind::Literal(LiteralKind::Integer(i, _)) => i.to_string(),
+            ExpressionKind::Literal(LiteralKind::Boolean(b)) => b.to_string(),
+            ExpressionKind::Path(name) => name.to_string(),
+            ExpressionKind::Unary(op, operand) => format!("({op} {})", grouped(operand)),
+            ExpressionKind::Binary(op, lhs, rhs) => {
+                format!("({} {op} {})", grouped(lhs), grouped(rhs))
+            }
+            ExpressionKind::Call(callee, args) => {
+                let args = args.iter().map(grouped).collect::<Vec<_>>();
+                format!("{}({})", grouped(callee), args.join(", "))
+            }
+            ExpressionKind::Return(Some(value)) => format!("(return {})", grouped(value)),
+            ExpressionKind::Return(None) => "(return)".into(),
+            kind => panic!("unexpected expression: {kind:?}"),
+        }
+    }
+
+    /// Parses a single expression, and groups it with [`grouped`].
+    fn group(input: &str) -> String {
+        let (items, errors) = parse(input, 0u32.into(), false);
+        assert!(errors.is_empty(), "{input}: {errors:#?}");
+        assert_eq!(items.len(), 1, "{input}");
+        match &items[0].kind {
+            ItemKind::Expression(expr) => grouped(expr),
+            kind => panic!("not an expression: {kind:?}"),
+        }
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(group("2 ** 3 ** 2"), "(2 ** (3 ** 2))");
+        assert_eq!(group("-2 ** 2"), "(- (2 ** 2))");
+        assert_eq!(group("2 ** -1"), "(2 ** (- 1))");
+        assert_eq!(group("2 ** -3 ** 2"), "(2 ** (- (3 ** 2)))");
+        assert_eq!(group("2 * 3 ** 2"), "(2 * (3 ** 2))");
+    }
+
+    #[test]
+    fn unary() {
+        assert_eq!(group("- -1"), "(- (- 1))");
+        assert_eq!(group("not a * b"), "((not a) * b)");
+    }
+
+    #[test]
+    fn signed_literal_limits() {
+        let errors = |input| {
+            let (_, errors) = parse(input, 0u32.into(), false);
+            errors
+                .iter()
+                .map(|err| (err.kind().clone(), err.span().range()))
+                .collect::<Vec<_>>()
+        };
+        let range = |ty| SyntaxErrorKind::LiteralOutOfRange(Intern::from_ref(ty));
+
+        assert_eq!(group("-128i8"), "(- 128)");
+        assert_eq!(group("- -32768i16"), "(- (- 32768))");
+        assert_eq!(group("2 ** -128i8"), "(2 ** (- 128))");
+        assert_eq!(errors("127i8 + -127i8"), []);
+        assert_eq!(errors("128i8"), [(range("i8"), CheapRange::new(0, 5))]);
+        assert_eq!(
+            errors("let x = 32768i16"),
+            [(range("i16"), CheapRange::new(8, 16))]
+        );
+        assert_eq!(errors("not 128i8"), [(range("i8"), CheapRange::new(4, 9))]);
+        assert_eq!(
+            errors("-128i8 ** 2"),
+            [(range("i8"), CheapRange::new(1, 6))]
+        );
+        assert_eq!(errors("1 - 128i8"), [(range("i8"), CheapRange::new(4, 9))]);
+
+        assert_eq!(errors("340282366920938463463374607431768211455u128"), []);
+        assert_eq!(errors("-170141183460469231731687303715884105728i128"), []);
+        assert_eq!(
+            errors("170141183460469231731687303715884105728i128"),
+            [(range("i128"), CheapRange::new(0, 43))]
+        );
+        assert_eq!(errors("-170141183460469231731687303715884105728"), []);
+        assert_eq!(
+            errors("170141183460469231731687303715884105728"),
+            [(range("int"), CheapRange::new(0, 39))]
+        );
+    }
+
+    #[test]
+    fn products() {
+        assert_eq!(group("a * b / c % d"), "(((a * b) / c) % d)");
+        assert_eq!(group("a + b * c"), "(a + (b * c))");
+    }
+
+    #[test]
+    fn sums() {
+        assert_eq!(group("a - b + c"), "((a - b) + c)");
+        assert_eq!(group("a << b + c"), "(a << (b + c))");
+    }
+
+    #[test]
+    fn shifts() {
+        assert_eq!(group("a << b >> c"), "((a << b) >> c)");
+        assert_eq!(group("a & b << c"), "(a & (b << c))");
+    }
+
+    #[test]
+    fn bitwise() {
+        assert_eq!(group("a & b & c"), "((a & b) & c)");
+        assert_eq!(group("a ^ b & c"), "(a ^ (b & c))");
+        assert_eq!(group("a | b ^ c"), "(a | (b ^ c))");
+        assert_eq!(group("a | b & c ^ d"), "(a | ((b & c) ^ d))");
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(group("a < b | c"), "(a < (b | c))");
+        assert_eq!(group("a <= b"), "(a <= b)");
+        assert_eq!(group("a > b"), "(a > b)");
+        assert_eq!(group("a >= b"), "(a >= b)");
+        assert_eq!(group("(a < b) < c"), "((a < b) < c)");
+    }
+
+    #[test]
+    fn chained_comparisons() {
+        let (items, errors) = parse("a < b <= c > d", 0u32.into(), false);
+        assert_eq!(items.len(), 1);
+
+        let errors = errors
+            .iter()
+            .map(|err| (err.kind().clone(), err.span().range()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                (SyntaxErrorKind::ChainedComparison, CheapRange::new(6, 8)),
+                (SyntaxErrorKind::ChainedComparison, CheapRange::new(11, 12)),
+            ]
+        );
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(group("a == b != c"), "((a == b) != c)");
+        assert_eq!(group("a < b == c > d"), "((a < b) == (c > d))");
+    }
+
+    #[test]
+    fn logical() {
+        assert_eq!(group("a and b == c"), "(a and (b == c))");
+        assert_eq!(group("a or b and c"), "(a or (b and c))");
+        assert_eq!(group("a and b or c and d"), "((a and b) or (c and d))");
+        assert_eq!(group("a or b or c"), "((a or b) or c)");
+    }
+
+    #[test]
+    fn calls() {
+        assert_eq!(group("f()"), "f()");
+        assert_eq!(group("f(a, b + c)"), "f(a, (b + c))");
+        assert_eq!(group("f(a, b,)"), "f(a, b)");
+        assert_eq!(group("std.io.print(f(a))"), "std.io.print(f(a))");
+        assert_eq!(group("f(a)(b)"), "f(a)(b)");
+        assert_eq!(group("-f(a) ** 2"), "(- (f(a) ** 2))");
+
+        let (items, _) = parse("f(a, b)", 0u32.into(), false);
+        assert_eq!(items[0].span.range(), CheapRange::new(0, 7));
+    }
+
+    #[test]
+    fn calls_need_a_name() {
+        // Literals can't be called, so parentheses after one start a new item.
+        let (items, errors) = parse("1\n(2)", 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(items.len(), 2);
+    }
+
+    #[test]
+    fn returns() {
+        assert_eq!(group("return"), "(return)");
+        assert_eq!(group("return a + b"), "(return (a + b))");
+        assert_eq!(group("return return a"), "(return (return a))");
+
+        let (items, _) = parse("return a + b", 0u32.into(), false);
+        assert_eq!(items[0].span.range(), CheapRange::new(0, 12));
+    }
+}
@@ -2,7 +2,10 @@ use chumsky::prelude::*;
 use xva_ast::ast::{BinaryOperator, Expression, ExpressionKind};
 use xva_span::{CheapRange, SourceSpan};
 
-use crate::parser::next_node_id;
+use crate::{
+    parser::{next_node_id, ParserExtras},
+    token::Token,
+};
 
 /// Helper function to reduce the amount of noise in the folding calls while parsing binary expressions.
 ///
@@ -25,3 +28,18 @@ pub(super) fn left_fold_into_binary_expr(
         span: SourceSpan::new(src_id, CheapRange::new(start, end)),
     }
 }
+
+/// A level of left-associative binary expressions: `operand`, followed by an operator and another `operand`,
+/// repeating, folding left. `1 - 2 - 3` is `(1 - 2) - 3`.
+///
+/// Each level is boxed. Otherwise every level nests the type of the one above it, and parsing even a short
+/// expression overflows the stack of a debug build.
+pub(super) fn left_associative<'src>(
+    operand: impl Parser<'src, &'src [Token], Expression, ParserExtras> + Clone + 'src,
+    op: impl Parser<'src, &'src [Token], BinaryOperator, ParserExtras> + Clone + 'src,
+) -> impl Parser<'src, &'src [Token], Expression, ParserExtras> + Clone {
+    operand
+        .clone()
+        .foldl(op.then(operand).repeated(), left_fold_into_binary_expr)
+        .boxed()
+}
@@ -0,0 +1,141 @@
+//! Function declarations, and the blocks that are their bodies.
+
+use chumsky::prelude::*;
+use xva_ast::ast::{Block, Function, Item, ItemKind, Parameter};
+use xva_span::SourceSpan;
+
+use crate::token::Token;
+
+use super::{
+    ident::declared_ident,
+    next_node_id,
+    sigil::{arrow, close_brace, close_paren, comma, just_operator, open_brace, open_paren, Op},
+    stmt::{keyword, Kw},
+    ty::ty,
+    ParserExtras,
+};
+
+/// Items between braces. `item` parses each one of them along with the separator after it, and must not consume
+/// the closing brace.
+pub(super) fn block<'src>(
+    item: impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone,
+) -> impl Parser<'src, &'src [Token], Block, ParserExtras> + Clone {
+    open_brace()
+        .then(item.repeated().collect::<Vec<_>>())
+        .then(close_brace())
+        .map(|((open, items), close)| Block {
+            id: next_node_id(),
+            items,
+            span: SourceSpan::from_start_end(open, close),
+        })
+}
+
+/// A parameter and its type, like `a: int`.
+fn parameter<'src>() -> impl Parser<'src, &'src [Token], Parameter, ParserExtras> + Clone {
+    declared_ident()
+        .then_ignore(just_operator(Op::Colon))
+        .then(ty())
+        .map(|(name, ty)| Parameter {
+            id: next_node_id(),
+            span: SourceSpan::from_start_end(name.span, ty.span),
+            name,
+            ty,
+        })
+}
+
+/// A function declaration: `fn`, its name, its parameters between parentheses (which can have a trailing comma), an
+/// optional return type after `->`, and a body of `item`s.
+pub(super) fn function<'src>(
+    item: impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone,
+) -> impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone {
+    let params = parameter()
+        .separated_by(comma())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(open_paren(), close_paren());
+
+    keyword(Kw::Fn)
+        .then(declared_ident())
+        .then(params)
+        .then(arrow().ignore_then(ty()).or_not())
+        .then(block(item))
+        .map(|(((((_, kw_span), name), params), return_ty), body)| {
+            let span = SourceSpan::from_start_end(kw_span, body.span);
+
+            Item {
+                id: next_node_id(),
+                kind: ItemKind::Function(Function {
+                    id: next_node_id(),
+                    span,
+                    name,
+                    params,
+                    return_ty,
+                    body,
+                    docs: None,
+                }),
+                span,
+                docs: None,
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use xva_ast::ast::ItemKind;
+    use xva_span::CheapRange;
+
+    use crate::{error::SyntaxErrorKind, parser::parse};
+
+    #[test]
+    fn parameters() {
+        let (items, errors) = parse("fn f(a: int, b: bool,) {}", 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+        let ItemKind::Function(function) = &items[0].kind else {
+            panic!("not a function: {:?}", items[0].kind);
+        };
+
+        let spans = function
+            .params
+            .iter()
+            .map(|param| param.span.range())
+            .collect::<Vec<_>>();
+        assert_eq!(spans, [CheapRange::new(5, 11), CheapRange::new(13, 20)]);
+        assert!(function.return_ty.is_none());
+        assert!(function.body.items.is_empty());
+    }
+
+    #[test]
+    fn nested_functions() {
+        let (items, errors) = parse("fn f() {\n  fn g() {}\n  g()\n}", 0u32.into(), false);
+        assert!(errors.is_empty(), "{errors:#?}");
+        assert_eq!(items.len(), 1);
+        let ItemKind::Function(function) = &items[0].kind else {
+            panic!("not a function: {:?}", items[0].kind);
+        };
+        assert_eq!(function.body.items.len(), 2);
+    }
+
+    #[test]
+    fn parameters_need_types() {
+        let (_, errors) = parse("fn f(a) {}", 0u32.into(), false);
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn unexpected_tokens_in_body() {
+        let (items, errors) = parse("fn f() { 1 @ 2 }\n3", 0u32.into(), false);
+        assert_eq!(errors.len(), 1, "{errors:#?}");
+        assert!(matches!(
+            errors[0].kind(),
+            SyntaxErrorKind::UnexpectedPattern(_)
+        ));
+        assert_eq!(items.len(), 2);
+    }
+
+    #[test]
+    fn stray_closing_brace() {
+        let (items, errors) = parse("1\n}\n2", 0u32.into(), false);
+        assert_eq!(errors.len(), 1, "{errors:#?}");
+        assert_eq!(items.len(), 3);
+    }
+}
@@ -1,9 +1,16 @@
 use chumsky::{prelude::*, primitive::select};
-use xva_ast::ast::Identifier;
+use xva_ast::ast::{Identifier, Name, NameSegment};
+use xva_span::SourceSpan;
 
-use crate::token::{Token, TokenKind};
+use crate::{
+    error::SyntaxErrorKind,
+    lexer::KEYWORDS,
+    token::{Token, TokenKind},
+    utils::intern_str,
+    SyntaxError,
+};
 
-use super::ParserExtras;
+use super::{next_node_id, ParserExtras};
 
 pub(super) fn ident<'src>() -> impl Parser<'src, &'src [Token], Identifier, ParserExtras> + Clone {
     select(move |tok: Token, _| {
@@ -21,3 +28,61 @@ pub(super) fn ident<'src>() -> impl Parser<'src, &'src [Token], Identifier, Pars
         _ => unreachable!(),
     })
 }
+
+fn dot<'src>() -> impl Parser<'src, &'src [Token], (), ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Dot => Some(()),
+        _ => None,
+    })
+}
+
+/// A name made of identifiers separated by dots, like `x` or `std.io.item`. The span runs from the start of the first
+/// segment to the end of the last.
+pub(super) fn name<'src>() -> impl Parser<'src, &'src [Token], Name, ParserExtras> + Clone {
+    let segment = ident().map(|ident| NameSegment {
+        id: next_node_id(),
+        ident,
+    });
+
+    segment
+        .separated_by(dot())
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|segments| {
+            let first = segments.first().unwrap().ident.span;
+            let last = segments.last().unwrap().ident.span;
+
+            Name {
+                id: next_node_id(),
+                span: SourceSpan::from_start_end(first, last),
+                segments,
+            }
+        })
+}
+
+/// An identifier being declared, like the name of a local.
+///
+/// Reserved words are accepted here too, with an error, so that `let fn = 1` reports the reserved word rather than
+/// failing to parse the whole binding.
+pub(super) fn declared_ident<'src>(
+) -> impl Parser<'src, &'src [Token], Identifier, ParserExtras> + Clone {
+    let reserved = select(move |tok: Token, _| {
+        KEYWORDS
+            .iter()
+            .find(|(_, kind)| *kind == tok.kind())
+            .map(|(word, _)| (*word, tok.span))
+    })
+    .validate(|(word, span), _, emitter| {
+        emitter.emit(SyntaxError::new(
+            SyntaxErrorKind::ReservedWord(intern_str(word)),
+            span,
+        ));
+
+        Identifier {
+            name: intern_str(word),
+            span,
+        }
+    });
+
+    ident().or(reserved)
+}
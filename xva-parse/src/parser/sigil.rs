@@ -13,11 +13,21 @@ use super::ParserExtras;
 /// Parses a single unary operator, that is valid for addition expressions:
 /// - `not` for logical negation,
 /// - `-` for arithmetic negation,
+///
+/// Produces the operator along with the span at which it occurred.
 pub(super) fn unary_op<'src>(
-) -> impl Parser<'src, &'src [Token], UnaryOperator, ParserExtras> + Clone {
+) -> impl Parser<'src, &'src [Token], (UnaryOperator, SourceSpan), ParserExtras> + Clone {
     select(move |tok: Token, _| match tok.kind() {
-        TokenKind::Minus => Some(UnaryOperator::Negation),
-        TokenKind::Not => Some(UnaryOperator::Not),
+        TokenKind::Minus => Some((UnaryOperator::Negation, tok.span)),
+        TokenKind::Not => Some((UnaryOperator::Not, tok.span)),
+        _ => None,
+    })
+}
+
+/// Parses the `**` operator, for powers
+pub(super) fn power_op<'src>() -> impl Parser<'src, &'src [Token], (), ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::DoubleStar => Some(()),
         _ => None,
     })
 }
@@ -29,7 +39,6 @@ pub(super) fn product_op<'src>(
         TokenKind::Star => Some(BinaryOperator::Multiply),
         TokenKind::Slash => Some(BinaryOperator::Divide),
         TokenKind::Percent => Some(BinaryOperator::Modulo),
-        TokenKind::DoubleStar => Some(BinaryOperator::Power),
         _ => None,
     })
 }
@@ -44,6 +53,84 @@ pub(super) fn sum_op<'src>(
     })
 }
 
+/// Parses a single binary operator, that is valid for shift expressions
+pub(super) fn shift_op<'src>(
+) -> impl Parser<'src, &'src [Token], BinaryOperator, ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::DoubleLeftAngle => Some(BinaryOperator::LeftShift),
+        TokenKind::DoubleRightAngle => Some(BinaryOperator::RightShift),
+        _ => None,
+    })
+}
+
+/// Parses the `&` operator, for bitwise and expressions
+pub(super) fn bitwise_and_op<'src>(
+) -> impl Parser<'src, &'src [Token], BinaryOperator, ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Ampersand => Some(BinaryOperator::BitwiseAnd),
+        _ => None,
+    })
+}
+
+/// Parses the `^` operator, for bitwise exclusive or expressions
+pub(super) fn bitwise_xor_op<'src>(
+) -> impl Parser<'src, &'src [Token], BinaryOperator, ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Caret => Some(BinaryOperator::BitwiseXor),
+        _ => None,
+    })
+}
+
+/// Parses the `|` operator, for bitwise or expressions
+pub(super) fn bitwise_or_op<'src>(
+) -> impl Parser<'src, &'src [Token], BinaryOperator, ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Pipe => Some(BinaryOperator::BitwiseOr),
+        _ => None,
+    })
+}
+
+/// Parses a single binary operator, that is valid for comparison expressions, and produces the span at which it
+/// occurred.
+pub(super) fn comparison_op<'src>(
+) -> impl Parser<'src, &'src [Token], (BinaryOperator, SourceSpan), ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::LeftAngle => Some((BinaryOperator::LessThan, tok.span)),
+        TokenKind::RightAngle => Some((BinaryOperator::GreaterThan, tok.span)),
+        TokenKind::LessThanEquals => Some((BinaryOperator::LessThanEqual, tok.span)),
+        TokenKind::GreaterThanEquals => Some((BinaryOperator::GreaterThanEqual, tok.span)),
+        _ => None,
+    })
+}
+
+/// Parses a single binary operator, that is valid for equality expressions
+pub(super) fn equality_op<'src>(
+) -> impl Parser<'src, &'src [Token], BinaryOperator, ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::DoubleEquals => Some(BinaryOperator::Equal),
+        TokenKind::NotEquals => Some(BinaryOperator::NotEqual),
+        _ => None,
+    })
+}
+
+/// Parses the `and` operator
+pub(super) fn logical_and_op<'src>(
+) -> impl Parser<'src, &'src [Token], BinaryOperator, ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::And => Some(BinaryOperator::LogicalAnd),
+        _ => None,
+    })
+}
+
+/// Parses the `or` operator
+pub(super) fn logical_or_op<'src>(
+) -> impl Parser<'src, &'src [Token], BinaryOperator, ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Or => Some(BinaryOperator::LogicalOr),
+        _ => None,
+    })
+}
+
 /// Parses a single opening delimiter of the specified kind and produces the span at which it occurred.
 pub(super) fn open_delim<'src>(
     kind: Delimiter,
@@ -94,6 +181,42 @@ pub(super) fn close_paren<'src>(
     close_delim(Delimiter::Parentheses)
 }
 
+/// Wrapper around [`open_delim`] for braces only
+pub(super) fn open_brace<'src>(
+) -> impl Parser<'src, &'src [Token], SourceSpan, ParserExtras> + Clone {
+    open_delim(Delimiter::Braces)
+}
+
+/// Wrapper around [`close_delim`] for braces only
+pub(super) fn close_brace<'src>(
+) -> impl Parser<'src, &'src [Token], SourceSpan, ParserExtras> + Clone {
+    close_delim(Delimiter::Braces)
+}
+
+/// Parses a `,`, which separates parameters and arguments
+pub(super) fn comma<'src>() -> impl Parser<'src, &'src [Token], (), ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Comma => Some(()),
+        _ => None,
+    })
+}
+
+/// Parses a `->`, which comes before the return type of a function
+pub(super) fn arrow<'src>() -> impl Parser<'src, &'src [Token], (), ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Arrow => Some(()),
+        _ => None,
+    })
+}
+
+/// Parses a `;` or a line break, either of which ends an item
+pub(super) fn separator<'src>() -> impl Parser<'src, &'src [Token], (), ParserExtras> + Clone {
+    select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Semicolon | TokenKind::Newline => Some(()),
+        _ => None,
+    })
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Op {
     /// The `=` operator
@@ -12,8 +12,8 @@ use crate::{
 };
 
 use super::{
-    expr::{expression, expression_inner},
-    ident::ident,
+    expr::expression_inner,
+    ident::declared_ident,
     next_node_id,
     sigil::{just_operator, Op},
     ty::ty,
@@ -24,15 +24,19 @@ use super::{
 pub(crate) enum Kw {
     Let,
     Var,
+    Fn,
+    Return,
 }
 
-fn keyword<'src>(
+pub(super) fn keyword<'src>(
     kind: Kw,
 ) -> impl Parser<'src, &'src [Token], (Kw, SourceSpan), ParserExtras> + Clone {
     select(move |tok: Token, _| {
         let matched = match tok.kind() {
             TokenKind::Let => Kw::Let,
             TokenKind::Var => Kw::Var,
+            TokenKind::Fn => Kw::Fn,
+            TokenKind::Return => Kw::Return,
             _ => return None,
         };
 
@@ -44,9 +48,11 @@ fn keyword<'src>(
     })
 }
 
-fn variable<'src>() -> impl Parser<'src, &'src [Token], Statement, ParserExtras> + Clone {
+fn variable<'src>(
+    item: impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone + 'src,
+) -> impl Parser<'src, &'src [Token], Statement, ParserExtras> + Clone {
     keyword(Kw::Var)
-        .then(ident())
+        .then(declared_ident())
         .then(
             just_operator(Op::Colon)
                 .then(ty())
@@ -56,7 +62,7 @@ fn variable<'src>() -> impl Parser<'src, &'src [Token], Statement, ParserExtras>
         .then(
             just_operator(Op::Assign)
                 .ignored()
-                .then(expression_inner())
+                .then(expression_inner(item))
                 .or_not()
                 .map(|x| x.map(|(_, expr)| expr)),
         )
@@ -74,23 +80,30 @@ fn variable<'src>() -> impl Parser<'src, &'src [Token], Statement, ParserExtras>
                     binding_flags: BindingFlags { mutable: true },
                     pattern: BindingPattern::Identifier(ident),
                     ty: maybe_ty,
+                    docs: None,
                 }),
                 span,
             }
         })
 }
 
-fn local<'src>() -> impl Parser<'src, &'src [Token], Statement, ParserExtras> + Clone {
+fn local<'src>(
+    item: impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone + 'src,
+) -> impl Parser<'src, &'src [Token], Statement, ParserExtras> + Clone {
     let immutable_binding = keyword(Kw::Let)
         .map(|(_, kw_span)| kw_span)
-        .then(ident())
+        .then(declared_ident())
         .then(
             just_operator(Op::Colon)
                 .then(ty())
                 .or_not()
                 .map(|x| x.map(|(_, ty)| ty)),
         )
-        .then(just_operator(Op::Assign).then(expression_inner()).or_not())
+        .then(
+            just_operator(Op::Assign)
+                .then(expression_inner(item.clone()))
+                .or_not(),
+        )
         .map(|(((kw_span, ident), maybe_ty), maybe_expr)| {
             let span = kw_span.copy_from_ending_at(ident.span.end());
 
@@ -105,12 +118,13 @@ fn local<'src>() -> impl Parser<'src, &'src [Token], Statement, ParserExtras> +
                     binding_flags: BindingFlags { mutable: false },
                     pattern: BindingPattern::Identifier(ident),
                     ty: maybe_ty,
+                    docs: None,
                 }),
                 span,
             }
         });
 
-    immutable_binding.or(variable())
+    immutable_binding.or(variable(item))
 }
 
 fn validate_local(stmt: Statement, emitter: &mut Emitter<SyntaxError>) -> Item {
@@ -122,6 +136,7 @@ fn validate_local(stmt: Statement, emitter: &mut Emitter<SyntaxError>) -> Item {
                     id: next_node_id(),
                     kind: ItemKind::Statement(s),
                     span,
+                    docs: None,
                 }
             };
 
@@ -148,8 +163,11 @@ fn validate_local(stmt: Statement, emitter: &mut Emitter<SyntaxError>) -> Item {
     }
 }
 
-pub(super) fn statement<'src>() -> impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone {
-    let local = local().validate(|s, _, e| validate_local(s, e));
+/// A statement. `item` parses the items inside of any block expressions in it.
+pub(super) fn statement<'src>(
+    item: impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone + 'src,
+) -> impl Parser<'src, &'src [Token], Item, ParserExtras> + Clone {
+    let local = local(item).validate(|s, _, e| validate_local(s, e));
 
     choice((local,))
 }
@@ -1,81 +1,22 @@
 use chumsky::{prelude::*, primitive::select};
-use xva_ast::ast::{Name, NameSegment, Type, TypeKind};
-use xva_span::{CheapRange, SourceSpan};
+use xva_ast::ast::{Type, TypeKind};
+use xva_span::SourceSpan;
 
 use crate::token::{Delimiter, Token, TokenKind};
 
 use super::{
-    ident::ident,
+    ident::name,
     next_node_id,
     sigil::{close_delim, open_delim},
     ParserExtras,
 };
 
-fn dot<'src>() -> impl Parser<'src, &'src [Token], (), ParserExtras> + Clone {
-    select(move |tok: Token, _| match tok.kind() {
-        TokenKind::Dot => Some(()),
-        _ => None,
-    })
-}
-
 fn ty_named<'src>() -> impl Parser<'src, &'src [Token], Type, ParserExtras> + Clone {
-    let name_segment = ident().map(|ident| NameSegment {
+    name().map(|name| Type {
         id: next_node_id(),
-        ident,
-    });
-
-    name_segment
-        .clone()
-        .then(
-            dot()
-                .ignore_then(name_segment)
-                .repeated()
-                .at_least(1)
-                .collect::<Vec<_>>()
-                .or_not(),
-        )
-        .map(|(first, rest)| match rest {
-            Some(segments) => {
-                let (first_span, last_span) = (
-                    segments.first().unwrap().ident.span,
-                    segments.last().unwrap().ident.span,
-                );
-
-                let span = SourceSpan::new(
-                    first_span.src(),
-                    CheapRange::new(first_span.start(), last_span.start()),
-                );
-
-                Type {
-                    id: next_node_id(),
-                    kind: TypeKind::Named(Name {
-                        id: next_node_id(),
-                        span: span.clone(),
-                        segments: {
-                            let mut temp = Vec::with_capacity(1 + segments.len());
-                            temp.push(first);
-                            for seg in segments {
-                                temp.push(seg);
-                            }
-                            temp
-                        },
-                    }),
-                    span,
-                }
-            }
-            None => {
-                let span = first.ident.span.clone();
-                Type {
-                    id: next_node_id(),
-                    kind: TypeKind::Named(Name {
-                        id: next_node_id(),
-                        span: span.clone(),
-                        segments: vec![first; 1],
-                    }),
-                    span,
-                }
-            }
-        })
+        span: name.span,
+        kind: TypeKind::Named(name),
+    })
 }
 
 fn ty_builtin<'src>() -> impl Parser<'src, &'src [Token], Type, ParserExtras> + Clone {
@@ -87,8 +28,19 @@ fn ty_builtin<'src>() -> impl Parser<'src, &'src [Token], Type, ParserExtras> +
             span: SourceSpan::from_start_end(start, end),
         });
 
-    // TODO: Never type, diverging code.
-    choice((unit,))
+    let keyword = select(move |tok: Token, _| match tok.kind() {
+        TokenKind::Unit => Some((TypeKind::Unit, tok.span)),
+        TokenKind::Never => Some((TypeKind::Never, tok.span)),
+        _ => None,
+    })
+    .map(|(kind, span)| Type {
+        id: next_node_id(),
+        kind,
+        span,
+    });
+
+    // TODO: Diverging code.
+    choice((unit, keyword))
 }
 
 pub(super) fn ty<'src>() -> impl Parser<'src, &'src [Token], Type, ParserExtras> + Clone {
@@ -99,6 +51,7 @@ pub(super) fn ty<'src>() -> impl Parser<'src, &'src [Token], Type, ParserExtras>
 mod tests {
     use chumsky::Parser;
     use xva_ast::ast::TypeKind;
+    use xva_span::CheapRange;
 
     use crate::lexer::lex;
 
@@ -114,17 +67,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn keyword_types() {
+        for (input, expected) in [("unit", TypeKind::Unit), ("never", TypeKind::Never)] {
+            let (tokens, _) = lex(input, 0u32.into(), false);
+            let (tree, errors) = super::ty().parse(tokens.as_slice()).into_output_errors();
+            assert!(errors.is_empty(), "{errors:?}");
+            assert_eq!(
+                std::mem::discriminant(&tree.unwrap().kind),
+                std::mem::discriminant(&expected)
+            );
+        }
+    }
+
     #[test]
 
     fn named_type_multiple_segments() {
-        let input = "std.module.item";
+        let input = "std.io.item";
         let (tokens, _) = lex(input, 0u32.into(), false);
         let (tree, _) = super::ty().parse(tokens.as_slice()).into_output_errors();
         let ty = tree.unwrap();
+        assert_eq!(ty.span.range(), CheapRange::new(0, 11));
         match ty.kind {
             TypeKind::Named(name) => {
                 if name.segments[0].ident.name.as_str() != "std"
-                    || name.segments[1].ident.name.as_str() != "module"
+                    || name.segments[1].ident.name.as_str() != "io"
                     || name.segments[2].ident.name.as_str() != "item"
                 {
                     panic!("")
@@ -1,15 +1,25 @@
 use internment::Intern;
+use xva_ast::{
+    ast::{FloatSuffix, IntegerSuffix},
+    dump::{Dump, DumpOptions, SExpr},
+};
 use xva_span::SourceSpan;
 
 /// A single token produced by the lexer.
 ///
 /// Contains a [`TokenKind`] as the variant, a [`SourceSpan`] indicating its span in the input text,
 /// and a reference to the original text, with full-fidelity.
+///
+/// Tokens from [`lex_lossless`](crate::lex_lossless) also keep the [`Trivia`] around them. Everything after a token
+/// up to and including the end of its line is its trailing trivia, and everything else before a token is its
+/// leading trivia. Tokens from [`lex`](crate::lex) have no trivia.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Token {
     pub kind: TokenKind,
     pub span: SourceSpan,
     pub original: Intern<String>,
+    pub leading: Vec<Trivia>,
+    pub trailing: Vec<Trivia>,
 }
 
 impl Token {
@@ -22,7 +32,74 @@ impl Token {
     }
 
     pub fn end(&self) -> usize {
-        self.span.range().start()
+        self.span.range().end()
+    }
+}
+
+/// Tokens are dumped as `(kind @start..end payload)`, e.g. `(integer @0..2 1)` or `(plus @2..3)`. Tokens don't
+/// have node IDs, so the options make no difference.
+impl Dump for Token {
+    fn dump(&self, _opts: &DumpOptions) -> SExpr {
+        let node = SExpr::new(self.kind.name()).atom(format!("@{}", self.span.range()));
+        match self.kind {
+            TokenKind::Boolean(b) => node.atom(b),
+            TokenKind::Char(c) | TokenKind::CharError(c) => node.quoted(c),
+            TokenKind::Integer(i, suffix) => node.atom(i).suffix(&suffix),
+            TokenKind::Float(f, suffix) => node.atom(format!("{f:?}")).suffix(&suffix),
+            TokenKind::String(s)
+            | TokenKind::StringStart(s)
+            | TokenKind::StringMiddle(s)
+            | TokenKind::StringEnd(s)
+            | TokenKind::Comment(s)
+            | TokenKind::DocComment(s)
+            | TokenKind::Error(s) => node.quoted(s.as_str()),
+            TokenKind::Identifier(i) => node.atom(i),
+            _ => node,
+        }
+    }
+}
+
+/// Dumps a token stream, one token per line.
+pub fn dump_tokens(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(|tok| format!("{}\n", tok.dump(&DumpOptions::default())))
+        .collect()
+}
+
+/// Source text that means nothing to the parser, but is kept by [`lex_lossless`](crate::lex_lossless) so that the
+/// source can be rebuilt exactly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: SourceSpan,
+    pub text: Intern<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    /// Spaces, tabs and newlines. A run of whitespace is split after each newline.
+    Whitespace,
+
+    /// A comment that isn't a doc comment. Doc comments are tokens, since they document the item after them.
+    Comment,
+
+    /// The shebang line at the start of a script, like `#!/usr/bin/env xva`, without its newline.
+    Shebang,
+
+    /// Anything else the lexer skipped over.
+    Skipped,
+}
+
+impl TriviaKind {
+    /// The stable name of the kind of trivia, used in dumps.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TriviaKind::Whitespace => "whitespace",
+            TriviaKind::Comment => "comment",
+            TriviaKind::Shebang => "shebang",
+            TriviaKind::Skipped => "skipped",
+        }
     }
 }
 
@@ -46,8 +123,17 @@ pub enum TokenKind {
     Boolean(bool),
     Char(char),
     String(Intern<String>),
-    Integer(i128),
-    Float(f64),
+    Integer(u128, Option<IntegerSuffix>),
+    Float(f64, Option<FloatSuffix>),
+
+    // Interpolated strings, like `"hello {name}!"`, are split around their interpolations. The tokens of each
+    // interpolated expression come between these.
+    /// The start of an interpolated string, up to its first interpolation: `"hello {`.
+    StringStart(Intern<String>),
+    /// The text between two interpolations: `} and {`.
+    StringMiddle(Intern<String>),
+    /// The end of an interpolated string, after its last interpolation: `}!"`.
+    StringEnd(Intern<String>),
 
     // Comments
     Comment(Intern<String>),
@@ -84,10 +170,49 @@ pub enum TokenKind {
     And,
     Or,
     Not,
+    Semicolon,
+    /// The end of a line that ends an item, like a `;` does. The lexer only produces these where an item could end:
+    /// see [`lex`](crate::lex). It's empty when a comment ends the item instead, like a block comment over more
+    /// than one line.
+    Newline,
+    Question,
+    Arrow,
+    FatArrow,
+    DoubleColon,
+    DoubleDot,
+    DoubleDotEquals,
+    PlusEquals,
+    MinusEquals,
+    StarEquals,
+    SlashEquals,
+    PercentEquals,
+    DoubleLeftAngleEquals,
+    DoubleRightAngleEquals,
+    AmpersandEquals,
+    PipeEquals,
+    CaretEquals,
 
     // Keywords
     Let,
     Var,
+    Fn,
+    If,
+    Else,
+    While,
+    For,
+    In,
+    Return,
+    Break,
+    Continue,
+    Match,
+    Struct,
+    Enum,
+    Module,
+    Import,
+    Pub,
+    Const,
+    Never,
+    Unit,
 
     /// Identifier
     Identifier(Intern<String>),
@@ -101,6 +226,104 @@ pub enum TokenKind {
     ///
     /// Contains multiple characters.
     Error(Intern<String>),
+
+    /// The end of the input. Only [`lex_lossless`](crate::lex_lossless) produces this, to hold the trivia at the end
+    /// of the file.
+    Eof,
+}
+
+impl TokenKind {
+    /// The stable name of the kind of token, used in dumps.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TokenKind::Boolean(_) => "boolean",
+            TokenKind::Char(_) => "char",
+            TokenKind::String(_) => "string",
+            TokenKind::StringStart(_) => "string_start",
+            TokenKind::StringMiddle(_) => "string_middle",
+            TokenKind::StringEnd(_) => "string_end",
+            TokenKind::Integer(..) => "integer",
+            TokenKind::Float(..) => "float",
+            TokenKind::Comment(_) => "comment",
+            TokenKind::DocComment(_) => "doc_comment",
+            TokenKind::OpenDelim(Delimiter::Parentheses) => "open_paren",
+            TokenKind::OpenDelim(Delimiter::Braces) => "open_brace",
+            TokenKind::OpenDelim(Delimiter::SquareBrackets) => "open_bracket",
+            TokenKind::CloseDelim(Delimiter::Parentheses) => "close_paren",
+            TokenKind::CloseDelim(Delimiter::Braces) => "close_brace",
+            TokenKind::CloseDelim(Delimiter::SquareBrackets) => "close_bracket",
+            TokenKind::Colon => "colon",
+            TokenKind::Comma => "comma",
+            TokenKind::DoubleQuote => "double_quote",
+            TokenKind::SingleQuote => "single_quote",
+            TokenKind::Plus => "plus",
+            TokenKind::Minus => "minus",
+            TokenKind::Star => "star",
+            TokenKind::Slash => "slash",
+            TokenKind::Percent => "percent",
+            TokenKind::DoubleStar => "double_star",
+            TokenKind::Equals => "equals",
+            TokenKind::LeftAngle => "left_angle",
+            TokenKind::RightAngle => "right_angle",
+            TokenKind::Increment => "increment",
+            TokenKind::Decrement => "decrement",
+            TokenKind::Dot => "dot",
+            TokenKind::Ampersand => "ampersand",
+            TokenKind::Pipe => "pipe",
+            TokenKind::Caret => "caret",
+            TokenKind::DoubleRightAngle => "double_right_angle",
+            TokenKind::DoubleLeftAngle => "double_left_angle",
+            TokenKind::GreaterThanEquals => "greater_than_equals",
+            TokenKind::LessThanEquals => "less_than_equals",
+            TokenKind::DoubleEquals => "double_equals",
+            TokenKind::NotEquals => "not_equals",
+            TokenKind::And => "and",
+            TokenKind::Or => "or",
+            TokenKind::Not => "not",
+            TokenKind::Semicolon => "semicolon",
+            TokenKind::Newline => "newline",
+            TokenKind::Question => "question",
+            TokenKind::Arrow => "arrow",
+            TokenKind::FatArrow => "fat_arrow",
+            TokenKind::DoubleColon => "double_colon",
+            TokenKind::DoubleDot => "double_dot",
+            TokenKind::DoubleDotEquals => "double_dot_equals",
+            TokenKind::PlusEquals => "plus_equals",
+            TokenKind::MinusEquals => "minus_equals",
+            TokenKind::StarEquals => "star_equals",
+            TokenKind::SlashEquals => "slash_equals",
+            TokenKind::PercentEquals => "percent_equals",
+            TokenKind::DoubleLeftAngleEquals => "double_left_angle_equals",
+            TokenKind::DoubleRightAngleEquals => "double_right_angle_equals",
+            TokenKind::AmpersandEquals => "ampersand_equals",
+            TokenKind::PipeEquals => "pipe_equals",
+            TokenKind::CaretEquals => "caret_equals",
+            TokenKind::Let => "let",
+            TokenKind::Var => "var",
+            TokenKind::Fn => "fn",
+            TokenKind::If => "if",
+            TokenKind::Else => "else",
+            TokenKind::While => "while",
+            TokenKind::For => "for",
+            TokenKind::In => "in",
+            TokenKind::Return => "return",
+            TokenKind::Break => "break",
+            TokenKind::Continue => "continue",
+            TokenKind::Match => "match",
+            TokenKind::Struct => "struct",
+            TokenKind::Enum => "enum",
+            TokenKind::Module => "module",
+            TokenKind::Import => "import",
+            TokenKind::Pub => "pub",
+            TokenKind::Const => "const",
+            TokenKind::Never => "never",
+            TokenKind::Unit => "unit",
+            TokenKind::Identifier(_) => "identifier",
+            TokenKind::CharError(_) => "char_error",
+            TokenKind::Error(_) => "error",
+            TokenKind::Eof => "eof",
+        }
+    }
 }
 
 impl<'src> std::fmt::Display for TokenKind {
@@ -109,8 +332,17 @@ impl<'src> std::fmt::Display for TokenKind {
             TokenKind::Boolean(b) => write!(f, "'{b}'"),
             TokenKind::Char(c) => write!(f, "'{c}'"),
             TokenKind::String(s) => write!(f, "'{s}'"),
-            TokenKind::Integer(i) => write!(f, "'{i}'"),
-            TokenKind::Float(fl) => write!(f, "'{fl}'"),
+            TokenKind::StringStart(s) => write!(f, "'\"{s}{{'"),
+            TokenKind::StringMiddle(s) => write!(f, "'}}{s}{{'"),
+            TokenKind::StringEnd(s) => write!(f, "'}}{s}\"'"),
+            TokenKind::Integer(i, suffix) => match suffix {
+                Some(suffix) => write!(f, "'{i}{suffix}'"),
+                None => write!(f, "'{i}'"),
+            },
+            TokenKind::Float(fl, suffix) => match suffix {
+                Some(suffix) => write!(f, "'{fl}{suffix}'"),
+                None => write!(f, "'{fl}'"),
+            },
             TokenKind::Comment(co) => write!(f, "'{co}'"),
             TokenKind::DocComment(dco) => write!(f, "'{dco}'"),
             TokenKind::Colon => write!(f, "':'"),
@@ -141,10 +373,29 @@ impl<'src> std::fmt::Display for TokenKind {
             TokenKind::And => write!(f, "'and'"),
             TokenKind::Or => write!(f, "'or'"),
             TokenKind::Not => write!(f, "'not'"),
+            TokenKind::Semicolon => write!(f, "';'"),
+            TokenKind::Newline => write!(f, "newline"),
+            TokenKind::Question => write!(f, "'?'"),
+            TokenKind::Arrow => write!(f, "'->'"),
+            TokenKind::FatArrow => write!(f, "'=>'"),
+            TokenKind::DoubleColon => write!(f, "'::'"),
+            TokenKind::DoubleDot => write!(f, "'..'"),
+            TokenKind::DoubleDotEquals => write!(f, "'..='"),
+            TokenKind::PlusEquals => write!(f, "'+='"),
+            TokenKind::MinusEquals => write!(f, "'-='"),
+            TokenKind::StarEquals => write!(f, "'*='"),
+            TokenKind::SlashEquals => write!(f, "'/='"),
+            TokenKind::PercentEquals => write!(f, "'%='"),
+            TokenKind::DoubleLeftAngleEquals => write!(f, "'<<='"),
+            TokenKind::DoubleRightAngleEquals => write!(f, "'>>='"),
+            TokenKind::AmpersandEquals => write!(f, "'&='"),
+            TokenKind::PipeEquals => write!(f, "'|='"),
+            TokenKind::CaretEquals => write!(f, "'^='"),
 
             TokenKind::Identifier(i) => write!(f, "'{i}'"),
             TokenKind::CharError(err) => write!(f, "'{err}'"),
             TokenKind::Error(err) => write!(f, "'{err}'"),
+            TokenKind::Eof => write!(f, "end of input"),
             TokenKind::OpenDelim(delim) => match delim {
                 Delimiter::Parentheses => write!(f, "("),
                 Delimiter::Braces => write!(f, "{{"),
@@ -158,6 +409,24 @@ impl<'src> std::fmt::Display for TokenKind {
 
             TokenKind::Let => write!(f, "let"),
             TokenKind::Var => write!(f, "var"),
+            TokenKind::Fn => write!(f, "fn"),
+            TokenKind::If => write!(f, "if"),
+            TokenKind::Else => write!(f, "else"),
+            TokenKind::While => write!(f, "while"),
+            TokenKind::For => write!(f, "for"),
+            TokenKind::In => write!(f, "in"),
+            TokenKind::Return => write!(f, "return"),
+            TokenKind::Break => write!(f, "break"),
+            TokenKind::Continue => write!(f, "continue"),
+            TokenKind::Match => write!(f, "match"),
+            TokenKind::Struct => write!(f, "struct"),
+            TokenKind::Enum => write!(f, "enum"),
+            TokenKind::Module => write!(f, "module"),
+            TokenKind::Import => write!(f, "import"),
+            TokenKind::Pub => write!(f, "pub"),
+            TokenKind::Const => write!(f, "const"),
+            TokenKind::Never => write!(f, "never"),
+            TokenKind::Unit => write!(f, "unit"),
         }
     }
 }
@@ -166,16 +435,23 @@ impl<'src> std::fmt::Display for TokenKind {
 impl PartialEq for TokenKind {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Float(f_self), Self::Float(f_other)) => f_self.to_bits() == f_other.to_bits(),
+            (Self::Float(f_self, l1), Self::Float(f_other, r1)) => {
+                f_self.to_bits() == f_other.to_bits() && l1 == r1
+            }
             (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
             (Self::Char(l0), Self::Char(r0)) => l0 == r0,
-            (Self::String(l0), Self::String(r0)) => l0 == r0,
-            (Self::Integer(l0), Self::Integer(r0)) => l0 == r0,
+            (Self::String(l0), Self::String(r0))
+            | (Self::StringStart(l0), Self::StringStart(r0))
+            | (Self::StringMiddle(l0), Self::StringMiddle(r0))
+            | (Self::StringEnd(l0), Self::StringEnd(r0)) => l0 == r0,
+            (Self::Integer(l0, l1), Self::Integer(r0, r1)) => l0 == r0 && l1 == r1,
             (Self::Comment(l0), Self::Comment(r0)) => l0 == r0,
             (Self::DocComment(l0), Self::DocComment(r0)) => l0 == r0,
             (Self::Identifier(l0), Self::Identifier(r0)) => l0 == r0,
             (Self::CharError(l0), Self::CharError(r0)) => l0 == r0,
             (Self::Error(l0), Self::Error(r0)) => l0 == r0,
+            (Self::OpenDelim(l0), Self::OpenDelim(r0)) => l0 == r0,
+            (Self::CloseDelim(l0), Self::CloseDelim(r0)) => l0 == r0,
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
@@ -267,9 +267,9 @@ impl SourceMap {
             Err(e) => panic!("Source map lock is poisoned: {e}"),
         };
 
-        map.iter()
-            .last()
-            .map_or_else(|| Self::FIRST_SRC_ID, |(k, _)| *k)
+        // Files are never removed from the map, so the number of files is always the next unused ID.
+        let SourceId(first) = Self::FIRST_SRC_ID;
+        SourceId(first + map.len() as u32)
     }
 
     /// Locates a file in the source map and returns an `Arc` to it, if it has previously been loaded.
@@ -280,6 +280,19 @@ impl SourceMap {
         }
     }
 
+    /// Finds the line and column of a byte offset into a file. Both start at 1, and columns are counted in
+    /// characters rather than bytes.
+    pub fn line_col(&self, id: &SourceId, offset: usize) -> Option<(usize, usize)> {
+        let raw = self.get_raw(id)?;
+        let before = raw.get(..offset.min(raw.len()))?;
+
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count() + 1;
+        let col = before[line_start..].chars().count() + 1;
+
+        Some((line, col))
+    }
+
     pub fn get_name(&self, id: &SourceId) -> Option<&str> {
         match self.raws.read() {
             Ok(_) => self.names.get(id).map(|name| name.as_str()),
@@ -322,4 +335,28 @@ impl ariadne::Cache<SourceId> for &SourceMap {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::SourceMap;
+
+    #[test]
+    fn virtual_files_get_unique_ids() {
+        let mut map = SourceMap::default();
+        let first = map.load_virtual("first".into(), "1".into());
+        let second = map.load_virtual("second".into(), "2".into());
+
+        assert_ne!(first, second);
+        assert_eq!(map.get_name(&first), Some("first"));
+        assert_eq!(map.get_raw(&second).as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn line_col() {
+        let mut map = SourceMap::default();
+        let id = map.load_virtual("file".into(), "ab\nλd\n".into());
+
+        assert_eq!(map.line_col(&id, 0), Some((1, 1)));
+        assert_eq!(map.line_col(&id, 3), Some((2, 1)));
+        assert_eq!(map.line_col(&id, 5), Some((2, 2)));
+        assert_eq!(map.line_col(&id, 7), Some((3, 1)));
+    }
+}
@@ -0,0 +1,18 @@
+fn main() {
+    let src_dir = std::path::Path::new("src");
+
+    let mut c_config = cc::Build::new();
+    c_config.include(src_dir);
+    c_config
+        .flag_if_supported("-Wno-unused-parameter")
+        .flag_if_supported("-Wno-unused-but-set-variable")
+        .flag_if_supported("-Wno-trigraphs");
+
+    for file in ["parser.c", "scanner.c"] {
+        let path = src_dir.join(file);
+        c_config.file(&path);
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+
+    c_config.compile("tree-sitter-xva");
+}
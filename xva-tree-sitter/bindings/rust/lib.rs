@@ -0,0 +1,177 @@
+//! Xva language support for the [tree-sitter](https://tree-sitter.github.io/) parsing library, for syntax
+//! highlighting in editors.
+//!
+//! The grammar in `grammar.js` follows the chumsky parser in `xva-parse`, which stays the source of truth. The corpus
+//! in `test/corpus` is run against both of them, so that every input in it parses the same way in each.
+//!
+//! ```
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(tree_sitter_xva::language()).unwrap();
+//! let tree = parser.parse("let x = 1 + 2", None).unwrap();
+//! assert!(!tree.root_node().has_error());
+//! ```
+
+use tree_sitter::Language;
+
+extern "C" {
+    fn tree_sitter_xva() -> Language;
+}
+
+/// The tree-sitter [`Language`] for Xva.
+pub fn language() -> Language {
+    unsafe { tree_sitter_xva() }
+}
+
+/// The contents of the [`node-types.json`](https://tree-sitter.github.io/tree-sitter/using-parsers#static-node-types)
+/// file for this grammar.
+pub const NODE_TYPES: &str = include_str!("../../src/node-types.json");
+
+/// Syntax highlighting.
+pub const HIGHLIGHTS_QUERY: &str = include_str!("../../queries/highlights.scm");
+
+/// Code folding.
+pub const FOLDS_QUERY: &str = include_str!("../../queries/folds.scm");
+
+/// Automatic indentation, using the captures of nvim-treesitter.
+pub const INDENTS_QUERY: &str = include_str!("../../queries/indents.scm");
+
+/// Scopes, definitions and references of locals.
+pub const LOCALS_QUERY: &str = include_str!("../../queries/locals.scm");
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use tree_sitter::{Node, Parser, Query, Tree};
+
+    const CORPUS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/corpus");
+
+    /// An example from the corpus: its name, input and the expected tree.
+    struct Example {
+        name: String,
+        input: String,
+        expected: String,
+    }
+
+    /// Reads every example in the corpus, in the format `tree-sitter test` uses: a name between two lines of `=`,
+    /// then the input, then a line of `-` and the tree in S-expression form.
+    fn corpus() -> Vec<Example> {
+        let mut files = fs::read_dir(CORPUS)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        files.sort();
+
+        let is_rule = |line: &str, c: char| line.len() >= 3 && line.chars().all(|x| x == c);
+
+        let mut examples = vec![];
+        for file in files {
+            let text = fs::read_to_string(&file).unwrap();
+            let lines = text.lines().collect::<Vec<_>>();
+
+            let mut i = 0;
+            while i < lines.len() {
+                if !is_rule(lines[i], '=') {
+                    i += 1;
+                    continue;
+                }
+
+                let name = lines[i + 1].trim();
+                let input_start = i + 3;
+                let divider = input_start
+                    + lines[input_start..]
+                        .iter()
+                        .position(|line| is_rule(line, '-'))
+                        .unwrap_or_else(|| panic!("{}: {name} has no tree", file.display()));
+                let next = divider
+                    + lines[divider..]
+                        .iter()
+                        .position(|line| is_rule(line, '='))
+                        .unwrap_or(lines.len() - divider);
+
+                examples.push(Example {
+                    name: format!("{}: {name}", file_stem(&file)),
+                    input: lines[input_start..divider].join("\n").trim().to_string(),
+                    expected: lines[divider + 1..next].join("\n"),
+                });
+                i = next;
+            }
+        }
+
+        assert!(!examples.is_empty(), "the corpus is empty");
+        examples
+    }
+
+    fn file_stem(path: &Path) -> String {
+        path.file_stem().unwrap().to_string_lossy().into_owned()
+    }
+
+    fn parse(input: &str) -> Tree {
+        let mut parser = Parser::new();
+        parser.set_language(super::language()).unwrap();
+        parser.parse(input, None).unwrap()
+    }
+
+    /// An S-expression with its whitespace normalised and field names removed, like `tree-sitter test` compares them.
+    fn normalize(sexp: &str) -> String {
+        sexp.split_whitespace()
+            .filter(|word| !word.ends_with(':'))
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace(" )", ")")
+    }
+
+    fn is_item(node: &Node) -> bool {
+        !matches!(
+            node.kind(),
+            "line_comment" | "block_comment" | "doc_comment" | "shebang"
+        )
+    }
+
+    #[test]
+    fn can_load_grammar() {
+        Parser::new().set_language(super::language()).unwrap();
+    }
+
+    #[test]
+    fn queries_are_valid() {
+        for query in [
+            super::HIGHLIGHTS_QUERY,
+            super::FOLDS_QUERY,
+            super::INDENTS_QUERY,
+            super::LOCALS_QUERY,
+        ] {
+            Query::new(super::language(), query).unwrap();
+        }
+    }
+
+    #[test]
+    fn corpus_trees() {
+        for example in corpus() {
+            let tree = parse(&example.input);
+            assert_eq!(
+                normalize(&tree.root_node().to_sexp()),
+                normalize(&example.expected),
+                "{}",
+                example.name
+            );
+        }
+    }
+
+    /// Every input in the corpus has to be valid for the chumsky parser too, with the same number of items.
+    #[test]
+    fn corpus_matches_chumsky_parser() {
+        for example in corpus() {
+            let (items, errors) = xva_parse::parser::parse(&example.input, 0u32.into(), false);
+            assert!(errors.is_empty(), "{}: {errors:#?}", example.name);
+
+            let tree = parse(&example.input);
+            let root = tree.root_node();
+            assert!(!root.has_error(), "{}: {}", example.name, root.to_sexp());
+
+            let mut cursor = root.walk();
+            let count = root.named_children(&mut cursor).filter(is_item).count();
+            assert_eq!(count, items.len(), "{}: number of items", example.name);
+        }
+    }
+}
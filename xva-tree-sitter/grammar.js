@@ -0,0 +1,251 @@
+/**
+ * @file Tree-sitter grammar for the Xva programming language.
+ *
+ * This follows the chumsky parser in `xva-parse`, which is the source of truth: anything it accepts should parse here
+ * without errors, into a tree of the same shape. The corpus in `test/corpus` is checked against both parsers.
+ */
+
+/* eslint-disable arrow-parens */
+/* eslint-disable camelcase */
+/* eslint-disable-next-line spaced-comment */
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+// Tighter binding operators have higher precedence.
+const PREC = {
+  or: 1,
+  and: 2,
+  equality: 3,
+  comparison: 4,
+  bitwise_or: 5,
+  bitwise_xor: 6,
+  bitwise_and: 7,
+  shift: 8,
+  sum: 9,
+  product: 10,
+  unary: 11,
+  power: 12,
+  call: 13,
+};
+
+const DECIMAL = /[0-9][0-9_]*/;
+const EXPONENT = /[eE][+-]?[0-9][0-9_]*/;
+const SUFFIX = /[a-zA-Z_][a-zA-Z0-9_]*/;
+
+module.exports = grammar({
+  name: 'xva',
+
+  // Strings and comments are lexed by `src/scanner.c`: the end of a multi-line string can't be found with a regular
+  // expression, and block comments nest.
+  externals: $ => [
+    $._string_content,
+    $._multiline_string_content,
+    $._raw_string_content,
+    $._raw_multiline_string_content,
+    $.line_comment,
+    $.block_comment,
+    $.doc_comment,
+    // Never produced, only valid during error recovery.
+    $._error_sentinel,
+  ],
+
+  extras: $ => [/\s/, $.line_comment, $.block_comment, $.doc_comment],
+
+  word: $ => $.identifier,
+
+  rules: {
+    source_file: $ => seq(optional($.shebang), optional($._items)),
+
+    // `#!/usr/bin/env xva`, only at the very start of a file.
+    shebang: _ => /#![^\n]*/,
+
+    // Items are separated by newlines or `;`s. A newline is only a token where it's valid, which is after anything
+    // that can end an item, so that an expression can still carry on over a line break after an operator.
+    _items: $ => seq($._item, repeat(seq($._terminator, $._item)), optional($._terminator)),
+
+    _terminator: _ => repeat1(choice('\n', ';')),
+
+    _item: $ => choice($.local_declaration, $.function_declaration, $._expression),
+
+    // `let x: int = 1` or `var x`. A `let` must have a value, but that's left to the compiler to report.
+    local_declaration: $ => seq(
+      choice('let', 'var'),
+      field('name', $.identifier),
+      optional(seq(':', field('type', $._type))),
+      optional(seq('=', field('value', $._expression))),
+    ),
+
+    // `fn add(a: int, b: int) -> int { a + b }`. Without a return type, a function returns `unit`.
+    function_declaration: $ => seq(
+      'fn',
+      field('name', $.identifier),
+      field('parameters', $.parameters),
+      optional(seq('->', field('return_type', $._type))),
+      field('body', $.block),
+    ),
+
+    parameters: $ => seq('(', commaSep($.parameter), ')'),
+
+    parameter: $ => seq(
+      field('name', $.identifier),
+      ':',
+      field('type', $._type),
+    ),
+
+    block: $ => seq('{', optional($._items), '}'),
+
+    // Types
+
+    _type: $ => choice($.unit_type, $.never_type, $.named_type),
+
+    unit_type: _ => choice(seq('(', ')'), 'unit'),
+
+    never_type: _ => 'never',
+
+    // `int`, or a type inside a module, like `std.io.item`.
+    named_type: $ => seq(
+      repeat(seq(field('path', $.identifier), '.')),
+      field('name', alias($.identifier, $.type_identifier)),
+    ),
+
+    // Expressions
+
+    _expression: $ => choice(
+      $.path,
+      $.call_expression,
+      $.return_expression,
+      $.unary_expression,
+      $.binary_expression,
+      $.parenthesized_expression,
+      $.block,
+      $._literal,
+    ),
+
+    unary_expression: $ => prec(PREC.unary, seq(
+      field('operator', choice('-', 'not')),
+      field('operand', $._expression),
+    )),
+
+    // Comparisons can't be chained, but tree-sitter has no way to say so. The chumsky parser reports them instead.
+    binary_expression: $ => {
+      const table = [
+        [prec.left, PREC.or, 'or'],
+        [prec.left, PREC.and, 'and'],
+        [prec.left, PREC.equality, choice('==', '!=')],
+        [prec.left, PREC.comparison, choice('<', '>', '<=', '>=')],
+        [prec.left, PREC.bitwise_or, '|'],
+        [prec.left, PREC.bitwise_xor, '^'],
+        [prec.left, PREC.bitwise_and, '&'],
+        [prec.left, PREC.shift, choice('<<', '>>')],
+        [prec.left, PREC.sum, choice('+', '-')],
+        [prec.left, PREC.product, choice('*', '/', '%')],
+        // Powers bind tighter than unary operators, but their exponent can still be one, like `2 ** -1`.
+        [prec.right, PREC.power, '**'],
+      ];
+
+      return choice(...table.map(([associativity, precedence, operator]) => associativity(precedence, seq(
+        field('left', $._expression),
+        // @ts-ignore
+        field('operator', operator),
+        field('right', $._expression),
+      ))));
+    },
+
+    // Only names can be called, or what a call returns, like `f(x)(y)`.
+    call_expression: $ => prec(PREC.call, seq(
+      field('function', choice($.path, $.call_expression)),
+      field('arguments', $.arguments),
+    )),
+
+    arguments: $ => seq('(', commaSep($._expression), ')'),
+
+    // A return takes everything after it as its value. Only the chumsky parser knows it can't be an operand.
+    return_expression: $ => prec.right(seq('return', optional($._expression))),
+
+    // A name used as an expression, like `x` or `std.io.stdout`.
+    path: $ => seq($.identifier, repeat(seq('.', $.identifier))),
+
+    parenthesized_expression: $ => seq('(', $._expression, ')'),
+
+    // Literals
+
+    _literal: $ => choice(
+      $.integer,
+      $.float,
+      $.boolean,
+      $.char,
+      $.string,
+      $.raw_string,
+    ),
+
+    // `1_000`, `0xff`, `0o17`, `0b1010` and `10u8`.
+    integer: _ => token(choice(
+      seq(DECIMAL, optional(SUFFIX)),
+      /0[xob][a-zA-Z0-9_]*/,
+    )),
+
+    // `1.5`, `2.`, `1.5e-3` and `2f32`. A float suffix makes a float even without a decimal point.
+    float: _ => token(prec(1, choice(
+      seq(DECIMAL, '.', DECIMAL, optional(EXPONENT), optional(SUFFIX)),
+      seq(DECIMAL, '.'),
+      seq(DECIMAL, EXPONENT, optional(SUFFIX)),
+      seq(DECIMAL, /f(32|64)/),
+    ))),
+
+    boolean: _ => choice('true', 'false'),
+
+    char: _ => token(seq(
+      '\'',
+      choice(
+        /[^'\\]/,
+        /\\u\{[0-9a-fA-F]*\}/,
+        /\\u[0-9a-fA-F]+/,
+        /\\[^u]/,
+      ),
+      '\'',
+    )),
+
+    // `"..."` and `"""..."""`, which can interpolate expressions between braces.
+    string: $ => choice(
+      seq(
+        '"',
+        repeat(choice(alias($._string_content, $.string_content), $.escape_sequence, $.interpolation)),
+        '"',
+      ),
+      seq(
+        '"""',
+        repeat(choice(alias($._multiline_string_content, $.string_content), $.escape_sequence, $.interpolation)),
+        '"""',
+      ),
+    ),
+
+    // `r"..."` and `r"""..."""`, where backslashes and braces are just text.
+    raw_string: $ => choice(
+      seq('r"', optional(alias($._raw_string_content, $.string_content)), '"'),
+      seq('r"""', optional(alias($._raw_multiline_string_content, $.string_content)), '"""'),
+    ),
+
+    escape_sequence: _ => token.immediate(seq(
+      '\\',
+      choice(
+        /u\{[0-9a-fA-F]*\}/,
+        /u[0-9a-fA-F]+/,
+        /[^u\r\n]/,
+      ),
+    )),
+
+    interpolation: $ => seq('{', $._expression, '}'),
+
+    identifier: _ => /[_\p{XID_Start}][\p{XID_Continue}]*/,
+  },
+});
+
+/**
+ * Zero or more of `rule`, separated by commas, with an optional trailing comma.
+ *
+ * @param {RuleOrLiteral} rule
+ * @return {ChoiceRule}
+ */
+function commaSep(rule) {
+  return optional(seq(rule, repeat(seq(',', rule)), optional(',')));
+}
@@ -0,0 +1,10 @@
+[
+  (block)
+  (parameters)
+  (arguments)
+  (parenthesized_expression)
+  (string)
+  (raw_string)
+  (block_comment)
+  (doc_comment)
+] @fold
@@ -0,0 +1,121 @@
+; Types
+
+(named_type
+  path: (identifier) @namespace)
+
+(type_identifier) @type
+
+[
+  (unit_type)
+  (never_type)
+] @type.builtin
+
+; Declarations
+
+(local_declaration
+  name: (identifier) @variable)
+
+(function_declaration
+  name: (identifier) @function)
+
+(parameter
+  name: (identifier) @variable.parameter)
+
+; The last segment of a called path names the function.
+(call_expression
+  function: (path
+    (identifier) @function.call .))
+
+(path
+  (identifier) @namespace
+  .
+  (identifier))
+
+(identifier) @variable
+
+; Literals
+
+(boolean) @boolean
+
+(integer) @number
+
+(float) @number.float
+
+(char) @character
+
+[
+  (string)
+  (raw_string)
+] @string
+
+(escape_sequence) @string.escape
+
+(interpolation
+  [
+    "{"
+    "}"
+  ] @punctuation.special)
+
+; Comments
+
+(doc_comment) @comment.documentation
+
+[
+  (line_comment)
+  (block_comment)
+] @comment
+
+(shebang) @keyword.directive
+
+; Keywords and operators
+
+[
+  "let"
+  "var"
+] @keyword
+
+"fn" @keyword.function
+
+"return" @keyword.return
+
+[
+  "not"
+  "and"
+  "or"
+] @keyword.operator
+
+[
+  "+"
+  "-"
+  "*"
+  "/"
+  "%"
+  "**"
+  "<<"
+  ">>"
+  "&"
+  "^"
+  "|"
+  "<"
+  ">"
+  "<="
+  ">="
+  "=="
+  "!="
+  "="
+] @operator
+
+[
+  "("
+  ")"
+  "{"
+  "}"
+] @punctuation.bracket
+
+[
+  ":"
+  "."
+  ","
+  ";"
+  "->"
+] @punctuation.delimiter
@@ -0,0 +1,35 @@
+[
+  (local_declaration)
+  (binary_expression)
+  (parenthesized_expression)
+  (parameters)
+  (arguments)
+  (block)
+  (interpolation)
+] @indent.begin
+
+(parenthesized_expression
+  ")" @indent.branch @indent.end)
+
+(parameters
+  ")" @indent.branch @indent.end)
+
+(arguments
+  ")" @indent.branch @indent.end)
+
+(block
+  "}" @indent.branch @indent.end)
+
+(interpolation
+  "}" @indent.branch @indent.end)
+
+; The text of a multi-line string is kept as it was written.
+[
+  (string)
+  (raw_string)
+] @indent.ignore
+
+[
+  (block_comment)
+  (doc_comment)
+] @indent.auto
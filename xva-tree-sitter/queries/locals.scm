@@ -0,0 +1,25 @@
+; Scopes
+
+(source_file) @local.scope
+
+(function_declaration) @local.scope
+
+(block) @local.scope
+
+; Definitions
+
+(local_declaration
+  name: (identifier) @local.definition.var)
+
+(function_declaration
+  name: (identifier) @local.definition.function)
+
+(parameter
+  name: (identifier) @local.definition.parameter)
+
+; References
+
+; Only the first segment of a path can name a local.
+(path
+  .
+  (identifier) @local.reference)
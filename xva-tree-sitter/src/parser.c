@@ -0,0 +1,9879 @@
+#include <tree_sitter/parser.h>
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic push
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 195
+#define LARGE_STATE_COUNT 4
+#define SYMBOL_COUNT 90
+#define ALIAS_COUNT 1
+#define TOKEN_COUNT 58
+#define EXTERNAL_TOKEN_COUNT 8
+#define FIELD_COUNT 13
+#define MAX_ALIAS_SEQUENCE_LENGTH 6
+#define PRODUCTION_ID_COUNT 15
+
+enum {
+  sym_identifier = 1,
+  sym_shebang = 2,
+  anon_sym_LF = 3,
+  anon_sym_SEMI = 4,
+  anon_sym_let = 5,
+  anon_sym_var = 6,
+  anon_sym_COLON = 7,
+  anon_sym_EQ = 8,
+  anon_sym_fn = 9,
+  anon_sym_DASH_GT = 10,
+  anon_sym_LPAREN = 11,
+  anon_sym_COMMA = 12,
+  anon_sym_RPAREN = 13,
+  anon_sym_LBRACE = 14,
+  anon_sym_RBRACE = 15,
+  anon_sym_unit = 16,
+  sym_never_type = 17,
+  anon_sym_DOT = 18,
+  anon_sym_DASH = 19,
+  anon_sym_not = 20,
+  anon_sym_or = 21,
+  anon_sym_and = 22,
+  anon_sym_EQ_EQ = 23,
+  anon_sym_BANG_EQ = 24,
+  anon_sym_LT = 25,
+  anon_sym_GT = 26,
+  anon_sym_LT_EQ = 27,
+  anon_sym_GT_EQ = 28,
+  anon_sym_PIPE = 29,
+  anon_sym_CARET = 30,
+  anon_sym_AMP = 31,
+  anon_sym_LT_LT = 32,
+  anon_sym_GT_GT = 33,
+  anon_sym_PLUS = 34,
+  anon_sym_STAR = 35,
+  anon_sym_SLASH = 36,
+  anon_sym_PERCENT = 37,
+  anon_sym_STAR_STAR = 38,
+  anon_sym_return = 39,
+  sym_integer = 40,
+  sym_float = 41,
+  anon_sym_true = 42,
+  anon_sym_false = 43,
+  sym_char = 44,
+  anon_sym_DQUOTE = 45,
+  anon_sym_DQUOTE_DQUOTE_DQUOTE = 46,
+  anon_sym_r_DQUOTE = 47,
+  anon_sym_r_DQUOTE_DQUOTE_DQUOTE = 48,
+  sym_escape_sequence = 49,
+  sym__string_content = 50,
+  sym__multiline_string_content = 51,
+  sym__raw_string_content = 52,
+  sym__raw_multiline_string_content = 53,
+  sym_line_comment = 54,
+  sym_block_comment = 55,
+  sym_doc_comment = 56,
+  sym__error_sentinel = 57,
+  sym_source_file = 58,
+  sym__items = 59,
+  aux_sym__terminator = 60,
+  sym__item = 61,
+  sym_local_declaration = 62,
+  sym_function_declaration = 63,
+  sym_parameters = 64,
+  sym_parameter = 65,
+  sym_block = 66,
+  sym__type = 67,
+  sym_unit_type = 68,
+  sym_named_type = 69,
+  sym__expression = 70,
+  sym_unary_expression = 71,
+  sym_binary_expression = 72,
+  sym_call_expression = 73,
+  sym_arguments = 74,
+  sym_return_expression = 75,
+  sym_path = 76,
+  sym_parenthesized_expression = 77,
+  sym__literal = 78,
+  sym_boolean = 79,
+  sym_string = 80,
+  sym_raw_string = 81,
+  sym_interpolation = 82,
+  aux_sym__items_repeat1 = 83,
+  aux_sym_parameters_repeat1 = 84,
+  aux_sym_named_type_repeat1 = 85,
+  aux_sym_arguments_repeat1 = 86,
+  aux_sym_path_repeat1 = 87,
+  aux_sym_string_repeat1 = 88,
+  aux_sym_string_repeat2 = 89,
+  alias_sym_type_identifier = 90,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [sym_identifier] = "identifier",
+  [sym_shebang] = "shebang",
+  [anon_sym_LF] = "\n",
+  [anon_sym_SEMI] = ";",
+  [anon_sym_let] = "let",
+  [anon_sym_var] = "var",
+  [anon_sym_COLON] = ":",
+  [anon_sym_EQ] = "=",
+  [anon_sym_fn] = "fn",
+  [anon_sym_DASH_GT] = "->",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_RPAREN] = ")",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_unit] = "unit",
+  [sym_never_type] = "never_type",
+  [anon_sym_DOT] = ".",
+  [anon_sym_DASH] = "-",
+  [anon_sym_not] = "not",
+  [anon_sym_or] = "or",
+  [anon_sym_and] = "and",
+  [anon_sym_EQ_EQ] = "==",
+  [anon_sym_BANG_EQ] = "!=",
+  [anon_sym_LT] = "<",
+  [anon_sym_GT] = ">",
+  [anon_sym_LT_EQ] = "<=",
+  [anon_sym_GT_EQ] = ">=",
+  [anon_sym_PIPE] = "|",
+  [anon_sym_CARET] = "^",
+  [anon_sym_AMP] = "&",
+  [anon_sym_LT_LT] = "<<",
+  [anon_sym_GT_GT] = ">>",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_STAR] = "*",
+  [anon_sym_SLASH] = "/",
+  [anon_sym_PERCENT] = "%",
+  [anon_sym_STAR_STAR] = "**",
+  [anon_sym_return] = "return",
+  [sym_integer] = "integer",
+  [sym_float] = "float",
+  [anon_sym_true] = "true",
+  [anon_sym_false] = "false",
+  [sym_char] = "char",
+  [anon_sym_DQUOTE] = "\"",
+  [anon_sym_DQUOTE_DQUOTE_DQUOTE] = "\"\"\"",
+  [anon_sym_r_DQUOTE] = "r\"",
+  [anon_sym_r_DQUOTE_DQUOTE_DQUOTE] = "r\"\"\"",
+  [sym_escape_sequence] = "escape_sequence",
+  [sym__string_content] = "string_content",
+  [sym__multiline_string_content] = "string_content",
+  [sym__raw_string_content] = "string_content",
+  [sym__raw_multiline_string_content] = "string_content",
+  [sym_line_comment] = "line_comment",
+  [sym_block_comment] = "block_comment",
+  [sym_doc_comment] = "doc_comment",
+  [sym__error_sentinel] = "_error_sentinel",
+  [sym_source_file] = "source_file",
+  [sym__items] = "_items",
+  [aux_sym__terminator] = "_terminator",
+  [sym__item] = "_item",
+  [sym_local_declaration] = "local_declaration",
+  [sym_function_declaration] = "function_declaration",
+  [sym_parameters] = "parameters",
+  [sym_parameter] = "parameter",
+  [sym_block] = "block",
+  [sym__type] = "_type",
+  [sym_unit_type] = "unit_type",
+  [sym_named_type] = "named_type",
+  [sym__expression] = "_expression",
+  [sym_unary_expression] = "unary_expression",
+  [sym_binary_expression] = "binary_expression",
+  [sym_call_expression] = "call_expression",
+  [sym_arguments] = "arguments",
+  [sym_return_expression] = "return_expression",
+  [sym_path] = "path",
+  [sym_parenthesized_expression] = "parenthesized_expression",
+  [sym__literal] = "_literal",
+  [sym_boolean] = "boolean",
+  [sym_string] = "string",
+  [sym_raw_string] = "raw_string",
+  [sym_interpolation] = "interpolation",
+  [aux_sym__items_repeat1] = "_items_repeat1",
+  [aux_sym_parameters_repeat1] = "parameters_repeat1",
+  [aux_sym_named_type_repeat1] = "named_type_repeat1",
+  [aux_sym_arguments_repeat1] = "arguments_repeat1",
+  [aux_sym_path_repeat1] = "path_repeat1",
+  [aux_sym_string_repeat1] = "string_repeat1",
+  [aux_sym_string_repeat2] = "string_repeat2",
+  [alias_sym_type_identifier] = "type_identifier",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [sym_identifier] = sym_identifier,
+  [sym_shebang] = sym_shebang,
+  [anon_sym_LF] = anon_sym_LF,
+  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_let] = anon_sym_let,
+  [anon_sym_var] = anon_sym_var,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_fn] = anon_sym_fn,
+  [anon_sym_DASH_GT] = anon_sym_DASH_GT,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_unit] = anon_sym_unit,
+  [sym_never_type] = sym_never_type,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_DASH] = anon_sym_DASH,
+  [anon_sym_not] = anon_sym_not,
+  [anon_sym_or] = anon_sym_or,
+  [anon_sym_and] = anon_sym_and,
+  [anon_sym_EQ_EQ] = anon_sym_EQ_EQ,
+  [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_GT] = anon_sym_GT,
+  [anon_sym_LT_EQ] = anon_sym_LT_EQ,
+  [anon_sym_GT_EQ] = anon_sym_GT_EQ,
+  [anon_sym_PIPE] = anon_sym_PIPE,
+  [anon_sym_CARET] = anon_sym_CARET,
+  [anon_sym_AMP] = anon_sym_AMP,
+  [anon_sym_LT_LT] = anon_sym_LT_LT,
+  [anon_sym_GT_GT] = anon_sym_GT_GT,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_STAR] = anon_sym_STAR,
+  [anon_sym_SLASH] = anon_sym_SLASH,
+  [anon_sym_PERCENT] = anon_sym_PERCENT,
+  [anon_sym_STAR_STAR] = anon_sym_STAR_STAR,
+  [anon_sym_return] = anon_sym_return,
+  [sym_integer] = sym_integer,
+  [sym_float] = sym_float,
+  [anon_sym_true] = anon_sym_true,
+  [anon_sym_false] = anon_sym_false,
+  [sym_char] = sym_char,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [anon_sym_DQUOTE_DQUOTE_DQUOTE] = anon_sym_DQUOTE_DQUOTE_DQUOTE,
+  [anon_sym_r_DQUOTE] = anon_sym_r_DQUOTE,
+  [anon_sym_r_DQUOTE_DQUOTE_DQUOTE] = anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+  [sym_escape_sequence] = sym_escape_sequence,
+  [sym__string_content] = sym__string_content,
+  [sym__multiline_string_content] = sym__string_content,
+  [sym__raw_string_content] = sym__string_content,
+  [sym__raw_multiline_string_content] = sym__string_content,
+  [sym_line_comment] = sym_line_comment,
+  [sym_block_comment] = sym_block_comment,
+  [sym_doc_comment] = sym_doc_comment,
+  [sym__error_sentinel] = sym__error_sentinel,
+  [sym_source_file] = sym_source_file,
+  [sym__items] = sym__items,
+  [aux_sym__terminator] = aux_sym__terminator,
+  [sym__item] = sym__item,
+  [sym_local_declaration] = sym_local_declaration,
+  [sym_function_declaration] = sym_function_declaration,
+  [sym_parameters] = sym_parameters,
+  [sym_parameter] = sym_parameter,
+  [sym_block] = sym_block,
+  [sym__type] = sym__type,
+  [sym_unit_type] = sym_unit_type,
+  [sym_named_type] = sym_named_type,
+  [sym__expression] = sym__expression,
+  [sym_unary_expression] = sym_unary_expression,
+  [sym_binary_expression] = sym_binary_expression,
+  [sym_call_expression] = sym_call_expression,
+  [sym_arguments] = sym_arguments,
+  [sym_return_expression] = sym_return_expression,
+  [sym_path] = sym_path,
+  [sym_parenthesized_expression] = sym_parenthesized_expression,
+  [sym__literal] = sym__literal,
+  [sym_boolean] = sym_boolean,
+  [sym_string] = sym_string,
+  [sym_raw_string] = sym_raw_string,
+  [sym_interpolation] = sym_interpolation,
+  [aux_sym__items_repeat1] = aux_sym__items_repeat1,
+  [aux_sym_parameters_repeat1] = aux_sym_parameters_repeat1,
+  [aux_sym_named_type_repeat1] = aux_sym_named_type_repeat1,
+  [aux_sym_arguments_repeat1] = aux_sym_arguments_repeat1,
+  [aux_sym_path_repeat1] = aux_sym_path_repeat1,
+  [aux_sym_string_repeat1] = aux_sym_string_repeat1,
+  [aux_sym_string_repeat2] = aux_sym_string_repeat2,
+  [alias_sym_type_identifier] = alias_sym_type_identifier,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_shebang] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LF] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_let] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_var] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_fn] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_unit] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_never_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_not] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_or] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_and] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PIPE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_CARET] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AMP] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PERCENT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_return] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_integer] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_float] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_true] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_false] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_char] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTE_DQUOTE_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_r_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_r_DQUOTE_DQUOTE_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_escape_sequence] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__string_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__multiline_string_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__raw_string_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__raw_multiline_string_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_line_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_doc_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__error_sentinel] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_source_file] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__items] = {
+    .visible = false,
+    .named = true,
+  },
+  [aux_sym__terminator] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym__item] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_local_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_function_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parameters] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__type] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_unit_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_named_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_unary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_call_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_return_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_path] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parenthesized_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__literal] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_boolean] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_raw_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym__items_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_parameters_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_named_type_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_arguments_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_path_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_string_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_string_repeat2] = {
+    .visible = false,
+    .named = false,
+  },
+  [alias_sym_type_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+};
+
+enum {
+  field_arguments = 1,
+  field_body = 2,
+  field_function = 3,
+  field_left = 4,
+  field_name = 5,
+  field_operand = 6,
+  field_operator = 7,
+  field_parameters = 8,
+  field_path = 9,
+  field_return_type = 10,
+  field_right = 11,
+  field_type = 12,
+  field_value = 13,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_arguments] = "arguments",
+  [field_body] = "body",
+  [field_function] = "function",
+  [field_left] = "left",
+  [field_name] = "name",
+  [field_operand] = "operand",
+  [field_operator] = "operator",
+  [field_parameters] = "parameters",
+  [field_path] = "path",
+  [field_return_type] = "return_type",
+  [field_right] = "right",
+  [field_type] = "type",
+  [field_value] = "value",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 2},
+  [3] = {.index = 3, .length = 2},
+  [4] = {.index = 5, .length = 3},
+  [5] = {.index = 8, .length = 1},
+  [6] = {.index = 9, .length = 2},
+  [7] = {.index = 11, .length = 2},
+  [8] = {.index = 13, .length = 3},
+  [9] = {.index = 16, .length = 1},
+  [10] = {.index = 17, .length = 2},
+  [11] = {.index = 19, .length = 2},
+  [12] = {.index = 21, .length = 3},
+  [13] = {.index = 24, .length = 2},
+  [14] = {.index = 26, .length = 4},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_name, 1},
+  [1] =
+    {field_operand, 1},
+    {field_operator, 0},
+  [3] =
+    {field_arguments, 1},
+    {field_function, 0},
+  [5] =
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [8] =
+    {field_name, 0},
+  [9] =
+    {field_name, 1},
+    {field_type, 3},
+  [11] =
+    {field_name, 1},
+    {field_value, 3},
+  [13] =
+    {field_body, 3},
+    {field_name, 1},
+    {field_parameters, 2},
+  [16] =
+    {field_path, 0},
+  [17] =
+    {field_name, 1},
+    {field_path, 0, .inherited = true},
+  [19] =
+    {field_path, 0, .inherited = true},
+    {field_path, 1, .inherited = true},
+  [21] =
+    {field_name, 1},
+    {field_type, 3},
+    {field_value, 5},
+  [24] =
+    {field_name, 0},
+    {field_type, 2},
+  [26] =
+    {field_body, 5},
+    {field_name, 1},
+    {field_parameters, 2},
+    {field_return_type, 4},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+  [5] = {
+    [0] = alias_sym_type_identifier,
+  },
+  [10] = {
+    [1] = alias_sym_type_identifier,
+  },
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 2,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 8,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 11,
+  [14] = 12,
+  [15] = 10,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 25,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 30,
+  [35] = 17,
+  [36] = 18,
+  [37] = 19,
+  [38] = 20,
+  [39] = 31,
+  [40] = 22,
+  [41] = 32,
+  [42] = 33,
+  [43] = 16,
+  [44] = 27,
+  [45] = 23,
+  [46] = 29,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 48,
+  [51] = 49,
+  [52] = 47,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 53,
+  [61] = 54,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 58,
+  [79] = 79,
+  [80] = 80,
+  [81] = 56,
+  [82] = 57,
+  [83] = 55,
+  [84] = 59,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 70,
+  [93] = 68,
+  [94] = 69,
+  [95] = 95,
+  [96] = 76,
+  [97] = 86,
+  [98] = 74,
+  [99] = 75,
+  [100] = 80,
+  [101] = 77,
+  [102] = 85,
+  [103] = 87,
+  [104] = 88,
+  [105] = 89,
+  [106] = 90,
+  [107] = 67,
+  [108] = 65,
+  [109] = 79,
+  [110] = 63,
+  [111] = 95,
+  [112] = 71,
+  [113] = 64,
+  [114] = 66,
+  [115] = 115,
+  [116] = 116,
+  [117] = 116,
+  [118] = 118,
+  [119] = 118,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 124,
+  [134] = 134,
+  [135] = 135,
+  [136] = 127,
+  [137] = 125,
+  [138] = 128,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 129,
+  [145] = 145,
+  [146] = 134,
+  [147] = 143,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 140,
+  [155] = 155,
+  [156] = 141,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 150,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 161,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 169,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 174,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 180,
+  [184] = 181,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 188,
+  [190] = 190,
+  [191] = 179,
+  [192] = 192,
+  [193] = 193,
+  [194] = 182,
+};
+
+static inline bool sym_identifier_character_set_1(int32_t c) {
+  return (c < 43514
+    ? (c < 4193
+      ? (c < 2707
+        ? (c < 1994
+          ? (c < 910
+            ? (c < 736
+              ? (c < 186
+                ? (c < 'a'
+                  ? (c < '_'
+                    ? (c >= 'A' && c <= 'Z')
+                    : c <= '_')
+                  : (c <= 'z' || (c < 181
+                    ? c == 170
+                    : c <= 181)))
+                : (c <= 186 || (c < 248
+                  ? (c < 216
+                    ? (c >= 192 && c <= 214)
+                    : c <= 246)
+                  : (c <= 705 || (c >= 710 && c <= 721)))))
+              : (c <= 740 || (c < 891
+                ? (c < 880
+                  ? (c < 750
+                    ? c == 748
+                    : c <= 750)
+                  : (c <= 884 || (c >= 886 && c <= 887)))
+                : (c <= 893 || (c < 904
+                  ? (c < 902
+                    ? c == 895
+                    : c <= 902)
+                  : (c <= 906 || c == 908))))))
+            : (c <= 929 || (c < 1649
+              ? (c < 1376
+                ? (c < 1162
+                  ? (c < 1015
+                    ? (c >= 931 && c <= 1013)
+                    : c <= 1153)
+                  : (c <= 1327 || (c < 1369
+                    ? (c >= 1329 && c <= 1366)
+                    : c <= 1369)))
+                : (c <= 1416 || (c < 1568
+                  ? (c < 1519
+                    ? (c >= 1488 && c <= 1514)
+                    : c <= 1522)
+                  : (c <= 1610 || (c >= 1646 && c <= 1647)))))
+              : (c <= 1747 || (c < 1791
+                ? (c < 1774
+                  ? (c < 1765
+                    ? c == 1749
+                    : c <= 1766)
+                  : (c <= 1775 || (c >= 1786 && c <= 1788)))
+                : (c <= 1791 || (c < 1869
+                  ? (c < 1810
+                    ? c == 1808
+                    : c <= 1839)
+                  : (c <= 1957 || c == 1969))))))))
+          : (c <= 2026 || (c < 2482
+            ? (c < 2208
+              ? (c < 2088
+                ? (c < 2048
+                  ? (c < 2042
+                    ? (c >= 2036 && c <= 2037)
+                    : c <= 2042)
+                  : (c <= 2069 || (c < 2084
+                    ? c == 2074
+                    : c <= 2084)))
+                : (c <= 2088 || (c < 2160
+                  ? (c < 2144
+                    ? (c >= 2112 && c <= 2136)
+                    : c <= 2154)
+                  : (c <= 2183 || (c >= 2185 && c <= 2190)))))
+              : (c <= 2249 || (c < 2417
+                ? (c < 2384
+                  ? (c < 2365
+                    ? (c >= 2308 && c <= 2361)
+                    : c <= 2365)
+                  : (c <= 2384 || (c >= 2392 && c <= 2401)))
+                : (c <= 2432 || (c < 2451
+                  ? (c < 2447
+                    ? (c >= 2437 && c <= 2444)
+                    : c <= 2448)
+                  : (c <= 2472 || (c >= 2474 && c <= 2480)))))))
+            : (c <= 2482 || (c < 2579
+              ? (c < 2527
+                ? (c < 2510
+                  ? (c < 2493
+                    ? (c >= 2486 && c <= 2489)
+                    : c <= 2493)
+                  : (c <= 2510 || (c >= 2524 && c <= 2525)))
+                : (c <= 2529 || (c < 2565
+                  ? (c < 2556
+                    ? (c >= 2544 && c <= 2545)
+                    : c <= 2556)
+                  : (c <= 2570 || (c >= 2575 && c <= 2576)))))
+              : (c <= 2600 || (c < 2649
+                ? (c < 2613
+                  ? (c < 2610
+                    ? (c >= 2602 && c <= 2608)
+                    : c <= 2611)
+                  : (c <= 2614 || (c >= 2616 && c <= 2617)))
+                : (c <= 2652 || (c < 2693
+                  ? (c < 2674
+                    ? c == 2654
+                    : c <= 2676)
+                  : (c <= 2701 || (c >= 2703 && c <= 2705)))))))))))
+        : (c <= 2728 || (c < 3242
+          ? (c < 2962
+            ? (c < 2858
+              ? (c < 2784
+                ? (c < 2741
+                  ? (c < 2738
+                    ? (c >= 2730 && c <= 2736)
+                    : c <= 2739)
+                  : (c <= 2745 || (c < 2768
+                    ? c == 2749
+                    : c <= 2768)))
+                : (c <= 2785 || (c < 2831
+                  ? (c < 2821
+                    ? c == 2809
+                    : c <= 2828)
+                  : (c <= 2832 || (c >= 2835 && c <= 2856)))))
+              : (c <= 2864 || (c < 2911
+                ? (c < 2877
+                  ? (c < 2869
+                    ? (c >= 2866 && c <= 2867)
+                    : c <= 2873)
+                  : (c <= 2877 || (c >= 2908 && c <= 2909)))
+                : (c <= 2913 || (c < 2949
+                  ? (c < 2947
+                    ? c == 2929
+                    : c <= 2947)
+                  : (c <= 2954 || (c >= 2958 && c <= 2960)))))))
+            : (c <= 2965 || (c < 3090
+              ? (c < 2984
+                ? (c < 2974
+                  ? (c < 2972
+                    ? (c >= 2969 && c <= 2970)
+                    : c <= 2972)
+                  : (c <= 2975 || (c >= 2979 && c <= 2980)))
+                : (c <= 2986 || (c < 3077
+                  ? (c < 3024
+                    ? (c >= 2990 && c <= 3001)
+                    : c <= 3024)
+                  : (c <= 3084 || (c >= 3086 && c <= 3088)))))
+              : (c <= 3112 || (c < 3168
+                ? (c < 3160
+                  ? (c < 3133
+                    ? (c >= 3114 && c <= 3129)
+                    : c <= 3133)
+                  : (c <= 3162 || c == 3165))
+                : (c <= 3169 || (c < 3214
+                  ? (c < 3205
+                    ? c == 3200
+                    : c <= 3212)
+                  : (c <= 3216 || (c >= 3218 && c <= 3240)))))))))
+          : (c <= 3251 || (c < 3648
+            ? (c < 3412
+              ? (c < 3332
+                ? (c < 3293
+                  ? (c < 3261
+                    ? (c >= 3253 && c <= 3257)
+                    : c <= 3261)
+                  : (c <= 3294 || (c < 3313
+                    ? (c >= 3296 && c <= 3297)
+                    : c <= 3314)))
+                : (c <= 3340 || (c < 3389
+                  ? (c < 3346
+                    ? (c >= 3342 && c <= 3344)
+                    : c <= 3386)
+                  : (c <= 3389 || c == 3406))))
+              : (c <= 3414 || (c < 3507
+                ? (c < 3461
+                  ? (c < 3450
+                    ? (c >= 3423 && c <= 3425)
+                    : c <= 3455)
+                  : (c <= 3478 || (c >= 3482 && c <= 3505)))
+                : (c <= 3515 || (c < 3585
+                  ? (c < 3520
+                    ? c == 3517
+                    : c <= 3526)
+                  : (c <= 3632 || c == 3634))))))
+            : (c <= 3654 || (c < 3782
+              ? (c < 3749
+                ? (c < 3718
+                  ? (c < 3716
+                    ? (c >= 3713 && c <= 3714)
+                    : c <= 3716)
+                  : (c <= 3722 || (c >= 3724 && c <= 3747)))
+                : (c <= 3749 || (c < 3773
+                  ? (c < 3762
+                    ? (c >= 3751 && c <= 3760)
+                    : c <= 3762)
+                  : (c <= 3773 || (c >= 3776 && c <= 3780)))))
+              : (c <= 3782 || (c < 3976
+                ? (c < 3904
+                  ? (c < 3840
+                    ? (c >= 3804 && c <= 3807)
+                    : c <= 3840)
+                  : (c <= 3911 || (c >= 3913 && c <= 3948)))
+                : (c <= 3980 || (c < 4176
+                  ? (c < 4159
+                    ? (c >= 4096 && c <= 4138)
+                    : c <= 4159)
+                  : (c <= 4181 || (c >= 4186 && c <= 4189)))))))))))))
+      : (c <= 4193 || (c < 8134
+        ? (c < 6176
+          ? (c < 4808
+            ? (c < 4688
+              ? (c < 4295
+                ? (c < 4213
+                  ? (c < 4206
+                    ? (c >= 4197 && c <= 4198)
+                    : c <= 4208)
+                  : (c <= 4225 || (c < 4256
+                    ? c == 4238
+                    : c <= 4293)))
+                : (c <= 4295 || (c < 4348
+                  ? (c < 4304
+                    ? c == 4301
+                    : c <= 4346)
+                  : (c <= 4680 || (c >= 4682 && c <= 4685)))))
+              : (c <= 4694 || (c < 4752
+                ? (c < 4704
+                  ? (c < 4698
+                    ? c == 4696
+                    : c <= 4701)
+                  : (c <= 4744 || (c >= 4746 && c <= 4749)))
+                : (c <= 4784 || (c < 4800
+                  ? (c < 4792
+                    ? (c >= 4786 && c <= 4789)
+                    : c <= 4798)
+                  : (c <= 4800 || (c >= 4802 && c <= 4805)))))))
+            : (c <= 4822 || (c < 5792
+              ? (c < 5024
+                ? (c < 4888
+                  ? (c < 4882
+                    ? (c >= 4824 && c <= 4880)
+                    : c <= 4885)
+                  : (c <= 4954 || (c >= 4992 && c <= 5007)))
+                : (c <= 5109 || (c < 5743
+                  ? (c < 5121
+                    ? (c >= 5112 && c <= 5117)
+                    : c <= 5740)
+                  : (c <= 5759 || (c >= 5761 && c <= 5786)))))
+              : (c <= 5866 || (c < 5984
+                ? (c < 5919
+                  ? (c < 5888
+                    ? (c >= 5870 && c <= 5880)
+                    : c <= 5905)
+                  : (c <= 5937 || (c >= 5952 && c <= 5969)))
+                : (c <= 5996 || (c < 6103
+                  ? (c < 6016
+                    ? (c >= 5998 && c <= 6000)
+                    : c <= 6067)
+                  : (c <= 6103 || c == 6108))))))))
+          : (c <= 6264 || (c < 7312
+            ? (c < 6823
+              ? (c < 6512
+                ? (c < 6320
+                  ? (c < 6314
+                    ? (c >= 6272 && c <= 6312)
+                    : c <= 6314)
+                  : (c <= 6389 || (c < 6480
+                    ? (c >= 6400 && c <= 6430)
+                    : c <= 6509)))
+                : (c <= 6516 || (c < 6656
+                  ? (c < 6576
+                    ? (c >= 6528 && c <= 6571)
+                    : c <= 6601)
+                  : (c <= 6678 || (c >= 6688 && c <= 6740)))))
+              : (c <= 6823 || (c < 7098
+                ? (c < 7043
+                  ? (c < 6981
+                    ? (c >= 6917 && c <= 6963)
+                    : c <= 6988)
+                  : (c <= 7072 || (c >= 7086 && c <= 7087)))
+                : (c <= 7141 || (c < 7258
+                  ? (c < 7245
+                    ? (c >= 7168 && c <= 7203)
+                    : c <= 7247)
+                  : (c <= 7293 || (c >= 7296 && c <= 7304)))))))
+            : (c <= 7354 || (c < 8008
+              ? (c < 7418
+                ? (c < 7406
+                  ? (c < 7401
+                    ? (c >= 7357 && c <= 7359)
+                    : c <= 7404)
+                  : (c <= 7411 || (c >= 7413 && c <= 7414)))
+                : (c <= 7418 || (c < 7960
+                  ? (c < 7680
+                    ? (c >= 7424 && c <= 7615)
+                    : c <= 7957)
+                  : (c <= 7965 || (c >= 7968 && c <= 8005)))))
+              : (c <= 8013 || (c < 8031
+                ? (c < 8027
+                  ? (c < 8025
+                    ? (c >= 8016 && c <= 8023)
+                    : c <= 8025)
+                  : (c <= 8027 || c == 8029))
+                : (c <= 8061 || (c < 8126
+                  ? (c < 8118
+                    ? (c >= 8064 && c <= 8116)
+                    : c <= 8124)
+                  : (c <= 8126 || (c >= 8130 && c <= 8132)))))))))))
+        : (c <= 8140 || (c < 12337
+          ? (c < 8544
+            ? (c < 8458
+              ? (c < 8305
+                ? (c < 8160
+                  ? (c < 8150
+                    ? (c >= 8144 && c <= 8147)
+                    : c <= 8155)
+                  : (c <= 8172 || (c < 8182
+                    ? (c >= 8178 && c <= 8180)
+                    : c <= 8188)))
+                : (c <= 8305 || (c < 8450
+                  ? (c < 8336
+                    ? c == 8319
+                    : c <= 8348)
+                  : (c <= 8450 || c == 8455))))
+              : (c <= 8467 || (c < 8488
+                ? (c < 8484
+                  ? (c < 8472
+                    ? c == 8469
+                    : c <= 8477)
+                  : (c <= 8484 || c == 8486))
+                : (c <= 8488 || (c < 8517
+                  ? (c < 8508
+                    ? (c >= 8490 && c <= 8505)
+                    : c <= 8511)
+                  : (c <= 8521 || c == 8526))))))
+            : (c <= 8584 || (c < 11680
+              ? (c < 11559
+                ? (c < 11506
+                  ? (c < 11499
+                    ? (c >= 11264 && c <= 11492)
+                    : c <= 11502)
+                  : (c <= 11507 || (c >= 11520 && c <= 11557)))
+                : (c <= 11559 || (c < 11631
+                  ? (c < 11568
+                    ? c == 11565
+                    : c <= 11623)
+                  : (c <= 11631 || (c >= 11648 && c <= 11670)))))
+              : (c <= 11686 || (c < 11720
+                ? (c < 11704
+                  ? (c < 11696
+                    ? (c >= 11688 && c <= 11694)
+                    : c <= 11702)
+                  : (c <= 11710 || (c >= 11712 && c <= 11718)))
+                : (c <= 11726 || (c < 12293
+                  ? (c < 11736
+                    ? (c >= 11728 && c <= 11734)
+                    : c <= 11742)
+                  : (c <= 12295 || (c >= 12321 && c <= 12329)))))))))
+          : (c <= 12341 || (c < 42891
+            ? (c < 19968
+              ? (c < 12549
+                ? (c < 12445
+                  ? (c < 12353
+                    ? (c >= 12344 && c <= 12348)
+                    : c <= 12438)
+                  : (c <= 12447 || (c < 12540
+                    ? (c >= 12449 && c <= 12538)
+                    : c <= 12543)))
+                : (c <= 12591 || (c < 12784
+                  ? (c < 12704
+                    ? (c >= 12593 && c <= 12686)
+                    : c <= 12735)
+                  : (c <= 12799 || (c >= 13312 && c <= 19903)))))
+              : (c <= 42124 || (c < 42560
+                ? (c < 42512
+                  ? (c < 42240
+                    ? (c >= 42192 && c <= 42237)
+                    : c <= 42508)
+                  : (c <= 42527 || (c >= 42538 && c <= 42539)))
+                : (c <= 42606 || (c < 42775
+                  ? (c < 42656
+                    ? (c >= 42623 && c <= 42653)
+                    : c <= 42735)
+                  : (c <= 42783 || (c >= 42786 && c <= 42888)))))))
+            : (c <= 42954 || (c < 43250
+              ? (c < 43011
+                ? (c < 42965
+                  ? (c < 42963
+                    ? (c >= 42960 && c <= 42961)
+                    : c <= 42963)
+                  : (c <= 42969 || (c >= 42994 && c <= 43009)))
+                : (c <= 43013 || (c < 43072
+                  ? (c < 43020
+                    ? (c >= 43015 && c <= 43018)
+                    : c <= 43042)
+                  : (c <= 43123 || (c >= 43138 && c <= 43187)))))
+              : (c <= 43255 || (c < 43360
+                ? (c < 43274
+                  ? (c < 43261
+                    ? c == 43259
+                    : c <= 43262)
+                  : (c <= 43301 || (c >= 43312 && c <= 43334)))
+                : (c <= 43388 || (c < 43488
+                  ? (c < 43471
+                    ? (c >= 43396 && c <= 43442)
+                    : c <= 43471)
+                  : (c <= 43492 || (c >= 43494 && c <= 43503)))))))))))))))
+    : (c <= 43518 || (c < 70727
+      ? (c < 66956
+        ? (c < 64914
+          ? (c < 43868
+            ? (c < 43714
+              ? (c < 43646
+                ? (c < 43588
+                  ? (c < 43584
+                    ? (c >= 43520 && c <= 43560)
+                    : c <= 43586)
+                  : (c <= 43595 || (c < 43642
+                    ? (c >= 43616 && c <= 43638)
+                    : c <= 43642)))
+                : (c <= 43695 || (c < 43705
+                  ? (c < 43701
+                    ? c == 43697
+                    : c <= 43702)
+                  : (c <= 43709 || c == 43712))))
+              : (c <= 43714 || (c < 43785
+                ? (c < 43762
+                  ? (c < 43744
+                    ? (c >= 43739 && c <= 43741)
+                    : c <= 43754)
+                  : (c <= 43764 || (c >= 43777 && c <= 43782)))
+                : (c <= 43790 || (c < 43816
+                  ? (c < 43808
+                    ? (c >= 43793 && c <= 43798)
+                    : c <= 43814)
+                  : (c <= 43822 || (c >= 43824 && c <= 43866)))))))
+            : (c <= 43881 || (c < 64287
+              ? (c < 63744
+                ? (c < 55216
+                  ? (c < 44032
+                    ? (c >= 43888 && c <= 44002)
+                    : c <= 55203)
+                  : (c <= 55238 || (c >= 55243 && c <= 55291)))
+                : (c <= 64109 || (c < 64275
+                  ? (c < 64256
+                    ? (c >= 64112 && c <= 64217)
+                    : c <= 64262)
+                  : (c <= 64279 || c == 64285))))
+              : (c <= 64296 || (c < 64323
+                ? (c < 64318
+                  ? (c < 64312
+                    ? (c >= 64298 && c <= 64310)
+                    : c <= 64316)
+                  : (c <= 64318 || (c >= 64320 && c <= 64321)))
+                : (c <= 64324 || (c < 64612
+                  ? (c < 64467
+                    ? (c >= 64326 && c <= 64433)
+                    : c <= 64605)
+                  : (c <= 64829 || (c >= 64848 && c <= 64911)))))))))
+          : (c <= 64967 || (c < 65599
+            ? (c < 65382
+              ? (c < 65147
+                ? (c < 65139
+                  ? (c < 65137
+                    ? (c >= 65008 && c <= 65017)
+                    : c <= 65137)
+                  : (c <= 65139 || (c < 65145
+                    ? c == 65143
+                    : c <= 65145)))
+                : (c <= 65147 || (c < 65313
+                  ? (c < 65151
+                    ? c == 65149
+                    : c <= 65276)
+                  : (c <= 65338 || (c >= 65345 && c <= 65370)))))
+              : (c <= 65437 || (c < 65498
+                ? (c < 65482
+                  ? (c < 65474
+                    ? (c >= 65440 && c <= 65470)
+                    : c <= 65479)
+                  : (c <= 65487 || (c >= 65490 && c <= 65495)))
+                : (c <= 65500 || (c < 65576
+                  ? (c < 65549
+                    ? (c >= 65536 && c <= 65547)
+                    : c <= 65574)
+                  : (c <= 65594 || (c >= 65596 && c <= 65597)))))))
+            : (c <= 65613 || (c < 66464
+              ? (c < 66208
+                ? (c < 65856
+                  ? (c < 65664
+                    ? (c >= 65616 && c <= 65629)
+                    : c <= 65786)
+                  : (c <= 65908 || (c >= 66176 && c <= 66204)))
+                : (c <= 66256 || (c < 66384
+                  ? (c < 66349
+                    ? (c >= 66304 && c <= 66335)
+                    : c <= 66378)
+                  : (c <= 66421 || (c >= 66432 && c <= 66461)))))
+              : (c <= 66499 || (c < 66776
+                ? (c < 66560
+                  ? (c < 66513
+                    ? (c >= 66504 && c <= 66511)
+                    : c <= 66517)
+                  : (c <= 66717 || (c >= 66736 && c <= 66771)))
+                : (c <= 66811 || (c < 66928
+                  ? (c < 66864
+                    ? (c >= 66816 && c <= 66855)
+                    : c <= 66915)
+                  : (c <= 66938 || (c >= 66940 && c <= 66954)))))))))))
+        : (c <= 66962 || (c < 68864
+          ? (c < 67828
+            ? (c < 67506
+              ? (c < 67072
+                ? (c < 66979
+                  ? (c < 66967
+                    ? (c >= 66964 && c <= 66965)
+                    : c <= 66977)
+                  : (c <= 66993 || (c < 67003
+                    ? (c >= 66995 && c <= 67001)
+                    : c <= 67004)))
+                : (c <= 67382 || (c < 67456
+                  ? (c < 67424
+                    ? (c >= 67392 && c <= 67413)
+                    : c <= 67431)
+                  : (c <= 67461 || (c >= 67463 && c <= 67504)))))
+              : (c <= 67514 || (c < 67644
+                ? (c < 67594
+                  ? (c < 67592
+                    ? (c >= 67584 && c <= 67589)
+                    : c <= 67592)
+                  : (c <= 67637 || (c >= 67639 && c <= 67640)))
+                : (c <= 67644 || (c < 67712
+                  ? (c < 67680
+                    ? (c >= 67647 && c <= 67669)
+                    : c <= 67702)
+                  : (c <= 67742 || (c >= 67808 && c <= 67826)))))))
+            : (c <= 67829 || (c < 68224
+              ? (c < 68096
+                ? (c < 67968
+                  ? (c < 67872
+                    ? (c >= 67840 && c <= 67861)
+                    : c <= 67897)
+                  : (c <= 68023 || (c >= 68030 && c <= 68031)))
+                : (c <= 68096 || (c < 68121
+                  ? (c < 68117
+                    ? (c >= 68112 && c <= 68115)
+                    : c <= 68119)
+                  : (c <= 68149 || (c >= 68192 && c <= 68220)))))
+              : (c <= 68252 || (c < 68448
+                ? (c < 68352
+                  ? (c < 68297
+                    ? (c >= 68288 && c <= 68295)
+                    : c <= 68324)
+                  : (c <= 68405 || (c >= 68416 && c <= 68437)))
+                : (c <= 68466 || (c < 68736
+                  ? (c < 68608
+                    ? (c >= 68480 && c <= 68497)
+                    : c <= 68680)
+                  : (c <= 68786 || (c >= 68800 && c <= 68850)))))))))
+          : (c <= 68899 || (c < 70106
+            ? (c < 69749
+              ? (c < 69488
+                ? (c < 69376
+                  ? (c < 69296
+                    ? (c >= 69248 && c <= 69289)
+                    : c <= 69297)
+                  : (c <= 69404 || (c < 69424
+                    ? c == 69415
+                    : c <= 69445)))
+                : (c <= 69505 || (c < 69635
+                  ? (c < 69600
+                    ? (c >= 69552 && c <= 69572)
+                    : c <= 69622)
+                  : (c <= 69687 || (c >= 69745 && c <= 69746)))))
+              : (c <= 69749 || (c < 69959
+                ? (c < 69891
+                  ? (c < 69840
+                    ? (c >= 69763 && c <= 69807)
+                    : c <= 69864)
+                  : (c <= 69926 || c == 69956))
+                : (c <= 69959 || (c < 70019
+                  ? (c < 70006
+                    ? (c >= 69968 && c <= 70002)
+                    : c <= 70006)
+                  : (c <= 70066 || (c >= 70081 && c <= 70084)))))))
+            : (c <= 70106 || (c < 70405
+              ? (c < 70280
+                ? (c < 70163
+                  ? (c < 70144
+                    ? c == 70108
+                    : c <= 70161)
+                  : (c <= 70187 || (c >= 70272 && c <= 70278)))
+                : (c <= 70280 || (c < 70303
+                  ? (c < 70287
+                    ? (c >= 70282 && c <= 70285)
+                    : c <= 70301)
+                  : (c <= 70312 || (c >= 70320 && c <= 70366)))))
+              : (c <= 70412 || (c < 70453
+                ? (c < 70442
+                  ? (c < 70419
+                    ? (c >= 70415 && c <= 70416)
+                    : c <= 70440)
+                  : (c <= 70448 || (c >= 70450 && c <= 70451)))
+                : (c <= 70457 || (c < 70493
+                  ? (c < 70480
+                    ? c == 70461
+                    : c <= 70480)
+                  : (c <= 70497 || (c >= 70656 && c <= 70708)))))))))))))
+      : (c <= 70730 || (c < 119894
+        ? (c < 73056
+          ? (c < 72001
+            ? (c < 71424
+              ? (c < 71128
+                ? (c < 70852
+                  ? (c < 70784
+                    ? (c >= 70751 && c <= 70753)
+                    : c <= 70831)
+                  : (c <= 70853 || (c < 71040
+                    ? c == 70855
+                    : c <= 71086)))
+                : (c <= 71131 || (c < 71296
+                  ? (c < 71236
+                    ? (c >= 71168 && c <= 71215)
+                    : c <= 71236)
+                  : (c <= 71338 || c == 71352))))
+              : (c <= 71450 || (c < 71945
+                ? (c < 71840
+                  ? (c < 71680
+                    ? (c >= 71488 && c <= 71494)
+                    : c <= 71723)
+                  : (c <= 71903 || (c >= 71935 && c <= 71942)))
+                : (c <= 71945 || (c < 71960
+                  ? (c < 71957
+                    ? (c >= 71948 && c <= 71955)
+                    : c <= 71958)
+                  : (c <= 71983 || c == 71999))))))
+            : (c <= 72001 || (c < 72349
+              ? (c < 72192
+                ? (c < 72161
+                  ? (c < 72106
+                    ? (c >= 72096 && c <= 72103)
+                    : c <= 72144)
+                  : (c <= 72161 || c == 72163))
+                : (c <= 72192 || (c < 72272
+                  ? (c < 72250
+                    ? (c >= 72203 && c <= 72242)
+                    : c <= 72250)
+                  : (c <= 72272 || (c >= 72284 && c <= 72329)))))
+              : (c <= 72349 || (c < 72818
+                ? (c < 72714
+                  ? (c < 72704
+                    ? (c >= 72368 && c <= 72440)
+                    : c <= 72712)
+                  : (c <= 72750 || c == 72768))
+                : (c <= 72847 || (c < 72971
+                  ? (c < 72968
+                    ? (c >= 72960 && c <= 72966)
+                    : c <= 72969)
+                  : (c <= 73008 || c == 73030))))))))
+          : (c <= 73061 || (c < 93952
+            ? (c < 82944
+              ? (c < 73728
+                ? (c < 73112
+                  ? (c < 73066
+                    ? (c >= 73063 && c <= 73064)
+                    : c <= 73097)
+                  : (c <= 73112 || (c < 73648
+                    ? (c >= 73440 && c <= 73458)
+                    : c <= 73648)))
+                : (c <= 74649 || (c < 77712
+                  ? (c < 74880
+                    ? (c >= 74752 && c <= 74862)
+                    : c <= 75075)
+                  : (c <= 77808 || (c >= 77824 && c <= 78894)))))
+              : (c <= 83526 || (c < 92928
+                ? (c < 92784
+                  ? (c < 92736
+                    ? (c >= 92160 && c <= 92728)
+                    : c <= 92766)
+                  : (c <= 92862 || (c >= 92880 && c <= 92909)))
+                : (c <= 92975 || (c < 93053
+                  ? (c < 93027
+                    ? (c >= 92992 && c <= 92995)
+                    : c <= 93047)
+                  : (c <= 93071 || (c >= 93760 && c <= 93823)))))))
+            : (c <= 94026 || (c < 110589
+              ? (c < 94208
+                ? (c < 94176
+                  ? (c < 94099
+                    ? c == 94032
+                    : c <= 94111)
+                  : (c <= 94177 || c == 94179))
+                : (c <= 100343 || (c < 110576
+                  ? (c < 101632
+                    ? (c >= 100352 && c <= 101589)
+                    : c <= 101640)
+                  : (c <= 110579 || (c >= 110581 && c <= 110587)))))
+              : (c <= 110590 || (c < 113664
+                ? (c < 110948
+                  ? (c < 110928
+                    ? (c >= 110592 && c <= 110882)
+                    : c <= 110930)
+                  : (c <= 110951 || (c >= 110960 && c <= 111355)))
+                : (c <= 113770 || (c < 113808
+                  ? (c < 113792
+                    ? (c >= 113776 && c <= 113788)
+                    : c <= 113800)
+                  : (c <= 113817 || (c >= 119808 && c <= 119892)))))))))))
+        : (c <= 119964 || (c < 125259
+          ? (c < 120572
+            ? (c < 120086
+              ? (c < 119995
+                ? (c < 119973
+                  ? (c < 119970
+                    ? (c >= 119966 && c <= 119967)
+                    : c <= 119970)
+                  : (c <= 119974 || (c < 119982
+                    ? (c >= 119977 && c <= 119980)
+                    : c <= 119993)))
+                : (c <= 119995 || (c < 120071
+                  ? (c < 120005
+                    ? (c >= 119997 && c <= 120003)
+                    : c <= 120069)
+                  : (c <= 120074 || (c >= 120077 && c <= 120084)))))
+              : (c <= 120092 || (c < 120138
+                ? (c < 120128
+                  ? (c < 120123
+                    ? (c >= 120094 && c <= 120121)
+                    : c <= 120126)
+                  : (c <= 120132 || c == 120134))
+                : (c <= 120144 || (c < 120514
+                  ? (c < 120488
+                    ? (c >= 120146 && c <= 120485)
+                    : c <= 120512)
+                  : (c <= 120538 || (c >= 120540 && c <= 120570)))))))
+            : (c <= 120596 || (c < 123191
+              ? (c < 120714
+                ? (c < 120656
+                  ? (c < 120630
+                    ? (c >= 120598 && c <= 120628)
+                    : c <= 120654)
+                  : (c <= 120686 || (c >= 120688 && c <= 120712)))
+                : (c <= 120744 || (c < 122624
+                  ? (c < 120772
+                    ? (c >= 120746 && c <= 120770)
+                    : c <= 120779)
+                  : (c <= 122654 || (c >= 123136 && c <= 123180)))))
+              : (c <= 123197 || (c < 124904
+                ? (c < 123584
+                  ? (c < 123536
+                    ? c == 123214
+                    : c <= 123565)
+                  : (c <= 123627 || (c >= 124896 && c <= 124902)))
+                : (c <= 124907 || (c < 124928
+                  ? (c < 124912
+                    ? (c >= 124909 && c <= 124910)
+                    : c <= 124926)
+                  : (c <= 125124 || (c >= 125184 && c <= 125251)))))))))
+          : (c <= 125259 || (c < 126559
+            ? (c < 126535
+              ? (c < 126505
+                ? (c < 126497
+                  ? (c < 126469
+                    ? (c >= 126464 && c <= 126467)
+                    : c <= 126495)
+                  : (c <= 126498 || (c < 126503
+                    ? c == 126500
+                    : c <= 126503)))
+                : (c <= 126514 || (c < 126523
+                  ? (c < 126521
+                    ? (c >= 126516 && c <= 126519)
+                    : c <= 126521)
+                  : (c <= 126523 || c == 126530))))
+              : (c <= 126535 || (c < 126548
+                ? (c < 126541
+                  ? (c < 126539
+                    ? c == 126537
+                    : c <= 126539)
+                  : (c <= 126543 || (c >= 126545 && c <= 126546)))
+                : (c <= 126548 || (c < 126555
+                  ? (c < 126553
+                    ? c == 126551
+                    : c <= 126553)
+                  : (c <= 126555 || c == 126557))))))
+            : (c <= 126559 || (c < 126625
+              ? (c < 126580
+                ? (c < 126567
+                  ? (c < 126564
+                    ? (c >= 126561 && c <= 126562)
+                    : c <= 126564)
+                  : (c <= 126570 || (c >= 126572 && c <= 126578)))
+                : (c <= 126583 || (c < 126592
+                  ? (c < 126590
+                    ? (c >= 126585 && c <= 126588)
+                    : c <= 126590)
+                  : (c <= 126601 || (c >= 126603 && c <= 126619)))))
+              : (c <= 126627 || (c < 177984
+                ? (c < 131072
+                  ? (c < 126635
+                    ? (c >= 126629 && c <= 126633)
+                    : c <= 126651)
+                  : (c <= 173791 || (c >= 173824 && c <= 177976)))
+                : (c <= 178205 || (c < 194560
+                  ? (c < 183984
+                    ? (c >= 178208 && c <= 183969)
+                    : c <= 191456)
+                  : (c <= 195101 || (c >= 196608 && c <= 201546)))))))))))))))));
+}
+
+static inline bool sym_identifier_character_set_2(int32_t c) {
+  return (c < 43616
+    ? (c < 3782
+      ? (c < 2748
+        ? (c < 2045
+          ? (c < 1015
+            ? (c < 710
+              ? (c < 181
+                ? (c < '_'
+                  ? (c < 'A'
+                    ? (c >= '0' && c <= '9')
+                    : c <= 'Z')
+                  : (c <= '_' || (c < 170
+                    ? (c >= 'a' && c <= 'z')
+                    : c <= 170)))
+                : (c <= 181 || (c < 192
+                  ? (c < 186
+                    ? c == 183
+                    : c <= 186)
+                  : (c <= 214 || (c < 248
+                    ? (c >= 216 && c <= 246)
+                    : c <= 705)))))
+              : (c <= 721 || (c < 891
+                ? (c < 750
+                  ? (c < 748
+                    ? (c >= 736 && c <= 740)
+                    : c <= 748)
+                  : (c <= 750 || (c < 886
+                    ? (c >= 768 && c <= 884)
+                    : c <= 887)))
+                : (c <= 893 || (c < 908
+                  ? (c < 902
+                    ? c == 895
+                    : c <= 906)
+                  : (c <= 908 || (c < 931
+                    ? (c >= 910 && c <= 929)
+                    : c <= 1013)))))))
+            : (c <= 1153 || (c < 1519
+              ? (c < 1425
+                ? (c < 1329
+                  ? (c < 1162
+                    ? (c >= 1155 && c <= 1159)
+                    : c <= 1327)
+                  : (c <= 1366 || (c < 1376
+                    ? c == 1369
+                    : c <= 1416)))
+                : (c <= 1469 || (c < 1476
+                  ? (c < 1473
+                    ? c == 1471
+                    : c <= 1474)
+                  : (c <= 1477 || (c < 1488
+                    ? c == 1479
+                    : c <= 1514)))))
+              : (c <= 1522 || (c < 1770
+                ? (c < 1646
+                  ? (c < 1568
+                    ? (c >= 1552 && c <= 1562)
+                    : c <= 1641)
+                  : (c <= 1747 || (c < 1759
+                    ? (c >= 1749 && c <= 1756)
+                    : c <= 1768)))
+                : (c <= 1788 || (c < 1869
+                  ? (c < 1808
+                    ? c == 1791
+                    : c <= 1866)
+                  : (c <= 1969 || (c < 2042
+                    ? (c >= 1984 && c <= 2037)
+                    : c <= 2042)))))))))
+          : (c <= 2045 || (c < 2558
+            ? (c < 2451
+              ? (c < 2200
+                ? (c < 2144
+                  ? (c < 2112
+                    ? (c >= 2048 && c <= 2093)
+                    : c <= 2139)
+                  : (c <= 2154 || (c < 2185
+                    ? (c >= 2160 && c <= 2183)
+                    : c <= 2190)))
+                : (c <= 2273 || (c < 2417
+                  ? (c < 2406
+                    ? (c >= 2275 && c <= 2403)
+                    : c <= 2415)
+                  : (c <= 2435 || (c < 2447
+                    ? (c >= 2437 && c <= 2444)
+                    : c <= 2448)))))
+              : (c <= 2472 || (c < 2507
+                ? (c < 2486
+                  ? (c < 2482
+                    ? (c >= 2474 && c <= 2480)
+                    : c <= 2482)
+                  : (c <= 2489 || (c < 2503
+                    ? (c >= 2492 && c <= 2500)
+                    : c <= 2504)))
+                : (c <= 2510 || (c < 2527
+                  ? (c < 2524
+                    ? c == 2519
+                    : c <= 2525)
+                  : (c <= 2531 || (c < 2556
+                    ? (c >= 2534 && c <= 2545)
+                    : c <= 2556)))))))
+            : (c <= 2558 || (c < 2635
+              ? (c < 2610
+                ? (c < 2575
+                  ? (c < 2565
+                    ? (c >= 2561 && c <= 2563)
+                    : c <= 2570)
+                  : (c <= 2576 || (c < 2602
+                    ? (c >= 2579 && c <= 2600)
+                    : c <= 2608)))
+                : (c <= 2611 || (c < 2620
+                  ? (c < 2616
+                    ? (c >= 2613 && c <= 2614)
+                    : c <= 2617)
+                  : (c <= 2620 || (c < 2631
+                    ? (c >= 2622 && c <= 2626)
+                    : c <= 2632)))))
+              : (c <= 2637 || (c < 2693
+                ? (c < 2654
+                  ? (c < 2649
+                    ? c == 2641
+                    : c <= 2652)
+                  : (c <= 2654 || (c < 2689
+                    ? (c >= 2662 && c <= 2677)
+                    : c <= 2691)))
+                : (c <= 2701 || (c < 2730
+                  ? (c < 2707
+                    ? (c >= 2703 && c <= 2705)
+                    : c <= 2728)
+                  : (c <= 2736 || (c < 2741
+                    ? (c >= 2738 && c <= 2739)
+                    : c <= 2745)))))))))))
+        : (c <= 2757 || (c < 3168
+          ? (c < 2958
+            ? (c < 2866
+              ? (c < 2809
+                ? (c < 2768
+                  ? (c < 2763
+                    ? (c >= 2759 && c <= 2761)
+                    : c <= 2765)
+                  : (c <= 2768 || (c < 2790
+                    ? (c >= 2784 && c <= 2787)
+                    : c <= 2799)))
+                : (c <= 2815 || (c < 2831
+                  ? (c < 2821
+                    ? (c >= 2817 && c <= 2819)
+                    : c <= 2828)
+                  : (c <= 2832 || (c < 2858
+                    ? (c >= 2835 && c <= 2856)
+                    : c <= 2864)))))
+              : (c <= 2867 || (c < 2908
+                ? (c < 2887
+                  ? (c < 2876
+                    ? (c >= 2869 && c <= 2873)
+                    : c <= 2884)
+                  : (c <= 2888 || (c < 2901
+                    ? (c >= 2891 && c <= 2893)
+                    : c <= 2903)))
+                : (c <= 2909 || (c < 2929
+                  ? (c < 2918
+                    ? (c >= 2911 && c <= 2915)
+                    : c <= 2927)
+                  : (c <= 2929 || (c < 2949
+                    ? (c >= 2946 && c <= 2947)
+                    : c <= 2954)))))))
+            : (c <= 2960 || (c < 3031
+              ? (c < 2984
+                ? (c < 2972
+                  ? (c < 2969
+                    ? (c >= 2962 && c <= 2965)
+                    : c <= 2970)
+                  : (c <= 2972 || (c < 2979
+                    ? (c >= 2974 && c <= 2975)
+                    : c <= 2980)))
+                : (c <= 2986 || (c < 3014
+                  ? (c < 3006
+                    ? (c >= 2990 && c <= 3001)
+                    : c <= 3010)
+                  : (c <= 3016 || (c < 3024
+                    ? (c >= 3018 && c <= 3021)
+                    : c <= 3024)))))
+              : (c <= 3031 || (c < 3132
+                ? (c < 3086
+                  ? (c < 3072
+                    ? (c >= 3046 && c <= 3055)
+                    : c <= 3084)
+                  : (c <= 3088 || (c < 3114
+                    ? (c >= 3090 && c <= 3112)
+                    : c <= 3129)))
+                : (c <= 3140 || (c < 3157
+                  ? (c < 3146
+                    ? (c >= 3142 && c <= 3144)
+                    : c <= 3149)
+                  : (c <= 3158 || (c < 3165
+                    ? (c >= 3160 && c <= 3162)
+                    : c <= 3165)))))))))
+          : (c <= 3171 || (c < 3450
+            ? (c < 3293
+              ? (c < 3242
+                ? (c < 3205
+                  ? (c < 3200
+                    ? (c >= 3174 && c <= 3183)
+                    : c <= 3203)
+                  : (c <= 3212 || (c < 3218
+                    ? (c >= 3214 && c <= 3216)
+                    : c <= 3240)))
+                : (c <= 3251 || (c < 3270
+                  ? (c < 3260
+                    ? (c >= 3253 && c <= 3257)
+                    : c <= 3268)
+                  : (c <= 3272 || (c < 3285
+                    ? (c >= 3274 && c <= 3277)
+                    : c <= 3286)))))
+              : (c <= 3294 || (c < 3346
+                ? (c < 3313
+                  ? (c < 3302
+                    ? (c >= 3296 && c <= 3299)
+                    : c <= 3311)
+                  : (c <= 3314 || (c < 3342
+                    ? (c >= 3328 && c <= 3340)
+                    : c <= 3344)))
+                : (c <= 3396 || (c < 3412
+                  ? (c < 3402
+                    ? (c >= 3398 && c <= 3400)
+                    : c <= 3406)
+                  : (c <= 3415 || (c < 3430
+                    ? (c >= 3423 && c <= 3427)
+                    : c <= 3439)))))))
+            : (c <= 3455 || (c < 3570
+              ? (c < 3520
+                ? (c < 3482
+                  ? (c < 3461
+                    ? (c >= 3457 && c <= 3459)
+                    : c <= 3478)
+                  : (c <= 3505 || (c < 3517
+                    ? (c >= 3507 && c <= 3515)
+                    : c <= 3517)))
+                : (c <= 3526 || (c < 3542
+                  ? (c < 3535
+                    ? c == 3530
+                    : c <= 3540)
+                  : (c <= 3542 || (c < 3558
+                    ? (c >= 3544 && c <= 3551)
+                    : c <= 3567)))))
+              : (c <= 3571 || (c < 3718
+                ? (c < 3664
+                  ? (c < 3648
+                    ? (c >= 3585 && c <= 3642)
+                    : c <= 3662)
+                  : (c <= 3673 || (c < 3716
+                    ? (c >= 3713 && c <= 3714)
+                    : c <= 3716)))
+                : (c <= 3722 || (c < 3751
+                  ? (c < 3749
+                    ? (c >= 3724 && c <= 3747)
+                    : c <= 3749)
+                  : (c <= 3773 || (c >= 3776 && c <= 3780)))))))))))))
+      : (c <= 3782 || (c < 8025
+        ? (c < 5888
+          ? (c < 4688
+            ? (c < 3953
+              ? (c < 3872
+                ? (c < 3804
+                  ? (c < 3792
+                    ? (c >= 3784 && c <= 3789)
+                    : c <= 3801)
+                  : (c <= 3807 || (c < 3864
+                    ? c == 3840
+                    : c <= 3865)))
+                : (c <= 3881 || (c < 3897
+                  ? (c < 3895
+                    ? c == 3893
+                    : c <= 3895)
+                  : (c <= 3897 || (c < 3913
+                    ? (c >= 3902 && c <= 3911)
+                    : c <= 3948)))))
+              : (c <= 3972 || (c < 4256
+                ? (c < 4038
+                  ? (c < 3993
+                    ? (c >= 3974 && c <= 3991)
+                    : c <= 4028)
+                  : (c <= 4038 || (c < 4176
+                    ? (c >= 4096 && c <= 4169)
+                    : c <= 4253)))
+                : (c <= 4293 || (c < 4304
+                  ? (c < 4301
+                    ? c == 4295
+                    : c <= 4301)
+                  : (c <= 4346 || (c < 4682
+                    ? (c >= 4348 && c <= 4680)
+                    : c <= 4685)))))))
+            : (c <= 4694 || (c < 4882
+              ? (c < 4786
+                ? (c < 4704
+                  ? (c < 4698
+                    ? c == 4696
+                    : c <= 4701)
+                  : (c <= 4744 || (c < 4752
+                    ? (c >= 4746 && c <= 4749)
+                    : c <= 4784)))
+                : (c <= 4789 || (c < 4802
+                  ? (c < 4800
+                    ? (c >= 4792 && c <= 4798)
+                    : c <= 4800)
+                  : (c <= 4805 || (c < 4824
+                    ? (c >= 4808 && c <= 4822)
+                    : c <= 4880)))))
+              : (c <= 4885 || (c < 5112
+                ? (c < 4969
+                  ? (c < 4957
+                    ? (c >= 4888 && c <= 4954)
+                    : c <= 4959)
+                  : (c <= 4977 || (c < 5024
+                    ? (c >= 4992 && c <= 5007)
+                    : c <= 5109)))
+                : (c <= 5117 || (c < 5761
+                  ? (c < 5743
+                    ? (c >= 5121 && c <= 5740)
+                    : c <= 5759)
+                  : (c <= 5786 || (c < 5870
+                    ? (c >= 5792 && c <= 5866)
+                    : c <= 5880)))))))))
+          : (c <= 5909 || (c < 6688
+            ? (c < 6176
+              ? (c < 6016
+                ? (c < 5984
+                  ? (c < 5952
+                    ? (c >= 5919 && c <= 5940)
+                    : c <= 5971)
+                  : (c <= 5996 || (c < 6002
+                    ? (c >= 5998 && c <= 6000)
+                    : c <= 6003)))
+                : (c <= 6099 || (c < 6112
+                  ? (c < 6108
+                    ? c == 6103
+                    : c <= 6109)
+                  : (c <= 6121 || (c < 6159
+                    ? (c >= 6155 && c <= 6157)
+                    : c <= 6169)))))
+              : (c <= 6264 || (c < 6470
+                ? (c < 6400
+                  ? (c < 6320
+                    ? (c >= 6272 && c <= 6314)
+                    : c <= 6389)
+                  : (c <= 6430 || (c < 6448
+                    ? (c >= 6432 && c <= 6443)
+                    : c <= 6459)))
+                : (c <= 6509 || (c < 6576
+                  ? (c < 6528
+                    ? (c >= 6512 && c <= 6516)
+                    : c <= 6571)
+                  : (c <= 6601 || (c < 6656
+                    ? (c >= 6608 && c <= 6618)
+                    : c <= 6683)))))))
+            : (c <= 6750 || (c < 7232
+              ? (c < 6847
+                ? (c < 6800
+                  ? (c < 6783
+                    ? (c >= 6752 && c <= 6780)
+                    : c <= 6793)
+                  : (c <= 6809 || (c < 6832
+                    ? c == 6823
+                    : c <= 6845)))
+                : (c <= 6862 || (c < 7019
+                  ? (c < 6992
+                    ? (c >= 6912 && c <= 6988)
+                    : c <= 7001)
+                  : (c <= 7027 || (c < 7168
+                    ? (c >= 7040 && c <= 7155)
+                    : c <= 7223)))))
+              : (c <= 7241 || (c < 7380
+                ? (c < 7312
+                  ? (c < 7296
+                    ? (c >= 7245 && c <= 7293)
+                    : c <= 7304)
+                  : (c <= 7354 || (c < 7376
+                    ? (c >= 7357 && c <= 7359)
+                    : c <= 7378)))
+                : (c <= 7418 || (c < 7968
+                  ? (c < 7960
+                    ? (c >= 7424 && c <= 7957)
+                    : c <= 7965)
+                  : (c <= 8005 || (c < 8016
+                    ? (c >= 8008 && c <= 8013)
+                    : c <= 8023)))))))))))
+        : (c <= 8025 || (c < 11720
+          ? (c < 8458
+            ? (c < 8178
+              ? (c < 8126
+                ? (c < 8031
+                  ? (c < 8029
+                    ? c == 8027
+                    : c <= 8029)
+                  : (c <= 8061 || (c < 8118
+                    ? (c >= 8064 && c <= 8116)
+                    : c <= 8124)))
+                : (c <= 8126 || (c < 8144
+                  ? (c < 8134
+                    ? (c >= 8130 && c <= 8132)
+                    : c <= 8140)
+                  : (c <= 8147 || (c < 8160
+                    ? (c >= 8150 && c <= 8155)
+                    : c <= 8172)))))
+              : (c <= 8180 || (c < 8336
+                ? (c < 8276
+                  ? (c < 8255
+                    ? (c >= 8182 && c <= 8188)
+                    : c <= 8256)
+                  : (c <= 8276 || (c < 8319
+                    ? c == 8305
+                    : c <= 8319)))
+                : (c <= 8348 || (c < 8421
+                  ? (c < 8417
+                    ? (c >= 8400 && c <= 8412)
+                    : c <= 8417)
+                  : (c <= 8432 || (c < 8455
+                    ? c == 8450
+                    : c <= 8455)))))))
+            : (c <= 8467 || (c < 11499
+              ? (c < 8490
+                ? (c < 8484
+                  ? (c < 8472
+                    ? c == 8469
+                    : c <= 8477)
+                  : (c <= 8484 || (c < 8488
+                    ? c == 8486
+                    : c <= 8488)))
+                : (c <= 8505 || (c < 8526
+                  ? (c < 8517
+                    ? (c >= 8508 && c <= 8511)
+                    : c <= 8521)
+                  : (c <= 8526 || (c < 11264
+                    ? (c >= 8544 && c <= 8584)
+                    : c <= 11492)))))
+              : (c <= 11507 || (c < 11647
+                ? (c < 11565
+                  ? (c < 11559
+                    ? (c >= 11520 && c <= 11557)
+                    : c <= 11559)
+                  : (c <= 11565 || (c < 11631
+                    ? (c >= 11568 && c <= 11623)
+                    : c <= 11631)))
+                : (c <= 11670 || (c < 11696
+                  ? (c < 11688
+                    ? (c >= 11680 && c <= 11686)
+                    : c <= 11694)
+                  : (c <= 11702 || (c < 11712
+                    ? (c >= 11704 && c <= 11710)
+                    : c <= 11718)))))))))
+          : (c <= 11726 || (c < 42623
+            ? (c < 12540
+              ? (c < 12337
+                ? (c < 11744
+                  ? (c < 11736
+                    ? (c >= 11728 && c <= 11734)
+                    : c <= 11742)
+                  : (c <= 11775 || (c < 12321
+                    ? (c >= 12293 && c <= 12295)
+                    : c <= 12335)))
+                : (c <= 12341 || (c < 12441
+                  ? (c < 12353
+                    ? (c >= 12344 && c <= 12348)
+                    : c <= 12438)
+                  : (c <= 12442 || (c < 12449
+                    ? (c >= 12445 && c <= 12447)
+                    : c <= 12538)))))
+              : (c <= 12543 || (c < 19968
+                ? (c < 12704
+                  ? (c < 12593
+                    ? (c >= 12549 && c <= 12591)
+                    : c <= 12686)
+                  : (c <= 12735 || (c < 13312
+                    ? (c >= 12784 && c <= 12799)
+                    : c <= 19903)))
+                : (c <= 42124 || (c < 42512
+                  ? (c < 42240
+                    ? (c >= 42192 && c <= 42237)
+                    : c <= 42508)
+                  : (c <= 42539 || (c < 42612
+                    ? (c >= 42560 && c <= 42607)
+                    : c <= 42621)))))))
+            : (c <= 42737 || (c < 43232
+              ? (c < 42965
+                ? (c < 42891
+                  ? (c < 42786
+                    ? (c >= 42775 && c <= 42783)
+                    : c <= 42888)
+                  : (c <= 42954 || (c < 42963
+                    ? (c >= 42960 && c <= 42961)
+                    : c <= 42963)))
+                : (c <= 42969 || (c < 43072
+                  ? (c < 43052
+                    ? (c >= 42994 && c <= 43047)
+                    : c <= 43052)
+                  : (c <= 43123 || (c < 43216
+                    ? (c >= 43136 && c <= 43205)
+                    : c <= 43225)))))
+              : (c <= 43255 || (c < 43471
+                ? (c < 43312
+                  ? (c < 43261
+                    ? c == 43259
+                    : c <= 43309)
+                  : (c <= 43347 || (c < 43392
+                    ? (c >= 43360 && c <= 43388)
+                    : c <= 43456)))
+                : (c <= 43481 || (c < 43584
+                  ? (c < 43520
+                    ? (c >= 43488 && c <= 43518)
+                    : c <= 43574)
+                  : (c <= 43597 || (c >= 43600 && c <= 43609)))))))))))))))
+    : (c <= 43638 || (c < 71453
+      ? (c < 67639
+        ? (c < 65345
+          ? (c < 64312
+            ? (c < 43888
+              ? (c < 43785
+                ? (c < 43744
+                  ? (c < 43739
+                    ? (c >= 43642 && c <= 43714)
+                    : c <= 43741)
+                  : (c <= 43759 || (c < 43777
+                    ? (c >= 43762 && c <= 43766)
+                    : c <= 43782)))
+                : (c <= 43790 || (c < 43816
+                  ? (c < 43808
+                    ? (c >= 43793 && c <= 43798)
+                    : c <= 43814)
+                  : (c <= 43822 || (c < 43868
+                    ? (c >= 43824 && c <= 43866)
+                    : c <= 43881)))))
+              : (c <= 44010 || (c < 63744
+                ? (c < 44032
+                  ? (c < 44016
+                    ? (c >= 44012 && c <= 44013)
+                    : c <= 44025)
+                  : (c <= 55203 || (c < 55243
+                    ? (c >= 55216 && c <= 55238)
+                    : c <= 55291)))
+                : (c <= 64109 || (c < 64275
+                  ? (c < 64256
+                    ? (c >= 64112 && c <= 64217)
+                    : c <= 64262)
+                  : (c <= 64279 || (c < 64298
+                    ? (c >= 64285 && c <= 64296)
+                    : c <= 64310)))))))
+            : (c <= 64316 || (c < 65075
+              ? (c < 64612
+                ? (c < 64323
+                  ? (c < 64320
+                    ? c == 64318
+                    : c <= 64321)
+                  : (c <= 64324 || (c < 64467
+                    ? (c >= 64326 && c <= 64433)
+                    : c <= 64605)))
+                : (c <= 64829 || (c < 65008
+                  ? (c < 64914
+                    ? (c >= 64848 && c <= 64911)
+                    : c <= 64967)
+                  : (c <= 65017 || (c < 65056
+                    ? (c >= 65024 && c <= 65039)
+                    : c <= 65071)))))
+              : (c <= 65076 || (c < 65147
+                ? (c < 65139
+                  ? (c < 65137
+                    ? (c >= 65101 && c <= 65103)
+                    : c <= 65137)
+                  : (c <= 65139 || (c < 65145
+                    ? c == 65143
+                    : c <= 65145)))
+                : (c <= 65147 || (c < 65296
+                  ? (c < 65151
+                    ? c == 65149
+                    : c <= 65276)
+                  : (c <= 65305 || (c < 65343
+                    ? (c >= 65313 && c <= 65338)
+                    : c <= 65343)))))))))
+          : (c <= 65370 || (c < 66513
+            ? (c < 65664
+              ? (c < 65536
+                ? (c < 65482
+                  ? (c < 65474
+                    ? (c >= 65382 && c <= 65470)
+                    : c <= 65479)
+                  : (c <= 65487 || (c < 65498
+                    ? (c >= 65490 && c <= 65495)
+                    : c <= 65500)))
+                : (c <= 65547 || (c < 65596
+                  ? (c < 65576
+                    ? (c >= 65549 && c <= 65574)
+                    : c <= 65594)
+                  : (c <= 65597 || (c < 65616
+                    ? (c >= 65599 && c <= 65613)
+                    : c <= 65629)))))
+              : (c <= 65786 || (c < 66304
+                ? (c < 66176
+                  ? (c < 66045
+                    ? (c >= 65856 && c <= 65908)
+                    : c <= 66045)
+                  : (c <= 66204 || (c < 66272
+                    ? (c >= 66208 && c <= 66256)
+                    : c <= 66272)))
+                : (c <= 66335 || (c < 66432
+                  ? (c < 66384
+                    ? (c >= 66349 && c <= 66378)
+                    : c <= 66426)
+                  : (c <= 66461 || (c < 66504
+                    ? (c >= 66464 && c <= 66499)
+                    : c <= 66511)))))))
+            : (c <= 66517 || (c < 66979
+              ? (c < 66864
+                ? (c < 66736
+                  ? (c < 66720
+                    ? (c >= 66560 && c <= 66717)
+                    : c <= 66729)
+                  : (c <= 66771 || (c < 66816
+                    ? (c >= 66776 && c <= 66811)
+                    : c <= 66855)))
+                : (c <= 66915 || (c < 66956
+                  ? (c < 66940
+                    ? (c >= 66928 && c <= 66938)
+                    : c <= 66954)
+                  : (c <= 66962 || (c < 66967
+                    ? (c >= 66964 && c <= 66965)
+                    : c <= 66977)))))
+              : (c <= 66993 || (c < 67456
+                ? (c < 67072
+                  ? (c < 67003
+                    ? (c >= 66995 && c <= 67001)
+                    : c <= 67004)
+                  : (c <= 67382 || (c < 67424
+                    ? (c >= 67392 && c <= 67413)
+                    : c <= 67431)))
+                : (c <= 67461 || (c < 67584
+                  ? (c < 67506
+                    ? (c >= 67463 && c <= 67504)
+                    : c <= 67514)
+                  : (c <= 67589 || (c < 67594
+                    ? c == 67592
+                    : c <= 67637)))))))))))
+        : (c <= 67640 || (c < 69956
+          ? (c < 68448
+            ? (c < 68101
+              ? (c < 67828
+                ? (c < 67680
+                  ? (c < 67647
+                    ? c == 67644
+                    : c <= 67669)
+                  : (c <= 67702 || (c < 67808
+                    ? (c >= 67712 && c <= 67742)
+                    : c <= 67826)))
+                : (c <= 67829 || (c < 67968
+                  ? (c < 67872
+                    ? (c >= 67840 && c <= 67861)
+                    : c <= 67897)
+                  : (c <= 68023 || (c < 68096
+                    ? (c >= 68030 && c <= 68031)
+                    : c <= 68099)))))
+              : (c <= 68102 || (c < 68192
+                ? (c < 68121
+                  ? (c < 68117
+                    ? (c >= 68108 && c <= 68115)
+                    : c <= 68119)
+                  : (c <= 68149 || (c < 68159
+                    ? (c >= 68152 && c <= 68154)
+                    : c <= 68159)))
+                : (c <= 68220 || (c < 68297
+                  ? (c < 68288
+                    ? (c >= 68224 && c <= 68252)
+                    : c <= 68295)
+                  : (c <= 68326 || (c < 68416
+                    ? (c >= 68352 && c <= 68405)
+                    : c <= 68437)))))))
+            : (c <= 68466 || (c < 69424
+              ? (c < 68912
+                ? (c < 68736
+                  ? (c < 68608
+                    ? (c >= 68480 && c <= 68497)
+                    : c <= 68680)
+                  : (c <= 68786 || (c < 68864
+                    ? (c >= 68800 && c <= 68850)
+                    : c <= 68903)))
+                : (c <= 68921 || (c < 69296
+                  ? (c < 69291
+                    ? (c >= 69248 && c <= 69289)
+                    : c <= 69292)
+                  : (c <= 69297 || (c < 69415
+                    ? (c >= 69376 && c <= 69404)
+                    : c <= 69415)))))
+              : (c <= 69456 || (c < 69759
+                ? (c < 69600
+                  ? (c < 69552
+                    ? (c >= 69488 && c <= 69509)
+                    : c <= 69572)
+                  : (c <= 69622 || (c < 69734
+                    ? (c >= 69632 && c <= 69702)
+                    : c <= 69749)))
+                : (c <= 69818 || (c < 69872
+                  ? (c < 69840
+                    ? c == 69826
+                    : c <= 69864)
+                  : (c <= 69881 || (c < 69942
+                    ? (c >= 69888 && c <= 69940)
+                    : c <= 69951)))))))))
+          : (c <= 69959 || (c < 70459
+            ? (c < 70282
+              ? (c < 70108
+                ? (c < 70016
+                  ? (c < 70006
+                    ? (c >= 69968 && c <= 70003)
+                    : c <= 70006)
+                  : (c <= 70084 || (c < 70094
+                    ? (c >= 70089 && c <= 70092)
+                    : c <= 70106)))
+                : (c <= 70108 || (c < 70206
+                  ? (c < 70163
+                    ? (c >= 70144 && c <= 70161)
+                    : c <= 70199)
+                  : (c <= 70206 || (c < 70280
+                    ? (c >= 70272 && c <= 70278)
+                    : c <= 70280)))))
+              : (c <= 70285 || (c < 70405
+                ? (c < 70320
+                  ? (c < 70303
+                    ? (c >= 70287 && c <= 70301)
+                    : c <= 70312)
+                  : (c <= 70378 || (c < 70400
+                    ? (c >= 70384 && c <= 70393)
+                    : c <= 70403)))
+                : (c <= 70412 || (c < 70442
+                  ? (c < 70419
+                    ? (c >= 70415 && c <= 70416)
+                    : c <= 70440)
+                  : (c <= 70448 || (c < 70453
+                    ? (c >= 70450 && c <= 70451)
+                    : c <= 70457)))))))
+            : (c <= 70468 || (c < 70855
+              ? (c < 70502
+                ? (c < 70480
+                  ? (c < 70475
+                    ? (c >= 70471 && c <= 70472)
+                    : c <= 70477)
+                  : (c <= 70480 || (c < 70493
+                    ? c == 70487
+                    : c <= 70499)))
+                : (c <= 70508 || (c < 70736
+                  ? (c < 70656
+                    ? (c >= 70512 && c <= 70516)
+                    : c <= 70730)
+                  : (c <= 70745 || (c < 70784
+                    ? (c >= 70750 && c <= 70753)
+                    : c <= 70853)))))
+              : (c <= 70855 || (c < 71236
+                ? (c < 71096
+                  ? (c < 71040
+                    ? (c >= 70864 && c <= 70873)
+                    : c <= 71093)
+                  : (c <= 71104 || (c < 71168
+                    ? (c >= 71128 && c <= 71133)
+                    : c <= 71232)))
+                : (c <= 71236 || (c < 71360
+                  ? (c < 71296
+                    ? (c >= 71248 && c <= 71257)
+                    : c <= 71352)
+                  : (c <= 71369 || (c >= 71424 && c <= 71450)))))))))))))
+      : (c <= 71467 || (c < 119973
+        ? (c < 77824
+          ? (c < 72760
+            ? (c < 72016
+              ? (c < 71945
+                ? (c < 71680
+                  ? (c < 71488
+                    ? (c >= 71472 && c <= 71481)
+                    : c <= 71494)
+                  : (c <= 71738 || (c < 71935
+                    ? (c >= 71840 && c <= 71913)
+                    : c <= 71942)))
+                : (c <= 71945 || (c < 71960
+                  ? (c < 71957
+                    ? (c >= 71948 && c <= 71955)
+                    : c <= 71958)
+                  : (c <= 71989 || (c < 71995
+                    ? (c >= 71991 && c <= 71992)
+                    : c <= 72003)))))
+              : (c <= 72025 || (c < 72263
+                ? (c < 72154
+                  ? (c < 72106
+                    ? (c >= 72096 && c <= 72103)
+                    : c <= 72151)
+                  : (c <= 72161 || (c < 72192
+                    ? (c >= 72163 && c <= 72164)
+                    : c <= 72254)))
+                : (c <= 72263 || (c < 72368
+                  ? (c < 72349
+                    ? (c >= 72272 && c <= 72345)
+                    : c <= 72349)
+                  : (c <= 72440 || (c < 72714
+                    ? (c >= 72704 && c <= 72712)
+                    : c <= 72758)))))))
+            : (c <= 72768 || (c < 73056
+              ? (c < 72968
+                ? (c < 72850
+                  ? (c < 72818
+                    ? (c >= 72784 && c <= 72793)
+                    : c <= 72847)
+                  : (c <= 72871 || (c < 72960
+                    ? (c >= 72873 && c <= 72886)
+                    : c <= 72966)))
+                : (c <= 72969 || (c < 73020
+                  ? (c < 73018
+                    ? (c >= 72971 && c <= 73014)
+                    : c <= 73018)
+                  : (c <= 73021 || (c < 73040
+                    ? (c >= 73023 && c <= 73031)
+                    : c <= 73049)))))
+              : (c <= 73061 || (c < 73440
+                ? (c < 73104
+                  ? (c < 73066
+                    ? (c >= 73063 && c <= 73064)
+                    : c <= 73102)
+                  : (c <= 73105 || (c < 73120
+                    ? (c >= 73107 && c <= 73112)
+                    : c <= 73129)))
+                : (c <= 73462 || (c < 74752
+                  ? (c < 73728
+                    ? c == 73648
+                    : c <= 74649)
+                  : (c <= 74862 || (c < 77712
+                    ? (c >= 74880 && c <= 75075)
+                    : c <= 77808)))))))))
+          : (c <= 78894 || (c < 110576
+            ? (c < 93027
+              ? (c < 92864
+                ? (c < 92736
+                  ? (c < 92160
+                    ? (c >= 82944 && c <= 83526)
+                    : c <= 92728)
+                  : (c <= 92766 || (c < 92784
+                    ? (c >= 92768 && c <= 92777)
+                    : c <= 92862)))
+                : (c <= 92873 || (c < 92928
+                  ? (c < 92912
+                    ? (c >= 92880 && c <= 92909)
+                    : c <= 92916)
+                  : (c <= 92982 || (c < 93008
+                    ? (c >= 92992 && c <= 92995)
+                    : c <= 93017)))))
+              : (c <= 93047 || (c < 94176
+                ? (c < 93952
+                  ? (c < 93760
+                    ? (c >= 93053 && c <= 93071)
+                    : c <= 93823)
+                  : (c <= 94026 || (c < 94095
+                    ? (c >= 94031 && c <= 94087)
+                    : c <= 94111)))
+                : (c <= 94177 || (c < 94208
+                  ? (c < 94192
+                    ? (c >= 94179 && c <= 94180)
+                    : c <= 94193)
+                  : (c <= 100343 || (c < 101632
+                    ? (c >= 100352 && c <= 101589)
+                    : c <= 101640)))))))
+            : (c <= 110579 || (c < 118528
+              ? (c < 110960
+                ? (c < 110592
+                  ? (c < 110589
+                    ? (c >= 110581 && c <= 110587)
+                    : c <= 110590)
+                  : (c <= 110882 || (c < 110948
+                    ? (c >= 110928 && c <= 110930)
+                    : c <= 110951)))
+                : (c <= 111355 || (c < 113792
+                  ? (c < 113776
+                    ? (c >= 113664 && c <= 113770)
+                    : c <= 113788)
+                  : (c <= 113800 || (c < 113821
+                    ? (c >= 113808 && c <= 113817)
+                    : c <= 113822)))))
+              : (c <= 118573 || (c < 119210
+                ? (c < 119149
+                  ? (c < 119141
+                    ? (c >= 118576 && c <= 118598)
+                    : c <= 119145)
+                  : (c <= 119154 || (c < 119173
+                    ? (c >= 119163 && c <= 119170)
+                    : c <= 119179)))
+                : (c <= 119213 || (c < 119894
+                  ? (c < 119808
+                    ? (c >= 119362 && c <= 119364)
+                    : c <= 119892)
+                  : (c <= 119964 || (c < 119970
+                    ? (c >= 119966 && c <= 119967)
+                    : c <= 119970)))))))))))
+        : (c <= 119974 || (c < 124912
+          ? (c < 120746
+            ? (c < 120134
+              ? (c < 120071
+                ? (c < 119995
+                  ? (c < 119982
+                    ? (c >= 119977 && c <= 119980)
+                    : c <= 119993)
+                  : (c <= 119995 || (c < 120005
+                    ? (c >= 119997 && c <= 120003)
+                    : c <= 120069)))
+                : (c <= 120074 || (c < 120094
+                  ? (c < 120086
+                    ? (c >= 120077 && c <= 120084)
+                    : c <= 120092)
+                  : (c <= 120121 || (c < 120128
+                    ? (c >= 120123 && c <= 120126)
+                    : c <= 120132)))))
+              : (c <= 120134 || (c < 120572
+                ? (c < 120488
+                  ? (c < 120146
+                    ? (c >= 120138 && c <= 120144)
+                    : c <= 120485)
+                  : (c <= 120512 || (c < 120540
+                    ? (c >= 120514 && c <= 120538)
+                    : c <= 120570)))
+                : (c <= 120596 || (c < 120656
+                  ? (c < 120630
+                    ? (c >= 120598 && c <= 120628)
+                    : c <= 120654)
+                  : (c <= 120686 || (c < 120714
+                    ? (c >= 120688 && c <= 120712)
+                    : c <= 120744)))))))
+            : (c <= 120770 || (c < 122907
+              ? (c < 121476
+                ? (c < 121344
+                  ? (c < 120782
+                    ? (c >= 120772 && c <= 120779)
+                    : c <= 120831)
+                  : (c <= 121398 || (c < 121461
+                    ? (c >= 121403 && c <= 121452)
+                    : c <= 121461)))
+                : (c <= 121476 || (c < 122624
+                  ? (c < 121505
+                    ? (c >= 121499 && c <= 121503)
+                    : c <= 121519)
+                  : (c <= 122654 || (c < 122888
+                    ? (c >= 122880 && c <= 122886)
+                    : c <= 122904)))))
+              : (c <= 122913 || (c < 123214
+                ? (c < 123136
+                  ? (c < 122918
+                    ? (c >= 122915 && c <= 122916)
+                    : c <= 122922)
+                  : (c <= 123180 || (c < 123200
+                    ? (c >= 123184 && c <= 123197)
+                    : c <= 123209)))
+                : (c <= 123214 || (c < 124896
+                  ? (c < 123584
+                    ? (c >= 123536 && c <= 123566)
+                    : c <= 123641)
+                  : (c <= 124902 || (c < 124909
+                    ? (c >= 124904 && c <= 124907)
+                    : c <= 124910)))))))))
+          : (c <= 124926 || (c < 126557
+            ? (c < 126521
+              ? (c < 126469
+                ? (c < 125184
+                  ? (c < 125136
+                    ? (c >= 124928 && c <= 125124)
+                    : c <= 125142)
+                  : (c <= 125259 || (c < 126464
+                    ? (c >= 125264 && c <= 125273)
+                    : c <= 126467)))
+                : (c <= 126495 || (c < 126503
+                  ? (c < 126500
+                    ? (c >= 126497 && c <= 126498)
+                    : c <= 126500)
+                  : (c <= 126503 || (c < 126516
+                    ? (c >= 126505 && c <= 126514)
+                    : c <= 126519)))))
+              : (c <= 126521 || (c < 126541
+                ? (c < 126535
+                  ? (c < 126530
+                    ? c == 126523
+                    : c <= 126530)
+                  : (c <= 126535 || (c < 126539
+                    ? c == 126537
+                    : c <= 126539)))
+                : (c <= 126543 || (c < 126551
+                  ? (c < 126548
+                    ? (c >= 126545 && c <= 126546)
+                    : c <= 126548)
+                  : (c <= 126551 || (c < 126555
+                    ? c == 126553
+                    : c <= 126555)))))))
+            : (c <= 126557 || (c < 126629
+              ? (c < 126580
+                ? (c < 126564
+                  ? (c < 126561
+                    ? c == 126559
+                    : c <= 126562)
+                  : (c <= 126564 || (c < 126572
+                    ? (c >= 126567 && c <= 126570)
+                    : c <= 126578)))
+                : (c <= 126583 || (c < 126592
+                  ? (c < 126590
+                    ? (c >= 126585 && c <= 126588)
+                    : c <= 126590)
+                  : (c <= 126601 || (c < 126625
+                    ? (c >= 126603 && c <= 126619)
+                    : c <= 126627)))))
+              : (c <= 126633 || (c < 178208
+                ? (c < 131072
+                  ? (c < 130032
+                    ? (c >= 126635 && c <= 126651)
+                    : c <= 130041)
+                  : (c <= 173791 || (c < 177984
+                    ? (c >= 173824 && c <= 177976)
+                    : c <= 178205)))
+                : (c <= 183969 || (c < 196608
+                  ? (c < 194560
+                    ? (c >= 183984 && c <= 191456)
+                    : c <= 195101)
+                  : (c <= 201546 || (c >= 917760 && c <= 917999)))))))))))))))));
+}
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(24);
+      if (lookahead == '!') ADVANCE(8);
+      if (lookahead == '"') ADVANCE(72);
+      if (lookahead == '#') ADVANCE(1);
+      if (lookahead == '%') ADVANCE(55);
+      if (lookahead == '&') ADVANCE(49);
+      if (lookahead == '\'') ADVANCE(11);
+      if (lookahead == '(') ADVANCE(33);
+      if (lookahead == ')') ADVANCE(35);
+      if (lookahead == '*') ADVANCE(53);
+      if (lookahead == '+') ADVANCE(52);
+      if (lookahead == ',') ADVANCE(34);
+      if (lookahead == '-') ADVANCE(40);
+      if (lookahead == '.') ADVANCE(38);
+      if (lookahead == '/') ADVANCE(54);
+      if (lookahead == '0') ADVANCE(57);
+      if (lookahead == ':') ADVANCE(29);
+      if (lookahead == ';') ADVANCE(28);
+      if (lookahead == '<') ADVANCE(43);
+      if (lookahead == '=') ADVANCE(31);
+      if (lookahead == '>') ADVANCE(44);
+      if (lookahead == '\\') ADVANCE(12);
+      if (lookahead == '^') ADVANCE(48);
+      if (lookahead == 'r') ADVANCE(78);
+      if (lookahead == '{') ADVANCE(36);
+      if (lookahead == '|') ADVANCE(47);
+      if (lookahead == '}') ADVANCE(37);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(22)
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      if (sym_identifier_character_set_1(lookahead)) ADVANCE(79);
+      END_STATE();
+    case 1:
+      if (lookahead == '!') ADVANCE(25);
+      END_STATE();
+    case 2:
+      if (lookahead == '"') ADVANCE(73);
+      END_STATE();
+    case 3:
+      if (lookahead == '"') ADVANCE(75);
+      END_STATE();
+    case 4:
+      if (lookahead == '"') ADVANCE(71);
+      if (lookahead == '-') ADVANCE(10);
+      if (lookahead == '\\') ADVANCE(12);
+      if (lookahead == '{') ADVANCE(36);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(5)
+      END_STATE();
+    case 5:
+      if (lookahead == '"') ADVANCE(71);
+      if (lookahead == '-') ADVANCE(10);
+      if (lookahead == '{') ADVANCE(36);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(5)
+      END_STATE();
+    case 6:
+      if (lookahead == '\'') ADVANCE(70);
+      END_STATE();
+    case 7:
+      if (lookahead == '\'') ADVANCE(70);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(7);
+      END_STATE();
+    case 8:
+      if (lookahead == '=') ADVANCE(42);
+      END_STATE();
+    case 9:
+      if (lookahead == '=') ADVANCE(41);
+      END_STATE();
+    case 10:
+      if (lookahead == '>') ADVANCE(32);
+      END_STATE();
+    case 11:
+      if (lookahead == '\\') ADVANCE(13);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(6);
+      END_STATE();
+    case 12:
+      if (lookahead == 'u') ADVANCE(14);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(76);
+      END_STATE();
+    case 13:
+      if (lookahead == 'u') ADVANCE(15);
+      if (lookahead != 0) ADVANCE(6);
+      END_STATE();
+    case 14:
+      if (lookahead == '{') ADVANCE(17);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(77);
+      END_STATE();
+    case 15:
+      if (lookahead == '{') ADVANCE(16);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(7);
+      END_STATE();
+    case 16:
+      if (lookahead == '}') ADVANCE(6);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(16);
+      END_STATE();
+    case 17:
+      if (lookahead == '}') ADVANCE(76);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(17);
+      END_STATE();
+    case 18:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(66);
+      END_STATE();
+    case 19:
+      if (eof) ADVANCE(24);
+      if (lookahead == '\n') ADVANCE(26);
+      if (lookahead == '!') ADVANCE(8);
+      if (lookahead == '"') ADVANCE(72);
+      if (lookahead == '%') ADVANCE(55);
+      if (lookahead == '&') ADVANCE(49);
+      if (lookahead == '\'') ADVANCE(11);
+      if (lookahead == '(') ADVANCE(33);
+      if (lookahead == '*') ADVANCE(53);
+      if (lookahead == '+') ADVANCE(52);
+      if (lookahead == '-') ADVANCE(39);
+      if (lookahead == '/') ADVANCE(54);
+      if (lookahead == '0') ADVANCE(57);
+      if (lookahead == ';') ADVANCE(28);
+      if (lookahead == '<') ADVANCE(43);
+      if (lookahead == '=') ADVANCE(9);
+      if (lookahead == '>') ADVANCE(44);
+      if (lookahead == '^') ADVANCE(48);
+      if (lookahead == 'r') ADVANCE(78);
+      if (lookahead == '{') ADVANCE(36);
+      if (lookahead == '|') ADVANCE(47);
+      if (lookahead == '}') ADVANCE(37);
+      if (lookahead == '\t' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(19)
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      if (sym_identifier_character_set_1(lookahead)) ADVANCE(79);
+      END_STATE();
+    case 20:
+      if (eof) ADVANCE(24);
+      if (lookahead == '\n') ADVANCE(27);
+      if (lookahead == '!') ADVANCE(8);
+      if (lookahead == '%') ADVANCE(55);
+      if (lookahead == '&') ADVANCE(49);
+      if (lookahead == '(') ADVANCE(33);
+      if (lookahead == '*') ADVANCE(53);
+      if (lookahead == '+') ADVANCE(52);
+      if (lookahead == '-') ADVANCE(39);
+      if (lookahead == '.') ADVANCE(38);
+      if (lookahead == '/') ADVANCE(54);
+      if (lookahead == ';') ADVANCE(28);
+      if (lookahead == '<') ADVANCE(43);
+      if (lookahead == '=') ADVANCE(9);
+      if (lookahead == '>') ADVANCE(44);
+      if (lookahead == '^') ADVANCE(48);
+      if (lookahead == '|') ADVANCE(47);
+      if (lookahead == '}') ADVANCE(37);
+      if (lookahead == '\t' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(20)
+      if (sym_identifier_character_set_1(lookahead)) ADVANCE(79);
+      END_STATE();
+    case 21:
+      if (eof) ADVANCE(24);
+      if (lookahead == '\n') ADVANCE(27);
+      if (lookahead == '.') ADVANCE(38);
+      if (lookahead == ':') ADVANCE(29);
+      if (lookahead == ';') ADVANCE(28);
+      if (lookahead == '=') ADVANCE(30);
+      if (lookahead == '}') ADVANCE(37);
+      if (lookahead == '\t' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(21)
+      END_STATE();
+    case 22:
+      if (eof) ADVANCE(24);
+      if (lookahead == '!') ADVANCE(8);
+      if (lookahead == '"') ADVANCE(72);
+      if (lookahead == '#') ADVANCE(1);
+      if (lookahead == '%') ADVANCE(55);
+      if (lookahead == '&') ADVANCE(49);
+      if (lookahead == '\'') ADVANCE(11);
+      if (lookahead == '(') ADVANCE(33);
+      if (lookahead == ')') ADVANCE(35);
+      if (lookahead == '*') ADVANCE(53);
+      if (lookahead == '+') ADVANCE(52);
+      if (lookahead == ',') ADVANCE(34);
+      if (lookahead == '-') ADVANCE(40);
+      if (lookahead == '.') ADVANCE(38);
+      if (lookahead == '/') ADVANCE(54);
+      if (lookahead == '0') ADVANCE(57);
+      if (lookahead == ':') ADVANCE(29);
+      if (lookahead == ';') ADVANCE(28);
+      if (lookahead == '<') ADVANCE(43);
+      if (lookahead == '=') ADVANCE(31);
+      if (lookahead == '>') ADVANCE(44);
+      if (lookahead == '^') ADVANCE(48);
+      if (lookahead == 'r') ADVANCE(78);
+      if (lookahead == '{') ADVANCE(36);
+      if (lookahead == '|') ADVANCE(47);
+      if (lookahead == '}') ADVANCE(37);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(22)
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      if (sym_identifier_character_set_1(lookahead)) ADVANCE(79);
+      END_STATE();
+    case 23:
+      if (eof) ADVANCE(24);
+      if (lookahead == '!') ADVANCE(8);
+      if (lookahead == '"') ADVANCE(72);
+      if (lookahead == '#') ADVANCE(1);
+      if (lookahead == '%') ADVANCE(55);
+      if (lookahead == '&') ADVANCE(49);
+      if (lookahead == '\'') ADVANCE(11);
+      if (lookahead == '(') ADVANCE(33);
+      if (lookahead == ')') ADVANCE(35);
+      if (lookahead == '*') ADVANCE(53);
+      if (lookahead == '+') ADVANCE(52);
+      if (lookahead == ',') ADVANCE(34);
+      if (lookahead == '-') ADVANCE(39);
+      if (lookahead == '.') ADVANCE(38);
+      if (lookahead == '/') ADVANCE(54);
+      if (lookahead == '0') ADVANCE(57);
+      if (lookahead == '<') ADVANCE(43);
+      if (lookahead == '=') ADVANCE(9);
+      if (lookahead == '>') ADVANCE(44);
+      if (lookahead == '^') ADVANCE(48);
+      if (lookahead == 'r') ADVANCE(78);
+      if (lookahead == '{') ADVANCE(36);
+      if (lookahead == '|') ADVANCE(47);
+      if (lookahead == '}') ADVANCE(37);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(23)
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      if (sym_identifier_character_set_1(lookahead)) ADVANCE(79);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(sym_shebang);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(25);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(anon_sym_LF);
+      if (lookahead == '\n') ADVANCE(26);
+      if (lookahead == '0') ADVANCE(57);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(anon_sym_LF);
+      if (lookahead == '\n') ADVANCE(27);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(41);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(anon_sym_DASH_GT);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '>') ADVANCE(32);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '<') ADVANCE(50);
+      if (lookahead == '=') ADVANCE(45);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(46);
+      if (lookahead == '>') ADVANCE(51);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(anon_sym_PIPE);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(anon_sym_CARET);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(anon_sym_AMP);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(anon_sym_LT_LT);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(anon_sym_GT_GT);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      if (lookahead == '*') ADVANCE(56);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(anon_sym_PERCENT);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(anon_sym_STAR_STAR);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(sym_integer);
+      if (lookahead == '.') ADVANCE(68);
+      if (lookahead == '_') ADVANCE(58);
+      if (lookahead == 'f') ADVANCE(60);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(62);
+      if (lookahead == 'b' ||
+          lookahead == 'o' ||
+          lookahead == 'x') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(63);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(sym_integer);
+      if (lookahead == '.') ADVANCE(68);
+      if (lookahead == '_') ADVANCE(58);
+      if (lookahead == 'f') ADVANCE(60);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(62);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(63);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(sym_integer);
+      if (lookahead == '2') ADVANCE(64);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(63);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(sym_integer);
+      if (lookahead == '3') ADVANCE(59);
+      if (lookahead == '6') ADVANCE(61);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(63);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(sym_integer);
+      if (lookahead == '4') ADVANCE(64);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(63);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(sym_integer);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(18);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(66);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(63);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(sym_integer);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(63);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_float);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(sym_float);
+      if (lookahead == '_') ADVANCE(65);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(67);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(65);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(69);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_float);
+      if (lookahead == '_') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(66);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(69);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_float);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(18);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(66);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(69);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(sym_float);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(65);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(sym_float);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(69);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(sym_char);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      if (lookahead == '"') ADVANCE(2);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(anon_sym_DQUOTE_DQUOTE_DQUOTE);
+      END_STATE();
+    case 74:
+      ACCEPT_TOKEN(anon_sym_r_DQUOTE);
+      if (lookahead == '"') ADVANCE(3);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(anon_sym_r_DQUOTE_DQUOTE_DQUOTE);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(77);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '"') ADVANCE(74);
+      if (sym_identifier_character_set_2(lookahead)) ADVANCE(79);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(sym_identifier);
+      if (sym_identifier_character_set_2(lookahead)) ADVANCE(79);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (lookahead == 'a') ADVANCE(1);
+      if (lookahead == 'f') ADVANCE(2);
+      if (lookahead == 'l') ADVANCE(3);
+      if (lookahead == 'n') ADVANCE(4);
+      if (lookahead == 'o') ADVANCE(5);
+      if (lookahead == 'r') ADVANCE(6);
+      if (lookahead == 't') ADVANCE(7);
+      if (lookahead == 'u') ADVANCE(8);
+      if (lookahead == 'v') ADVANCE(9);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(0)
+      END_STATE();
+    case 1:
+      if (lookahead == 'n') ADVANCE(10);
+      END_STATE();
+    case 2:
+      if (lookahead == 'a') ADVANCE(11);
+      if (lookahead == 'n') ADVANCE(12);
+      END_STATE();
+    case 3:
+      if (lookahead == 'e') ADVANCE(13);
+      END_STATE();
+    case 4:
+      if (lookahead == 'e') ADVANCE(14);
+      if (lookahead == 'o') ADVANCE(15);
+      END_STATE();
+    case 5:
+      if (lookahead == 'r') ADVANCE(16);
+      END_STATE();
+    case 6:
+      if (lookahead == 'e') ADVANCE(17);
+      END_STATE();
+    case 7:
+      if (lookahead == 'r') ADVANCE(18);
+      END_STATE();
+    case 8:
+      if (lookahead == 'n') ADVANCE(19);
+      END_STATE();
+    case 9:
+      if (lookahead == 'a') ADVANCE(20);
+      END_STATE();
+    case 10:
+      if (lookahead == 'd') ADVANCE(21);
+      END_STATE();
+    case 11:
+      if (lookahead == 'l') ADVANCE(22);
+      END_STATE();
+    case 12:
+      ACCEPT_TOKEN(anon_sym_fn);
+      END_STATE();
+    case 13:
+      if (lookahead == 't') ADVANCE(23);
+      END_STATE();
+    case 14:
+      if (lookahead == 'v') ADVANCE(24);
+      END_STATE();
+    case 15:
+      if (lookahead == 't') ADVANCE(25);
+      END_STATE();
+    case 16:
+      ACCEPT_TOKEN(anon_sym_or);
+      END_STATE();
+    case 17:
+      if (lookahead == 't') ADVANCE(26);
+      END_STATE();
+    case 18:
+      if (lookahead == 'u') ADVANCE(27);
+      END_STATE();
+    case 19:
+      if (lookahead == 'i') ADVANCE(28);
+      END_STATE();
+    case 20:
+      if (lookahead == 'r') ADVANCE(29);
+      END_STATE();
+    case 21:
+      ACCEPT_TOKEN(anon_sym_and);
+      END_STATE();
+    case 22:
+      if (lookahead == 's') ADVANCE(30);
+      END_STATE();
+    case 23:
+      ACCEPT_TOKEN(anon_sym_let);
+      END_STATE();
+    case 24:
+      if (lookahead == 'e') ADVANCE(31);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(anon_sym_not);
+      END_STATE();
+    case 26:
+      if (lookahead == 'u') ADVANCE(32);
+      END_STATE();
+    case 27:
+      if (lookahead == 'e') ADVANCE(33);
+      END_STATE();
+    case 28:
+      if (lookahead == 't') ADVANCE(34);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(anon_sym_var);
+      END_STATE();
+    case 30:
+      if (lookahead == 'e') ADVANCE(35);
+      END_STATE();
+    case 31:
+      if (lookahead == 'r') ADVANCE(36);
+      END_STATE();
+    case 32:
+      if (lookahead == 'r') ADVANCE(37);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(anon_sym_true);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(anon_sym_unit);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(anon_sym_false);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(sym_never_type);
+      END_STATE();
+    case 37:
+      if (lookahead == 'n') ADVANCE(38);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(anon_sym_return);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0, .external_lex_state = 1},
+  [1] = {.lex_state = 23, .external_lex_state = 2},
+  [2] = {.lex_state = 19, .external_lex_state = 2},
+  [3] = {.lex_state = 23, .external_lex_state = 2},
+  [4] = {.lex_state = 19, .external_lex_state = 2},
+  [5] = {.lex_state = 19, .external_lex_state = 2},
+  [6] = {.lex_state = 19, .external_lex_state = 2},
+  [7] = {.lex_state = 23, .external_lex_state = 2},
+  [8] = {.lex_state = 23, .external_lex_state = 2},
+  [9] = {.lex_state = 23, .external_lex_state = 2},
+  [10] = {.lex_state = 23, .external_lex_state = 2},
+  [11] = {.lex_state = 23, .external_lex_state = 2},
+  [12] = {.lex_state = 23, .external_lex_state = 2},
+  [13] = {.lex_state = 23, .external_lex_state = 2},
+  [14] = {.lex_state = 23, .external_lex_state = 2},
+  [15] = {.lex_state = 23, .external_lex_state = 2},
+  [16] = {.lex_state = 23, .external_lex_state = 2},
+  [17] = {.lex_state = 23, .external_lex_state = 2},
+  [18] = {.lex_state = 23, .external_lex_state = 2},
+  [19] = {.lex_state = 23, .external_lex_state = 2},
+  [20] = {.lex_state = 23, .external_lex_state = 2},
+  [21] = {.lex_state = 23, .external_lex_state = 2},
+  [22] = {.lex_state = 23, .external_lex_state = 2},
+  [23] = {.lex_state = 23, .external_lex_state = 2},
+  [24] = {.lex_state = 23, .external_lex_state = 2},
+  [25] = {.lex_state = 23, .external_lex_state = 2},
+  [26] = {.lex_state = 23, .external_lex_state = 2},
+  [27] = {.lex_state = 23, .external_lex_state = 2},
+  [28] = {.lex_state = 23, .external_lex_state = 2},
+  [29] = {.lex_state = 23, .external_lex_state = 2},
+  [30] = {.lex_state = 23, .external_lex_state = 2},
+  [31] = {.lex_state = 23, .external_lex_state = 2},
+  [32] = {.lex_state = 23, .external_lex_state = 2},
+  [33] = {.lex_state = 23, .external_lex_state = 2},
+  [34] = {.lex_state = 23, .external_lex_state = 2},
+  [35] = {.lex_state = 23, .external_lex_state = 2},
+  [36] = {.lex_state = 23, .external_lex_state = 2},
+  [37] = {.lex_state = 23, .external_lex_state = 2},
+  [38] = {.lex_state = 23, .external_lex_state = 2},
+  [39] = {.lex_state = 23, .external_lex_state = 2},
+  [40] = {.lex_state = 23, .external_lex_state = 2},
+  [41] = {.lex_state = 23, .external_lex_state = 2},
+  [42] = {.lex_state = 23, .external_lex_state = 2},
+  [43] = {.lex_state = 23, .external_lex_state = 2},
+  [44] = {.lex_state = 23, .external_lex_state = 2},
+  [45] = {.lex_state = 23, .external_lex_state = 2},
+  [46] = {.lex_state = 23, .external_lex_state = 2},
+  [47] = {.lex_state = 20, .external_lex_state = 2},
+  [48] = {.lex_state = 20, .external_lex_state = 2},
+  [49] = {.lex_state = 20, .external_lex_state = 2},
+  [50] = {.lex_state = 23, .external_lex_state = 2},
+  [51] = {.lex_state = 23, .external_lex_state = 2},
+  [52] = {.lex_state = 23, .external_lex_state = 2},
+  [53] = {.lex_state = 20, .external_lex_state = 2},
+  [54] = {.lex_state = 20, .external_lex_state = 2},
+  [55] = {.lex_state = 20, .external_lex_state = 2},
+  [56] = {.lex_state = 20, .external_lex_state = 2},
+  [57] = {.lex_state = 20, .external_lex_state = 2},
+  [58] = {.lex_state = 20, .external_lex_state = 2},
+  [59] = {.lex_state = 20, .external_lex_state = 2},
+  [60] = {.lex_state = 23, .external_lex_state = 2},
+  [61] = {.lex_state = 23, .external_lex_state = 2},
+  [62] = {.lex_state = 20, .external_lex_state = 2},
+  [63] = {.lex_state = 20, .external_lex_state = 2},
+  [64] = {.lex_state = 20, .external_lex_state = 2},
+  [65] = {.lex_state = 20, .external_lex_state = 2},
+  [66] = {.lex_state = 20, .external_lex_state = 2},
+  [67] = {.lex_state = 20, .external_lex_state = 2},
+  [68] = {.lex_state = 20, .external_lex_state = 2},
+  [69] = {.lex_state = 20, .external_lex_state = 2},
+  [70] = {.lex_state = 20, .external_lex_state = 2},
+  [71] = {.lex_state = 20, .external_lex_state = 2},
+  [72] = {.lex_state = 20, .external_lex_state = 2},
+  [73] = {.lex_state = 19, .external_lex_state = 2},
+  [74] = {.lex_state = 20, .external_lex_state = 2},
+  [75] = {.lex_state = 20, .external_lex_state = 2},
+  [76] = {.lex_state = 20, .external_lex_state = 2},
+  [77] = {.lex_state = 20, .external_lex_state = 2},
+  [78] = {.lex_state = 23, .external_lex_state = 2},
+  [79] = {.lex_state = 20, .external_lex_state = 2},
+  [80] = {.lex_state = 20, .external_lex_state = 2},
+  [81] = {.lex_state = 23, .external_lex_state = 2},
+  [82] = {.lex_state = 23, .external_lex_state = 2},
+  [83] = {.lex_state = 23, .external_lex_state = 2},
+  [84] = {.lex_state = 23, .external_lex_state = 2},
+  [85] = {.lex_state = 20, .external_lex_state = 2},
+  [86] = {.lex_state = 20, .external_lex_state = 2},
+  [87] = {.lex_state = 20, .external_lex_state = 2},
+  [88] = {.lex_state = 20, .external_lex_state = 2},
+  [89] = {.lex_state = 20, .external_lex_state = 2},
+  [90] = {.lex_state = 20, .external_lex_state = 2},
+  [91] = {.lex_state = 20, .external_lex_state = 2},
+  [92] = {.lex_state = 23, .external_lex_state = 2},
+  [93] = {.lex_state = 23, .external_lex_state = 2},
+  [94] = {.lex_state = 23, .external_lex_state = 2},
+  [95] = {.lex_state = 23, .external_lex_state = 2},
+  [96] = {.lex_state = 23, .external_lex_state = 2},
+  [97] = {.lex_state = 23, .external_lex_state = 2},
+  [98] = {.lex_state = 23, .external_lex_state = 2},
+  [99] = {.lex_state = 23, .external_lex_state = 2},
+  [100] = {.lex_state = 23, .external_lex_state = 2},
+  [101] = {.lex_state = 23, .external_lex_state = 2},
+  [102] = {.lex_state = 23, .external_lex_state = 2},
+  [103] = {.lex_state = 23, .external_lex_state = 2},
+  [104] = {.lex_state = 23, .external_lex_state = 2},
+  [105] = {.lex_state = 23, .external_lex_state = 2},
+  [106] = {.lex_state = 23, .external_lex_state = 2},
+  [107] = {.lex_state = 23, .external_lex_state = 2},
+  [108] = {.lex_state = 23, .external_lex_state = 2},
+  [109] = {.lex_state = 23, .external_lex_state = 2},
+  [110] = {.lex_state = 23, .external_lex_state = 2},
+  [111] = {.lex_state = 23, .external_lex_state = 2},
+  [112] = {.lex_state = 23, .external_lex_state = 2},
+  [113] = {.lex_state = 23, .external_lex_state = 2},
+  [114] = {.lex_state = 23, .external_lex_state = 2},
+  [115] = {.lex_state = 23, .external_lex_state = 2},
+  [116] = {.lex_state = 23, .external_lex_state = 2},
+  [117] = {.lex_state = 23, .external_lex_state = 2},
+  [118] = {.lex_state = 23, .external_lex_state = 2},
+  [119] = {.lex_state = 23, .external_lex_state = 2},
+  [120] = {.lex_state = 0, .external_lex_state = 2},
+  [121] = {.lex_state = 0, .external_lex_state = 2},
+  [122] = {.lex_state = 0, .external_lex_state = 2},
+  [123] = {.lex_state = 21, .external_lex_state = 2},
+  [124] = {.lex_state = 4, .external_lex_state = 3},
+  [125] = {.lex_state = 0, .external_lex_state = 4},
+  [126] = {.lex_state = 20, .external_lex_state = 2},
+  [127] = {.lex_state = 4, .external_lex_state = 3},
+  [128] = {.lex_state = 0, .external_lex_state = 4},
+  [129] = {.lex_state = 21, .external_lex_state = 2},
+  [130] = {.lex_state = 20, .external_lex_state = 2},
+  [131] = {.lex_state = 4, .external_lex_state = 3},
+  [132] = {.lex_state = 0, .external_lex_state = 4},
+  [133] = {.lex_state = 4, .external_lex_state = 3},
+  [134] = {.lex_state = 21, .external_lex_state = 2},
+  [135] = {.lex_state = 20, .external_lex_state = 2},
+  [136] = {.lex_state = 4, .external_lex_state = 3},
+  [137] = {.lex_state = 0, .external_lex_state = 4},
+  [138] = {.lex_state = 0, .external_lex_state = 4},
+  [139] = {.lex_state = 21, .external_lex_state = 2},
+  [140] = {.lex_state = 21, .external_lex_state = 2},
+  [141] = {.lex_state = 21, .external_lex_state = 2},
+  [142] = {.lex_state = 20, .external_lex_state = 2},
+  [143] = {.lex_state = 4, .external_lex_state = 3},
+  [144] = {.lex_state = 0, .external_lex_state = 2},
+  [145] = {.lex_state = 20, .external_lex_state = 2},
+  [146] = {.lex_state = 0, .external_lex_state = 2},
+  [147] = {.lex_state = 0, .external_lex_state = 4},
+  [148] = {.lex_state = 20, .external_lex_state = 2},
+  [149] = {.lex_state = 0, .external_lex_state = 2},
+  [150] = {.lex_state = 0, .external_lex_state = 2},
+  [151] = {.lex_state = 0, .external_lex_state = 2},
+  [152] = {.lex_state = 0, .external_lex_state = 2},
+  [153] = {.lex_state = 4, .external_lex_state = 2},
+  [154] = {.lex_state = 0, .external_lex_state = 2},
+  [155] = {.lex_state = 0, .external_lex_state = 2},
+  [156] = {.lex_state = 0, .external_lex_state = 2},
+  [157] = {.lex_state = 0, .external_lex_state = 2},
+  [158] = {.lex_state = 0, .external_lex_state = 2},
+  [159] = {.lex_state = 0, .external_lex_state = 2},
+  [160] = {.lex_state = 0, .external_lex_state = 2},
+  [161] = {.lex_state = 0, .external_lex_state = 5},
+  [162] = {.lex_state = 0, .external_lex_state = 2},
+  [163] = {.lex_state = 0, .external_lex_state = 2},
+  [164] = {.lex_state = 4, .external_lex_state = 2},
+  [165] = {.lex_state = 0, .external_lex_state = 5},
+  [166] = {.lex_state = 4, .external_lex_state = 2},
+  [167] = {.lex_state = 0, .external_lex_state = 2},
+  [168] = {.lex_state = 4, .external_lex_state = 2},
+  [169] = {.lex_state = 4, .external_lex_state = 6},
+  [170] = {.lex_state = 0, .external_lex_state = 2},
+  [171] = {.lex_state = 4, .external_lex_state = 6},
+  [172] = {.lex_state = 0, .external_lex_state = 2},
+  [173] = {.lex_state = 0, .external_lex_state = 2},
+  [174] = {.lex_state = 0, .external_lex_state = 2},
+  [175] = {.lex_state = 4, .external_lex_state = 2},
+  [176] = {.lex_state = 0, .external_lex_state = 2},
+  [177] = {.lex_state = 0, .external_lex_state = 2},
+  [178] = {.lex_state = 0, .external_lex_state = 2},
+  [179] = {.lex_state = 0, .external_lex_state = 2},
+  [180] = {.lex_state = 0, .external_lex_state = 2},
+  [181] = {.lex_state = 0, .external_lex_state = 2},
+  [182] = {.lex_state = 4, .external_lex_state = 2},
+  [183] = {.lex_state = 0, .external_lex_state = 2},
+  [184] = {.lex_state = 0, .external_lex_state = 2},
+  [185] = {.lex_state = 0, .external_lex_state = 2},
+  [186] = {.lex_state = 0, .external_lex_state = 2},
+  [187] = {.lex_state = 0, .external_lex_state = 2},
+  [188] = {.lex_state = 0, .external_lex_state = 2},
+  [189] = {.lex_state = 0, .external_lex_state = 2},
+  [190] = {.lex_state = 0, .external_lex_state = 2},
+  [191] = {.lex_state = 0, .external_lex_state = 2},
+  [192] = {.lex_state = 0, .external_lex_state = 2},
+  [193] = {.lex_state = 0, .external_lex_state = 2},
+  [194] = {.lex_state = 4, .external_lex_state = 2},
+};
+
+enum {
+  ts_external_token__string_content = 0,
+  ts_external_token__multiline_string_content = 1,
+  ts_external_token__raw_string_content = 2,
+  ts_external_token__raw_multiline_string_content = 3,
+  ts_external_token_line_comment = 4,
+  ts_external_token_block_comment = 5,
+  ts_external_token_doc_comment = 6,
+  ts_external_token__error_sentinel = 7,
+};
+
+static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
+  [ts_external_token__string_content] = sym__string_content,
+  [ts_external_token__multiline_string_content] = sym__multiline_string_content,
+  [ts_external_token__raw_string_content] = sym__raw_string_content,
+  [ts_external_token__raw_multiline_string_content] = sym__raw_multiline_string_content,
+  [ts_external_token_line_comment] = sym_line_comment,
+  [ts_external_token_block_comment] = sym_block_comment,
+  [ts_external_token_doc_comment] = sym_doc_comment,
+  [ts_external_token__error_sentinel] = sym__error_sentinel,
+};
+
+static const bool ts_external_scanner_states[7][EXTERNAL_TOKEN_COUNT] = {
+  [1] = {
+    [ts_external_token__string_content] = true,
+    [ts_external_token__multiline_string_content] = true,
+    [ts_external_token__raw_string_content] = true,
+    [ts_external_token__raw_multiline_string_content] = true,
+    [ts_external_token_line_comment] = true,
+    [ts_external_token_block_comment] = true,
+    [ts_external_token_doc_comment] = true,
+    [ts_external_token__error_sentinel] = true,
+  },
+  [2] = {
+    [ts_external_token_line_comment] = true,
+    [ts_external_token_block_comment] = true,
+    [ts_external_token_doc_comment] = true,
+  },
+  [3] = {
+    [ts_external_token__string_content] = true,
+    [ts_external_token_line_comment] = true,
+    [ts_external_token_block_comment] = true,
+    [ts_external_token_doc_comment] = true,
+  },
+  [4] = {
+    [ts_external_token__multiline_string_content] = true,
+    [ts_external_token_line_comment] = true,
+    [ts_external_token_block_comment] = true,
+    [ts_external_token_doc_comment] = true,
+  },
+  [5] = {
+    [ts_external_token__raw_multiline_string_content] = true,
+    [ts_external_token_line_comment] = true,
+    [ts_external_token_block_comment] = true,
+    [ts_external_token_doc_comment] = true,
+  },
+  [6] = {
+    [ts_external_token__raw_string_content] = true,
+    [ts_external_token_line_comment] = true,
+    [ts_external_token_block_comment] = true,
+    [ts_external_token_doc_comment] = true,
+  },
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym_identifier] = ACTIONS(1),
+    [sym_shebang] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_let] = ACTIONS(1),
+    [anon_sym_var] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_fn] = ACTIONS(1),
+    [anon_sym_DASH_GT] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_unit] = ACTIONS(1),
+    [sym_never_type] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_not] = ACTIONS(1),
+    [anon_sym_or] = ACTIONS(1),
+    [anon_sym_and] = ACTIONS(1),
+    [anon_sym_EQ_EQ] = ACTIONS(1),
+    [anon_sym_BANG_EQ] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [anon_sym_PIPE] = ACTIONS(1),
+    [anon_sym_CARET] = ACTIONS(1),
+    [anon_sym_AMP] = ACTIONS(1),
+    [anon_sym_LT_LT] = ACTIONS(1),
+    [anon_sym_GT_GT] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_SLASH] = ACTIONS(1),
+    [anon_sym_PERCENT] = ACTIONS(1),
+    [anon_sym_STAR_STAR] = ACTIONS(1),
+    [anon_sym_return] = ACTIONS(1),
+    [sym_integer] = ACTIONS(1),
+    [sym_float] = ACTIONS(1),
+    [anon_sym_true] = ACTIONS(1),
+    [anon_sym_false] = ACTIONS(1),
+    [sym_char] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(1),
+    [anon_sym_r_DQUOTE] = ACTIONS(1),
+    [anon_sym_r_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(1),
+    [sym_escape_sequence] = ACTIONS(1),
+    [sym__string_content] = ACTIONS(1),
+    [sym__multiline_string_content] = ACTIONS(1),
+    [sym__raw_string_content] = ACTIONS(1),
+    [sym__raw_multiline_string_content] = ACTIONS(1),
+    [sym_line_comment] = ACTIONS(3),
+    [sym_block_comment] = ACTIONS(3),
+    [sym_doc_comment] = ACTIONS(3),
+    [sym__error_sentinel] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_source_file] = STATE(190),
+    [sym__items] = STATE(192),
+    [sym__item] = STATE(126),
+    [sym_local_declaration] = STATE(126),
+    [sym_function_declaration] = STATE(126),
+    [sym_block] = STATE(62),
+    [sym__expression] = STATE(62),
+    [sym_unary_expression] = STATE(62),
+    [sym_binary_expression] = STATE(62),
+    [sym_call_expression] = STATE(53),
+    [sym_return_expression] = STATE(62),
+    [sym_path] = STATE(53),
+    [sym_parenthesized_expression] = STATE(62),
+    [sym__literal] = STATE(62),
+    [sym_boolean] = STATE(62),
+    [sym_string] = STATE(62),
+    [sym_raw_string] = STATE(62),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [sym_identifier] = ACTIONS(7),
+    [sym_shebang] = ACTIONS(9),
+    [anon_sym_let] = ACTIONS(11),
+    [anon_sym_var] = ACTIONS(11),
+    [anon_sym_fn] = ACTIONS(13),
+    [anon_sym_LPAREN] = ACTIONS(15),
+    [anon_sym_LBRACE] = ACTIONS(17),
+    [anon_sym_DASH] = ACTIONS(19),
+    [anon_sym_not] = ACTIONS(21),
+    [anon_sym_return] = ACTIONS(23),
+    [sym_integer] = ACTIONS(25),
+    [sym_float] = ACTIONS(27),
+    [anon_sym_true] = ACTIONS(29),
+    [anon_sym_false] = ACTIONS(29),
+    [sym_char] = ACTIONS(27),
+    [anon_sym_DQUOTE] = ACTIONS(31),
+    [anon_sym_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(33),
+    [anon_sym_r_DQUOTE] = ACTIONS(35),
+    [anon_sym_r_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(37),
+    [sym_line_comment] = ACTIONS(3),
+    [sym_block_comment] = ACTIONS(3),
+    [sym_doc_comment] = ACTIONS(3),
+  },
+  [2] = {
+    [sym_block] = STATE(64),
+    [sym__expression] = STATE(64),
+    [sym_unary_expression] = STATE(64),
+    [sym_binary_expression] = STATE(64),
+    [sym_call_expression] = STATE(53),
+    [sym_return_expression] = STATE(64),
+    [sym_path] = STATE(53),
+    [sym_parenthesized_expression] = STATE(64),
+    [sym__literal] = STATE(64),
+    [sym_boolean] = STATE(64),
+    [sym_string] = STATE(64),
+    [sym_raw_string] = STATE(64),
+    [ts_builtin_sym_end] = ACTIONS(39),
+    [sym_identifier] = ACTIONS(7),
+    [anon_sym_LF] = ACTIONS(41),
+    [anon_sym_SEMI] = ACTIONS(41),
+    [anon_sym_LPAREN] = ACTIONS(43),
+    [anon_sym_LBRACE] = ACTIONS(45),
+    [anon_sym_RBRACE] = ACTIONS(41),
+    [anon_sym_DASH] = ACTIONS(21),
+    [anon_sym_not] = ACTIONS(21),
+    [anon_sym_or] = ACTIONS(41),
+    [anon_sym_and] = ACTIONS(41),
+    [anon_sym_EQ_EQ] = ACTIONS(41),
+    [anon_sym_BANG_EQ] = ACTIONS(41),
+    [anon_sym_LT] = ACTIONS(41),
+    [anon_sym_GT] = ACTIONS(41),
+    [anon_sym_LT_EQ] = ACTIONS(41),
+    [anon_sym_GT_EQ] = ACTIONS(41),
+    [anon_sym_PIPE] = ACTIONS(41),
+    [anon_sym_CARET] = ACTIONS(41),
+    [anon_sym_AMP] = ACTIONS(41),
+    [anon_sym_LT_LT] = ACTIONS(41),
+    [anon_sym_GT_GT] = ACTIONS(41),
+    [anon_sym_PLUS] = ACTIONS(41),
+    [anon_sym_STAR] = ACTIONS(41),
+    [anon_sym_SLASH] = ACTIONS(41),
+    [anon_sym_PERCENT] = ACTIONS(41),
+    [anon_sym_STAR_STAR] = ACTIONS(41),
+    [anon_sym_return] = ACTIONS(23),
+    [sym_integer] = ACTIONS(47),
+    [sym_float] = ACTIONS(47),
+    [anon_sym_true] = ACTIONS(29),
+    [anon_sym_false] = ACTIONS(29),
+    [sym_char] = ACTIONS(47),
+    [anon_sym_DQUOTE] = ACTIONS(31),
+    [anon_sym_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(49),
+    [anon_sym_r_DQUOTE] = ACTIONS(35),
+    [anon_sym_r_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(51),
+    [sym_line_comment] = ACTIONS(3),
+    [sym_block_comment] = ACTIONS(3),
+    [sym_doc_comment] = ACTIONS(3),
+  },
+  [3] = {
+    [sym_block] = STATE(113),
+    [sym__expression] = STATE(113),
+    [sym_unary_expression] = STATE(113),
+    [sym_binary_expression] = STATE(113),
+    [sym_call_expression] = STATE(60),
+    [sym_return_expression] = STATE(113),
+    [sym_path] = STATE(60),
+    [sym_parenthesized_expression] = STATE(113),
+    [sym__literal] = STATE(113),
+    [sym_boolean] = STATE(113),
+    [sym_string] = STATE(113),
+    [sym_raw_string] = STATE(113),
+    [sym_identifier] = ACTIONS(53),
+    [anon_sym_LPAREN] = ACTIONS(55),
+    [anon_sym_COMMA] = ACTIONS(39),
+    [anon_sym_RPAREN] = ACTIONS(39),
+    [anon_sym_LBRACE] = ACTIONS(57),
+    [anon_sym_RBRACE] = ACTIONS(39),
+    [anon_sym_DASH] = ACTIONS(59),
+    [anon_sym_not] = ACTIONS(61),
+    [anon_sym_or] = ACTIONS(41),
+    [anon_sym_and] = ACTIONS(41),
+    [anon_sym_EQ_EQ] = ACTIONS(39),
+    [anon_sym_BANG_EQ] = ACTIONS(39),
+    [anon_sym_LT] = ACTIONS(41),
+    [anon_sym_GT] = ACTIONS(41),
+    [anon_sym_LT_EQ] = ACTIONS(39),
+    [anon_sym_GT_EQ] = ACTIONS(39),
+    [anon_sym_PIPE] = ACTIONS(39),
+    [anon_sym_CARET] = ACTIONS(39),
+    [anon_sym_AMP] = ACTIONS(39),
+    [anon_sym_LT_LT] = ACTIONS(39),
+    [anon_sym_GT_GT] = ACTIONS(39),
+    [anon_sym_PLUS] = ACTIONS(39),
+    [anon_sym_STAR] = ACTIONS(41),
+    [anon_sym_SLASH] = ACTIONS(39),
+    [anon_sym_PERCENT] = ACTIONS(39),
+    [anon_sym_STAR_STAR] = ACTIONS(39),
+    [anon_sym_return] = ACTIONS(63),
+    [sym_integer] = ACTIONS(65),
+    [sym_float] = ACTIONS(67),
+    [anon_sym_true] = ACTIONS(69),
+    [anon_sym_false] = ACTIONS(69),
+    [sym_char] = ACTIONS(67),
+    [anon_sym_DQUOTE] = ACTIONS(71),
+    [anon_sym_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(73),
+    [anon_sym_r_DQUOTE] = ACTIONS(75),
+    [anon_sym_r_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(77),
+    [sym_line_comment] = ACTIONS(3),
+    [sym_block_comment] = ACTIONS(3),
+    [sym_doc_comment] = ACTIONS(3),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 21,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      anon_sym_fn,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(43), 1,
+      anon_sym_LPAREN,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(49), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(51), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(79), 1,
+      ts_builtin_sym_end,
+    ACTIONS(83), 1,
+      anon_sym_RBRACE,
+    STATE(73), 1,
+      aux_sym__terminator,
+    ACTIONS(11), 2,
+      anon_sym_let,
+      anon_sym_var,
+    ACTIONS(21), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(81), 2,
+      anon_sym_LF,
+      anon_sym_SEMI,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(25), 3,
+      sym_integer,
+      sym_float,
+      sym_char,
+    STATE(145), 3,
+      sym__item,
+      sym_local_declaration,
+      sym_function_declaration,
+    STATE(62), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [84] = 21,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      anon_sym_fn,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(43), 1,
+      anon_sym_LPAREN,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(49), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(51), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(85), 1,
+      ts_builtin_sym_end,
+    ACTIONS(87), 1,
+      anon_sym_RBRACE,
+    STATE(73), 1,
+      aux_sym__terminator,
+    ACTIONS(11), 2,
+      anon_sym_let,
+      anon_sym_var,
+    ACTIONS(21), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(81), 2,
+      anon_sym_LF,
+      anon_sym_SEMI,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(25), 3,
+      sym_integer,
+      sym_float,
+      sym_char,
+    STATE(145), 3,
+      sym__item,
+      sym_local_declaration,
+      sym_function_declaration,
+    STATE(62), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [168] = 19,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      anon_sym_fn,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(43), 1,
+      anon_sym_LPAREN,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(49), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(51), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    STATE(73), 1,
+      aux_sym__terminator,
+    ACTIONS(11), 2,
+      anon_sym_let,
+      anon_sym_var,
+    ACTIONS(21), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(81), 2,
+      anon_sym_LF,
+      anon_sym_SEMI,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(25), 3,
+      sym_integer,
+      sym_float,
+      sym_char,
+    STATE(145), 3,
+      sym__item,
+      sym_local_declaration,
+      sym_function_declaration,
+    STATE(62), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [246] = 21,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      anon_sym_fn,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(25), 1,
+      sym_integer,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(89), 1,
+      ts_builtin_sym_end,
+    STATE(187), 1,
+      sym__items,
+    ACTIONS(11), 2,
+      anon_sym_let,
+      anon_sym_var,
+    ACTIONS(27), 2,
+      sym_float,
+      sym_char,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(126), 3,
+      sym__item,
+      sym_local_declaration,
+      sym_function_declaration,
+    STATE(62), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [327] = 21,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      anon_sym_fn,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(25), 1,
+      sym_integer,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(91), 1,
+      anon_sym_RBRACE,
+    STATE(191), 1,
+      sym__items,
+    ACTIONS(11), 2,
+      anon_sym_let,
+      anon_sym_var,
+    ACTIONS(27), 2,
+      sym_float,
+      sym_char,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(126), 3,
+      sym__item,
+      sym_local_declaration,
+      sym_function_declaration,
+    STATE(62), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [408] = 21,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      anon_sym_fn,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(25), 1,
+      sym_integer,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(93), 1,
+      anon_sym_RBRACE,
+    STATE(179), 1,
+      sym__items,
+    ACTIONS(11), 2,
+      anon_sym_let,
+      anon_sym_var,
+    ACTIONS(27), 2,
+      sym_float,
+      sym_char,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(126), 3,
+      sym__item,
+      sym_local_declaration,
+      sym_function_declaration,
+    STATE(62), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [489] = 17,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(95), 1,
+      anon_sym_RPAREN,
+    ACTIONS(97), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(99), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(115), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [555] = 17,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(97), 1,
+      sym_integer,
+    ACTIONS(101), 1,
+      anon_sym_RPAREN,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(99), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(115), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [621] = 17,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(103), 1,
+      anon_sym_RPAREN,
+    ACTIONS(105), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(107), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(95), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [687] = 17,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(97), 1,
+      sym_integer,
+    ACTIONS(109), 1,
+      anon_sym_RPAREN,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(99), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(115), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [753] = 17,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(111), 1,
+      anon_sym_RPAREN,
+    ACTIONS(113), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(115), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(111), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [819] = 17,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(97), 1,
+      sym_integer,
+    ACTIONS(117), 1,
+      anon_sym_RPAREN,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(99), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(115), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [885] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(119), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(121), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(97), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [948] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(123), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(125), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(87), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1011] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(127), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(129), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(88), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1074] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(131), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(133), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(89), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1137] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(135), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(137), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(90), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1200] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(139), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(141), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(72), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1263] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(143), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(145), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(79), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1326] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(147), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(149), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(116), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1389] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(151), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(153), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(91), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1452] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(155), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(157), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(74), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1515] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(97), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(99), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(115), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1578] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(159), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(161), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(118), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1641] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(163), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(165), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(98), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1704] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(167), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(169), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(99), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1767] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(171), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(173), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(96), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1830] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(175), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(177), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(101), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1893] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(179), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(181), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(100), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [1956] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(183), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(185), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(102), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2019] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(187), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(189), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(76), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2082] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(191), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(193), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(103), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2145] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(195), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(197), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(104), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2208] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(199), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(201), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(105), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2271] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(203), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(205), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(106), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2334] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(207), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(209), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(77), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2397] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(211), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(213), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(109), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2460] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(215), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(217), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(80), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2523] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(219), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(221), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(85), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2586] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(223), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(225), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(86), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2649] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(227), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(229), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(119), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2712] = 16,
+    ACTIONS(53), 1,
+      sym_identifier,
+    ACTIONS(55), 1,
+      anon_sym_LPAREN,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_DASH,
+    ACTIONS(61), 1,
+      anon_sym_not,
+    ACTIONS(63), 1,
+      anon_sym_return,
+    ACTIONS(71), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(75), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(77), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(231), 1,
+      sym_integer,
+    ACTIONS(69), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(233), 2,
+      sym_float,
+      sym_char,
+    STATE(60), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(117), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2775] = 16,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(15), 1,
+      anon_sym_LPAREN,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      anon_sym_DASH,
+    ACTIONS(21), 1,
+      anon_sym_not,
+    ACTIONS(23), 1,
+      anon_sym_return,
+    ACTIONS(31), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_r_DQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(235), 1,
+      sym_integer,
+    ACTIONS(29), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(237), 2,
+      sym_float,
+      sym_char,
+    STATE(53), 2,
+      sym_call_expression,
+      sym_path,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(75), 10,
+      sym_block,
+      sym__expression,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_return_expression,
+      sym_parenthesized_expression,
+      sym__literal,
+      sym_boolean,
+      sym_string,
+      sym_raw_string,
+  [2838] = 5,
+    ACTIONS(243), 1,
+      anon_sym_DOT,
+    STATE(48), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(239), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(241), 22,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [2878] = 5,
+    ACTIONS(249), 1,
+      anon_sym_DOT,
+    STATE(48), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(245), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(247), 22,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [2918] = 5,
+    ACTIONS(243), 1,
+      anon_sym_DOT,
+    STATE(47), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(252), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(254), 22,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [2958] = 5,
+    ACTIONS(256), 1,
+      anon_sym_DOT,
+    STATE(50), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(247), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(245), 20,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [2997] = 5,
+    ACTIONS(259), 1,
+      anon_sym_DOT,
+    STATE(52), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(254), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(252), 20,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3036] = 5,
+    ACTIONS(259), 1,
+      anon_sym_DOT,
+    STATE(50), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(241), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(239), 20,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3075] = 5,
+    ACTIONS(265), 1,
+      anon_sym_LPAREN,
+    STATE(58), 1,
+      sym_arguments,
+    ACTIONS(261), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(263), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3114] = 3,
+    ACTIONS(245), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(247), 23,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3149] = 3,
+    ACTIONS(267), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(269), 22,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3183] = 3,
+    ACTIONS(271), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(273), 22,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3217] = 3,
+    ACTIONS(275), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(277), 22,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3251] = 3,
+    ACTIONS(279), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(281), 22,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3285] = 3,
+    ACTIONS(283), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(285), 22,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3319] = 5,
+    ACTIONS(287), 1,
+      anon_sym_LPAREN,
+    STATE(78), 1,
+      sym_arguments,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(263), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(261), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3357] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(247), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(245), 21,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3391] = 14,
+    ACTIONS(295), 1,
+      anon_sym_or,
+    ACTIONS(297), 1,
+      anon_sym_and,
+    ACTIONS(303), 1,
+      anon_sym_PIPE,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(289), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(291), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(299), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(301), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3446] = 3,
+    ACTIONS(315), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(317), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3479] = 14,
+    ACTIONS(295), 1,
+      anon_sym_or,
+    ACTIONS(297), 1,
+      anon_sym_and,
+    ACTIONS(303), 1,
+      anon_sym_PIPE,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(299), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(319), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(321), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(301), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3534] = 3,
+    ACTIONS(323), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(325), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3567] = 3,
+    ACTIONS(327), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(329), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3600] = 3,
+    ACTIONS(331), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(333), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3633] = 3,
+    ACTIONS(335), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(337), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3666] = 3,
+    ACTIONS(339), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(341), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3699] = 3,
+    ACTIONS(343), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(345), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3732] = 3,
+    ACTIONS(347), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(349), 21,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [3765] = 14,
+    ACTIONS(295), 1,
+      anon_sym_or,
+    ACTIONS(297), 1,
+      anon_sym_and,
+    ACTIONS(303), 1,
+      anon_sym_PIPE,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(299), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(351), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(353), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(301), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3820] = 5,
+    ACTIONS(355), 1,
+      ts_builtin_sym_end,
+    STATE(73), 1,
+      aux_sym__terminator,
+    ACTIONS(359), 2,
+      anon_sym_LF,
+      anon_sym_SEMI,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(357), 19,
+      anon_sym_let,
+      anon_sym_var,
+      anon_sym_fn,
+      anon_sym_LPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_not,
+      anon_sym_return,
+      sym_integer,
+      sym_float,
+      anon_sym_true,
+      anon_sym_false,
+      sym_char,
+      anon_sym_DQUOTE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_r_DQUOTE,
+      anon_sym_r_DQUOTE_DQUOTE_DQUOTE,
+      sym_identifier,
+  [3857] = 5,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(364), 17,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+  [3894] = 13,
+    ACTIONS(297), 1,
+      anon_sym_and,
+    ACTIONS(303), 1,
+      anon_sym_PIPE,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(299), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(364), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_or,
+    ACTIONS(301), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3947] = 12,
+    ACTIONS(303), 1,
+      anon_sym_PIPE,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(299), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(301), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(364), 4,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+  [3998] = 11,
+    ACTIONS(303), 1,
+      anon_sym_PIPE,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(301), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(364), 6,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [4047] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(281), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(279), 20,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [4080] = 4,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(366), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(368), 20,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [4115] = 10,
+    ACTIONS(303), 1,
+      anon_sym_PIPE,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(364), 10,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [4162] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(273), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(271), 20,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [4195] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(277), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(275), 20,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [4228] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(269), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(267), 20,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [4261] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(285), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(283), 20,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [4294] = 9,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(364), 11,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+  [4339] = 8,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(364), 12,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+  [4382] = 7,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(364), 13,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+  [4423] = 6,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(364), 15,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+  [4462] = 4,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(364), 20,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [4497] = 4,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(362), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(364), 20,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [4532] = 14,
+    ACTIONS(295), 1,
+      anon_sym_or,
+    ACTIONS(297), 1,
+      anon_sym_and,
+    ACTIONS(303), 1,
+      anon_sym_PIPE,
+    ACTIONS(305), 1,
+      anon_sym_CARET,
+    ACTIONS(307), 1,
+      anon_sym_AMP,
+    ACTIONS(313), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(293), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(299), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(309), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(370), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(372), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(311), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(301), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [4587] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(345), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(343), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [4619] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(337), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(335), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [4651] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(341), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(339), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [4683] = 17,
+    ACTIONS(374), 1,
+      anon_sym_COMMA,
+    ACTIONS(376), 1,
+      anon_sym_RPAREN,
+    ACTIONS(380), 1,
+      anon_sym_or,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    STATE(160), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [4743] = 13,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 5,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+  [4795] = 9,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(364), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 11,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+  [4839] = 6,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(364), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 16,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+  [4877] = 14,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 4,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_or,
+  [4931] = 11,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(364), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 9,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [4979] = 12,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 7,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [5029] = 10,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(364), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 10,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+  [5075] = 8,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(364), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 12,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+  [5117] = 7,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(364), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(362), 14,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+  [5157] = 4,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(364), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(362), 18,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [5191] = 4,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(364), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(362), 18,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [5225] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(333), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(331), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [5257] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(325), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(323), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [5289] = 4,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(368), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(366), 18,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [5323] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(317), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(315), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [5355] = 17,
+    ACTIONS(380), 1,
+      anon_sym_or,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(404), 1,
+      anon_sym_COMMA,
+    ACTIONS(406), 1,
+      anon_sym_RPAREN,
+    STATE(150), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5415] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(349), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(347), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [5447] = 15,
+    ACTIONS(380), 1,
+      anon_sym_or,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(319), 3,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+  [5503] = 3,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(329), 3,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_STAR,
+    ACTIONS(327), 19,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_DASH,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_STAR_STAR,
+  [5535] = 15,
+    ACTIONS(380), 1,
+      anon_sym_or,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(408), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5590] = 15,
+    ACTIONS(380), 1,
+      anon_sym_or,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(410), 1,
+      anon_sym_RBRACE,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5644] = 15,
+    ACTIONS(380), 1,
+      anon_sym_or,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(412), 1,
+      anon_sym_RBRACE,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5698] = 15,
+    ACTIONS(380), 1,
+      anon_sym_or,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(414), 1,
+      anon_sym_RPAREN,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5752] = 15,
+    ACTIONS(380), 1,
+      anon_sym_or,
+    ACTIONS(382), 1,
+      anon_sym_and,
+    ACTIONS(390), 1,
+      anon_sym_PIPE,
+    ACTIONS(392), 1,
+      anon_sym_CARET,
+    ACTIONS(394), 1,
+      anon_sym_AMP,
+    ACTIONS(398), 1,
+      anon_sym_STAR,
+    ACTIONS(402), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(416), 1,
+      anon_sym_RPAREN,
+    ACTIONS(378), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(384), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(388), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(396), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(400), 2,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5806] = 7,
+    ACTIONS(418), 1,
+      sym_identifier,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      anon_sym_unit,
+    ACTIONS(424), 1,
+      sym_never_type,
+    STATE(174), 1,
+      aux_sym_named_type_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(167), 3,
+      sym__type,
+      sym_unit_type,
+      sym_named_type,
+  [5832] = 7,
+    ACTIONS(418), 1,
+      sym_identifier,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      anon_sym_unit,
+    ACTIONS(426), 1,
+      sym_never_type,
+    STATE(174), 1,
+      aux_sym_named_type_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(172), 3,
+      sym__type,
+      sym_unit_type,
+      sym_named_type,
+  [5858] = 7,
+    ACTIONS(428), 1,
+      sym_identifier,
+    ACTIONS(430), 1,
+      anon_sym_LPAREN,
+    ACTIONS(432), 1,
+      anon_sym_unit,
+    ACTIONS(434), 1,
+      sym_never_type,
+    STATE(176), 1,
+      aux_sym_named_type_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    STATE(139), 3,
+      sym__type,
+      sym_unit_type,
+      sym_named_type,
+  [5884] = 5,
+    ACTIONS(440), 1,
+      anon_sym_COLON,
+    ACTIONS(442), 1,
+      anon_sym_EQ,
+    ACTIONS(436), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(438), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5904] = 5,
+    ACTIONS(444), 1,
+      anon_sym_LBRACE,
+    ACTIONS(446), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(448), 2,
+      sym__string_content,
+      sym_escape_sequence,
+    STATE(131), 2,
+      sym_interpolation,
+      aux_sym_string_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5924] = 5,
+    ACTIONS(446), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(450), 1,
+      anon_sym_LBRACE,
+    ACTIONS(452), 2,
+      sym__multiline_string_content,
+      sym_escape_sequence,
+    STATE(132), 2,
+      sym_interpolation,
+      aux_sym_string_repeat2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5944] = 7,
+    ACTIONS(454), 1,
+      ts_builtin_sym_end,
+    ACTIONS(456), 1,
+      anon_sym_LF,
+    ACTIONS(458), 1,
+      anon_sym_SEMI,
+    ACTIONS(460), 1,
+      anon_sym_RBRACE,
+    STATE(5), 1,
+      aux_sym__terminator,
+    STATE(130), 1,
+      aux_sym__items_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5968] = 5,
+    ACTIONS(444), 1,
+      anon_sym_LBRACE,
+    ACTIONS(462), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(464), 2,
+      sym__string_content,
+      sym_escape_sequence,
+    STATE(124), 2,
+      sym_interpolation,
+      aux_sym_string_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [5988] = 5,
+    ACTIONS(450), 1,
+      anon_sym_LBRACE,
+    ACTIONS(462), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(466), 2,
+      sym__multiline_string_content,
+      sym_escape_sequence,
+    STATE(125), 2,
+      sym_interpolation,
+      aux_sym_string_repeat2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6008] = 4,
+    ACTIONS(472), 1,
+      anon_sym_DOT,
+    ACTIONS(468), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(470), 3,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_RBRACE,
+  [6026] = 7,
+    ACTIONS(85), 1,
+      ts_builtin_sym_end,
+    ACTIONS(87), 1,
+      anon_sym_RBRACE,
+    ACTIONS(474), 1,
+      anon_sym_LF,
+    ACTIONS(476), 1,
+      anon_sym_SEMI,
+    STATE(4), 1,
+      aux_sym__terminator,
+    STATE(135), 1,
+      aux_sym__items_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6050] = 5,
+    ACTIONS(478), 1,
+      anon_sym_LBRACE,
+    ACTIONS(481), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(483), 2,
+      sym__string_content,
+      sym_escape_sequence,
+    STATE(131), 2,
+      sym_interpolation,
+      aux_sym_string_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6070] = 5,
+    ACTIONS(486), 1,
+      anon_sym_LBRACE,
+    ACTIONS(489), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(491), 2,
+      sym__multiline_string_content,
+      sym_escape_sequence,
+    STATE(132), 2,
+      sym_interpolation,
+      aux_sym_string_repeat2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6090] = 5,
+    ACTIONS(444), 1,
+      anon_sym_LBRACE,
+    ACTIONS(494), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(448), 2,
+      sym__string_content,
+      sym_escape_sequence,
+    STATE(131), 2,
+      sym_interpolation,
+      aux_sym_string_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6110] = 4,
+    ACTIONS(472), 1,
+      anon_sym_DOT,
+    ACTIONS(496), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(498), 3,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_RBRACE,
+  [6128] = 7,
+    ACTIONS(500), 1,
+      ts_builtin_sym_end,
+    ACTIONS(502), 1,
+      anon_sym_LF,
+    ACTIONS(505), 1,
+      anon_sym_SEMI,
+    ACTIONS(508), 1,
+      anon_sym_RBRACE,
+    STATE(6), 1,
+      aux_sym__terminator,
+    STATE(135), 1,
+      aux_sym__items_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6152] = 5,
+    ACTIONS(444), 1,
+      anon_sym_LBRACE,
+    ACTIONS(510), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(512), 2,
+      sym__string_content,
+      sym_escape_sequence,
+    STATE(133), 2,
+      sym_interpolation,
+      aux_sym_string_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6172] = 5,
+    ACTIONS(450), 1,
+      anon_sym_LBRACE,
+    ACTIONS(494), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(452), 2,
+      sym__multiline_string_content,
+      sym_escape_sequence,
+    STATE(132), 2,
+      sym_interpolation,
+      aux_sym_string_repeat2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6192] = 5,
+    ACTIONS(450), 1,
+      anon_sym_LBRACE,
+    ACTIONS(510), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(514), 2,
+      sym__multiline_string_content,
+      sym_escape_sequence,
+    STATE(137), 2,
+      sym_interpolation,
+      aux_sym_string_repeat2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6212] = 4,
+    ACTIONS(520), 1,
+      anon_sym_EQ,
+    ACTIONS(516), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(518), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6229] = 3,
+    ACTIONS(522), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(524), 3,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_RBRACE,
+  [6244] = 3,
+    ACTIONS(526), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(528), 3,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_RBRACE,
+  [6259] = 3,
+    ACTIONS(530), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(532), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6273] = 2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(534), 4,
+      sym__string_content,
+      anon_sym_LBRACE,
+      anon_sym_DQUOTE,
+      sym_escape_sequence,
+  [6285] = 3,
+    ACTIONS(536), 1,
+      anon_sym_DOT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(468), 3,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+  [6299] = 3,
+    ACTIONS(500), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(508), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6313] = 3,
+    ACTIONS(536), 1,
+      anon_sym_DOT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(496), 3,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+  [6327] = 2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(534), 4,
+      sym__multiline_string_content,
+      anon_sym_LBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_escape_sequence,
+  [6339] = 3,
+    ACTIONS(538), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+    ACTIONS(540), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6353] = 4,
+    ACTIONS(542), 1,
+      sym_identifier,
+    ACTIONS(544), 1,
+      anon_sym_RPAREN,
+    STATE(157), 1,
+      sym_parameter,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6368] = 4,
+    ACTIONS(117), 1,
+      anon_sym_RPAREN,
+    ACTIONS(546), 1,
+      anon_sym_COMMA,
+    STATE(155), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6383] = 4,
+    ACTIONS(542), 1,
+      sym_identifier,
+    ACTIONS(548), 1,
+      anon_sym_RPAREN,
+    STATE(170), 1,
+      sym_parameter,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6398] = 4,
+    ACTIONS(548), 1,
+      anon_sym_RPAREN,
+    ACTIONS(550), 1,
+      anon_sym_COMMA,
+    STATE(159), 1,
+      aux_sym_parameters_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6413] = 4,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(552), 1,
+      anon_sym_DASH_GT,
+    STATE(142), 1,
+      sym_block,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6428] = 2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(522), 3,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+  [6439] = 4,
+    ACTIONS(408), 1,
+      anon_sym_RPAREN,
+    ACTIONS(554), 1,
+      anon_sym_COMMA,
+    STATE(155), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6454] = 2,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+    ACTIONS(526), 3,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+  [6465] = 4,
+    ACTIONS(557), 1,
+      anon_sym_COMMA,
+    ACTIONS(559), 1,
+      anon_sym_RPAREN,
+    STATE(152), 1,
+      aux_sym_parameters_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6480] = 4,
+    ACTIONS(542), 1,
+      sym_identifier,
+    ACTIONS(561), 1,
+      anon_sym_RPAREN,
+    STATE(170), 1,
+      sym_parameter,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6495] = 4,
+    ACTIONS(563), 1,
+      anon_sym_COMMA,
+    ACTIONS(566), 1,
+      anon_sym_RPAREN,
+    STATE(159), 1,
+      aux_sym_parameters_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6510] = 4,
+    ACTIONS(95), 1,
+      anon_sym_RPAREN,
+    ACTIONS(568), 1,
+      anon_sym_COMMA,
+    STATE(155), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6525] = 3,
+    ACTIONS(570), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(572), 1,
+      sym__raw_multiline_string_content,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6537] = 3,
+    ACTIONS(542), 1,
+      sym_identifier,
+    STATE(170), 1,
+      sym_parameter,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6549] = 3,
+    ACTIONS(574), 1,
+      sym_identifier,
+    STATE(163), 1,
+      aux_sym_named_type_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6561] = 2,
+    ACTIONS(577), 2,
+      anon_sym_DASH_GT,
+      anon_sym_LBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6571] = 3,
+    ACTIONS(579), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(581), 1,
+      sym__raw_multiline_string_content,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6583] = 2,
+    ACTIONS(583), 2,
+      anon_sym_DASH_GT,
+      anon_sym_LBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6593] = 2,
+    ACTIONS(585), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6603] = 2,
+    ACTIONS(587), 2,
+      anon_sym_DASH_GT,
+      anon_sym_LBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6613] = 3,
+    ACTIONS(570), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(589), 1,
+      sym__raw_string_content,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6625] = 2,
+    ACTIONS(566), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6635] = 3,
+    ACTIONS(579), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(591), 1,
+      sym__raw_string_content,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6647] = 3,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    STATE(148), 1,
+      sym_block,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6659] = 3,
+    ACTIONS(593), 1,
+      anon_sym_LPAREN,
+    STATE(153), 1,
+      sym_parameters,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6671] = 3,
+    ACTIONS(595), 1,
+      sym_identifier,
+    STATE(163), 1,
+      aux_sym_named_type_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6683] = 2,
+    ACTIONS(597), 2,
+      anon_sym_DASH_GT,
+      anon_sym_LBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6693] = 3,
+    ACTIONS(599), 1,
+      sym_identifier,
+    STATE(163), 1,
+      aux_sym_named_type_repeat1,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6705] = 2,
+    ACTIONS(601), 1,
+      sym_identifier,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6714] = 2,
+    ACTIONS(536), 1,
+      anon_sym_DOT,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6723] = 2,
+    ACTIONS(603), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6732] = 2,
+    ACTIONS(605), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6741] = 2,
+    ACTIONS(607), 1,
+      sym_identifier,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6750] = 2,
+    ACTIONS(609), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6759] = 2,
+    ACTIONS(609), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6768] = 2,
+    ACTIONS(611), 1,
+      sym_identifier,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6777] = 2,
+    ACTIONS(613), 1,
+      sym_identifier,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6786] = 2,
+    ACTIONS(615), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6795] = 2,
+    ACTIONS(617), 1,
+      ts_builtin_sym_end,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6804] = 2,
+    ACTIONS(619), 1,
+      anon_sym_RPAREN,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6813] = 2,
+    ACTIONS(621), 1,
+      anon_sym_RPAREN,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6822] = 2,
+    ACTIONS(623), 1,
+      ts_builtin_sym_end,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6831] = 2,
+    ACTIONS(625), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6840] = 2,
+    ACTIONS(89), 1,
+      ts_builtin_sym_end,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6849] = 2,
+    ACTIONS(627), 1,
+      sym_identifier,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+  [6858] = 2,
+    ACTIONS(605), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 3,
+      sym_line_comment,
+      sym_block_comment,
+      sym_doc_comment,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(4)] = 0,
+  [SMALL_STATE(5)] = 84,
+  [SMALL_STATE(6)] = 168,
+  [SMALL_STATE(7)] = 246,
+  [SMALL_STATE(8)] = 327,
+  [SMALL_STATE(9)] = 408,
+  [SMALL_STATE(10)] = 489,
+  [SMALL_STATE(11)] = 555,
+  [SMALL_STATE(12)] = 621,
+  [SMALL_STATE(13)] = 687,
+  [SMALL_STATE(14)] = 753,
+  [SMALL_STATE(15)] = 819,
+  [SMALL_STATE(16)] = 885,
+  [SMALL_STATE(17)] = 948,
+  [SMALL_STATE(18)] = 1011,
+  [SMALL_STATE(19)] = 1074,
+  [SMALL_STATE(20)] = 1137,
+  [SMALL_STATE(21)] = 1200,
+  [SMALL_STATE(22)] = 1263,
+  [SMALL_STATE(23)] = 1326,
+  [SMALL_STATE(24)] = 1389,
+  [SMALL_STATE(25)] = 1452,
+  [SMALL_STATE(26)] = 1515,
+  [SMALL_STATE(27)] = 1578,
+  [SMALL_STATE(28)] = 1641,
+  [SMALL_STATE(29)] = 1704,
+  [SMALL_STATE(30)] = 1767,
+  [SMALL_STATE(31)] = 1830,
+  [SMALL_STATE(32)] = 1893,
+  [SMALL_STATE(33)] = 1956,
+  [SMALL_STATE(34)] = 2019,
+  [SMALL_STATE(35)] = 2082,
+  [SMALL_STATE(36)] = 2145,
+  [SMALL_STATE(37)] = 2208,
+  [SMALL_STATE(38)] = 2271,
+  [SMALL_STATE(39)] = 2334,
+  [SMALL_STATE(40)] = 2397,
+  [SMALL_STATE(41)] = 2460,
+  [SMALL_STATE(42)] = 2523,
+  [SMALL_STATE(43)] = 2586,
+  [SMALL_STATE(44)] = 2649,
+  [SMALL_STATE(45)] = 2712,
+  [SMALL_STATE(46)] = 2775,
+  [SMALL_STATE(47)] = 2838,
+  [SMALL_STATE(48)] = 2878,
+  [SMALL_STATE(49)] = 2918,
+  [SMALL_STATE(50)] = 2958,
+  [SMALL_STATE(51)] = 2997,
+  [SMALL_STATE(52)] = 3036,
+  [SMALL_STATE(53)] = 3075,
+  [SMALL_STATE(54)] = 3114,
+  [SMALL_STATE(55)] = 3149,
+  [SMALL_STATE(56)] = 3183,
+  [SMALL_STATE(57)] = 3217,
+  [SMALL_STATE(58)] = 3251,
+  [SMALL_STATE(59)] = 3285,
+  [SMALL_STATE(60)] = 3319,
+  [SMALL_STATE(61)] = 3357,
+  [SMALL_STATE(62)] = 3391,
+  [SMALL_STATE(63)] = 3446,
+  [SMALL_STATE(64)] = 3479,
+  [SMALL_STATE(65)] = 3534,
+  [SMALL_STATE(66)] = 3567,
+  [SMALL_STATE(67)] = 3600,
+  [SMALL_STATE(68)] = 3633,
+  [SMALL_STATE(69)] = 3666,
+  [SMALL_STATE(70)] = 3699,
+  [SMALL_STATE(71)] = 3732,
+  [SMALL_STATE(72)] = 3765,
+  [SMALL_STATE(73)] = 3820,
+  [SMALL_STATE(74)] = 3857,
+  [SMALL_STATE(75)] = 3894,
+  [SMALL_STATE(76)] = 3947,
+  [SMALL_STATE(77)] = 3998,
+  [SMALL_STATE(78)] = 4047,
+  [SMALL_STATE(79)] = 4080,
+  [SMALL_STATE(80)] = 4115,
+  [SMALL_STATE(81)] = 4162,
+  [SMALL_STATE(82)] = 4195,
+  [SMALL_STATE(83)] = 4228,
+  [SMALL_STATE(84)] = 4261,
+  [SMALL_STATE(85)] = 4294,
+  [SMALL_STATE(86)] = 4339,
+  [SMALL_STATE(87)] = 4382,
+  [SMALL_STATE(88)] = 4423,
+  [SMALL_STATE(89)] = 4462,
+  [SMALL_STATE(90)] = 4497,
+  [SMALL_STATE(91)] = 4532,
+  [SMALL_STATE(92)] = 4587,
+  [SMALL_STATE(93)] = 4619,
+  [SMALL_STATE(94)] = 4651,
+  [SMALL_STATE(95)] = 4683,
+  [SMALL_STATE(96)] = 4743,
+  [SMALL_STATE(97)] = 4795,
+  [SMALL_STATE(98)] = 4839,
+  [SMALL_STATE(99)] = 4877,
+  [SMALL_STATE(100)] = 4931,
+  [SMALL_STATE(101)] = 4979,
+  [SMALL_STATE(102)] = 5029,
+  [SMALL_STATE(103)] = 5075,
+  [SMALL_STATE(104)] = 5117,
+  [SMALL_STATE(105)] = 5157,
+  [SMALL_STATE(106)] = 5191,
+  [SMALL_STATE(107)] = 5225,
+  [SMALL_STATE(108)] = 5257,
+  [SMALL_STATE(109)] = 5289,
+  [SMALL_STATE(110)] = 5323,
+  [SMALL_STATE(111)] = 5355,
+  [SMALL_STATE(112)] = 5415,
+  [SMALL_STATE(113)] = 5447,
+  [SMALL_STATE(114)] = 5503,
+  [SMALL_STATE(115)] = 5535,
+  [SMALL_STATE(116)] = 5590,
+  [SMALL_STATE(117)] = 5644,
+  [SMALL_STATE(118)] = 5698,
+  [SMALL_STATE(119)] = 5752,
+  [SMALL_STATE(120)] = 5806,
+  [SMALL_STATE(121)] = 5832,
+  [SMALL_STATE(122)] = 5858,
+  [SMALL_STATE(123)] = 5884,
+  [SMALL_STATE(124)] = 5904,
+  [SMALL_STATE(125)] = 5924,
+  [SMALL_STATE(126)] = 5944,
+  [SMALL_STATE(127)] = 5968,
+  [SMALL_STATE(128)] = 5988,
+  [SMALL_STATE(129)] = 6008,
+  [SMALL_STATE(130)] = 6026,
+  [SMALL_STATE(131)] = 6050,
+  [SMALL_STATE(132)] = 6070,
+  [SMALL_STATE(133)] = 6090,
+  [SMALL_STATE(134)] = 6110,
+  [SMALL_STATE(135)] = 6128,
+  [SMALL_STATE(136)] = 6152,
+  [SMALL_STATE(137)] = 6172,
+  [SMALL_STATE(138)] = 6192,
+  [SMALL_STATE(139)] = 6212,
+  [SMALL_STATE(140)] = 6229,
+  [SMALL_STATE(141)] = 6244,
+  [SMALL_STATE(142)] = 6259,
+  [SMALL_STATE(143)] = 6273,
+  [SMALL_STATE(144)] = 6285,
+  [SMALL_STATE(145)] = 6299,
+  [SMALL_STATE(146)] = 6313,
+  [SMALL_STATE(147)] = 6327,
+  [SMALL_STATE(148)] = 6339,
+  [SMALL_STATE(149)] = 6353,
+  [SMALL_STATE(150)] = 6368,
+  [SMALL_STATE(151)] = 6383,
+  [SMALL_STATE(152)] = 6398,
+  [SMALL_STATE(153)] = 6413,
+  [SMALL_STATE(154)] = 6428,
+  [SMALL_STATE(155)] = 6439,
+  [SMALL_STATE(156)] = 6454,
+  [SMALL_STATE(157)] = 6465,
+  [SMALL_STATE(158)] = 6480,
+  [SMALL_STATE(159)] = 6495,
+  [SMALL_STATE(160)] = 6510,
+  [SMALL_STATE(161)] = 6525,
+  [SMALL_STATE(162)] = 6537,
+  [SMALL_STATE(163)] = 6549,
+  [SMALL_STATE(164)] = 6561,
+  [SMALL_STATE(165)] = 6571,
+  [SMALL_STATE(166)] = 6583,
+  [SMALL_STATE(167)] = 6593,
+  [SMALL_STATE(168)] = 6603,
+  [SMALL_STATE(169)] = 6613,
+  [SMALL_STATE(170)] = 6625,
+  [SMALL_STATE(171)] = 6635,
+  [SMALL_STATE(172)] = 6647,
+  [SMALL_STATE(173)] = 6659,
+  [SMALL_STATE(174)] = 6671,
+  [SMALL_STATE(175)] = 6683,
+  [SMALL_STATE(176)] = 6693,
+  [SMALL_STATE(177)] = 6705,
+  [SMALL_STATE(178)] = 6714,
+  [SMALL_STATE(179)] = 6723,
+  [SMALL_STATE(180)] = 6732,
+  [SMALL_STATE(181)] = 6741,
+  [SMALL_STATE(182)] = 6750,
+  [SMALL_STATE(183)] = 6759,
+  [SMALL_STATE(184)] = 6768,
+  [SMALL_STATE(185)] = 6777,
+  [SMALL_STATE(186)] = 6786,
+  [SMALL_STATE(187)] = 6795,
+  [SMALL_STATE(188)] = 6804,
+  [SMALL_STATE(189)] = 6813,
+  [SMALL_STATE(190)] = 6822,
+  [SMALL_STATE(191)] = 6831,
+  [SMALL_STATE(192)] = 6840,
+  [SMALL_STATE(193)] = 6849,
+  [SMALL_STATE(194)] = 6858,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0),
+  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(49),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(193),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(177),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(2),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(62),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(67),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(127),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(171),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [39] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_expression, 1),
+  [41] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_expression, 1),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(27),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(64),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(128),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(165),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(51),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [61] = {.entry = {.count = 1, .reusable = false}}, SHIFT(40),
+  [63] = {.entry = {.count = 1, .reusable = false}}, SHIFT(3),
+  [65] = {.entry = {.count = 1, .reusable = false}}, SHIFT(113),
+  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [69] = {.entry = {.count = 1, .reusable = false}}, SHIFT(107),
+  [71] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [75] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [79] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__items, 3),
+  [81] = {.entry = {.count = 1, .reusable = false}}, SHIFT(73),
+  [83] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__items, 3),
+  [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__items, 2),
+  [87] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__items, 2),
+  [89] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1),
+  [91] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [97] = {.entry = {.count = 1, .reusable = false}}, SHIFT(115),
+  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [105] = {.entry = {.count = 1, .reusable = false}}, SHIFT(95),
+  [107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [113] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
+  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(97),
+  [121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [123] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
+  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [127] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
+  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [131] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
+  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [135] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
+  [141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
+  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(116),
+  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [151] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
+  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
+  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [163] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
+  [165] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [167] = {.entry = {.count = 1, .reusable = false}}, SHIFT(99),
+  [169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [171] = {.entry = {.count = 1, .reusable = false}}, SHIFT(96),
+  [173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(101),
+  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [179] = {.entry = {.count = 1, .reusable = false}}, SHIFT(100),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [183] = {.entry = {.count = 1, .reusable = false}}, SHIFT(102),
+  [185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [187] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
+  [189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [191] = {.entry = {.count = 1, .reusable = false}}, SHIFT(103),
+  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [195] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
+  [197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [199] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
+  [201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [203] = {.entry = {.count = 1, .reusable = false}}, SHIFT(106),
+  [205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [207] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
+  [209] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [211] = {.entry = {.count = 1, .reusable = false}}, SHIFT(109),
+  [213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [215] = {.entry = {.count = 1, .reusable = false}}, SHIFT(80),
+  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [219] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
+  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [223] = {.entry = {.count = 1, .reusable = false}}, SHIFT(86),
+  [225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [227] = {.entry = {.count = 1, .reusable = false}}, SHIFT(119),
+  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [231] = {.entry = {.count = 1, .reusable = false}}, SHIFT(117),
+  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [235] = {.entry = {.count = 1, .reusable = false}}, SHIFT(75),
+  [237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_path, 2),
+  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_path, 2),
+  [243] = {.entry = {.count = 1, .reusable = false}}, SHIFT(181),
+  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_path_repeat1, 2),
+  [247] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_path_repeat1, 2),
+  [249] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_path_repeat1, 2), SHIFT_REPEAT(181),
+  [252] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_path, 1),
+  [254] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_path, 1),
+  [256] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_repeat1, 2), SHIFT_REPEAT(184),
+  [259] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1),
+  [263] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression, 1),
+  [265] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
+  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 4),
+  [269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 4),
+  [271] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 2),
+  [273] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 2),
+  [275] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 3),
+  [277] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 3),
+  [279] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expression, 2, .production_id = 3),
+  [281] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expression, 2, .production_id = 3),
+  [283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 5),
+  [285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 5),
+  [287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__item, 1),
+  [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__item, 1),
+  [293] = {.entry = {.count = 1, .reusable = false}}, SHIFT(25),
+  [295] = {.entry = {.count = 1, .reusable = false}}, SHIFT(46),
+  [297] = {.entry = {.count = 1, .reusable = false}}, SHIFT(34),
+  [299] = {.entry = {.count = 1, .reusable = false}}, SHIFT(39),
+  [301] = {.entry = {.count = 1, .reusable = false}}, SHIFT(41),
+  [303] = {.entry = {.count = 1, .reusable = false}}, SHIFT(42),
+  [305] = {.entry = {.count = 1, .reusable = false}}, SHIFT(43),
+  [307] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [309] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
+  [311] = {.entry = {.count = 1, .reusable = false}}, SHIFT(19),
+  [313] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
+  [315] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2),
+  [317] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2),
+  [319] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_expression, 2),
+  [321] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_expression, 2),
+  [323] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2),
+  [325] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2),
+  [327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3),
+  [329] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_expression, 3),
+  [331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1),
+  [333] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1),
+  [335] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3),
+  [337] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3),
+  [339] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3),
+  [341] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3),
+  [343] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_string, 3),
+  [345] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_string, 3),
+  [347] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_string, 2),
+  [349] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_string, 2),
+  [351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_local_declaration, 4, .production_id = 7),
+  [353] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_local_declaration, 4, .production_id = 7),
+  [355] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__terminator, 2),
+  [357] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__terminator, 2),
+  [359] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym__terminator, 2), SHIFT_REPEAT(73),
+  [362] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, .production_id = 4),
+  [364] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, .production_id = 4),
+  [366] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expression, 2, .production_id = 2),
+  [368] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_expression, 2, .production_id = 2),
+  [370] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_local_declaration, 6, .production_id = 12),
+  [372] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_local_declaration, 6, .production_id = 12),
+  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [386] = {.entry = {.count = 1, .reusable = false}}, SHIFT(32),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [398] = {.entry = {.count = 1, .reusable = false}}, SHIFT(37),
+  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [408] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2),
+  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [418] = {.entry = {.count = 1, .reusable = false}}, SHIFT(144),
+  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [422] = {.entry = {.count = 1, .reusable = false}}, SHIFT(154),
+  [424] = {.entry = {.count = 1, .reusable = false}}, SHIFT(167),
+  [426] = {.entry = {.count = 1, .reusable = false}}, SHIFT(172),
+  [428] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
+  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [432] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
+  [434] = {.entry = {.count = 1, .reusable = false}}, SHIFT(139),
+  [436] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_local_declaration, 2, .production_id = 1),
+  [438] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_local_declaration, 2, .production_id = 1),
+  [440] = {.entry = {.count = 1, .reusable = false}}, SHIFT(122),
+  [442] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [446] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [454] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__items, 1),
+  [456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [458] = {.entry = {.count = 1, .reusable = false}}, SHIFT(5),
+  [460] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__items, 1),
+  [462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [468] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_named_type, 1, .production_id = 5),
+  [470] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_named_type, 1, .production_id = 5),
+  [472] = {.entry = {.count = 1, .reusable = false}}, SHIFT(185),
+  [474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [476] = {.entry = {.count = 1, .reusable = false}}, SHIFT(4),
+  [478] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2), SHIFT_REPEAT(23),
+  [481] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2),
+  [483] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2), SHIFT_REPEAT(131),
+  [486] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat2, 2), SHIFT_REPEAT(45),
+  [489] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat2, 2),
+  [491] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat2, 2), SHIFT_REPEAT(132),
+  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [496] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_named_type, 2, .production_id = 10),
+  [498] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_named_type, 2, .production_id = 10),
+  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__items_repeat1, 2),
+  [502] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__items_repeat1, 2), SHIFT_REPEAT(6),
+  [505] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym__items_repeat1, 2), SHIFT_REPEAT(6),
+  [508] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__items_repeat1, 2),
+  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [516] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_local_declaration, 4, .production_id = 6),
+  [518] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_local_declaration, 4, .production_id = 6),
+  [520] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [522] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unit_type, 1),
+  [524] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unit_type, 1),
+  [526] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unit_type, 2),
+  [528] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unit_type, 2),
+  [530] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 4, .production_id = 8),
+  [532] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_declaration, 4, .production_id = 8),
+  [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interpolation, 3),
+  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [538] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 6, .production_id = 14),
+  [540] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_declaration, 6, .production_id = 14),
+  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [550] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [554] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2), SHIFT_REPEAT(26),
+  [557] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [559] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [561] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [563] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parameters_repeat1, 2), SHIFT_REPEAT(162),
+  [566] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parameters_repeat1, 2),
+  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [574] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_named_type_repeat1, 2, .production_id = 11), SHIFT_REPEAT(178),
+  [577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 2),
+  [579] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [581] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [583] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 3),
+  [585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 3, .production_id = 13),
+  [587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 4),
+  [589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [591] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [593] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 5),
+  [599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [601] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [605] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [607] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [609] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_named_type_repeat1, 2, .production_id = 9),
+  [615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2),
+  [619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [623] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [627] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+void *tree_sitter_xva_external_scanner_create(void);
+void tree_sitter_xva_external_scanner_destroy(void *);
+bool tree_sitter_xva_external_scanner_scan(void *, TSLexer *, const bool *);
+unsigned tree_sitter_xva_external_scanner_serialize(void *, char *);
+void tree_sitter_xva_external_scanner_deserialize(void *, const char *, unsigned);
+
+#ifdef _WIN32
+#define extern __declspec(dllexport)
+#endif
+
+extern const TSLanguage *tree_sitter_xva(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .keyword_lex_fn = ts_lex_keywords,
+    .keyword_capture_token = sym_identifier,
+    .external_scanner = {
+      &ts_external_scanner_states[0][0],
+      ts_external_scanner_symbol_map,
+      tree_sitter_xva_external_scanner_create,
+      tree_sitter_xva_external_scanner_destroy,
+      tree_sitter_xva_external_scanner_scan,
+      tree_sitter_xva_external_scanner_serialize,
+      tree_sitter_xva_external_scanner_deserialize,
+    },
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,177 @@
+#include "tree_sitter/parser.h"
+
+#include <wctype.h>
+
+// The external tokens, in the order they're declared in `grammar.js`.
+enum TokenType {
+    STRING_CONTENT,
+    MULTILINE_STRING_CONTENT,
+    RAW_STRING_CONTENT,
+    RAW_MULTILINE_STRING_CONTENT,
+    LINE_COMMENT,
+    BLOCK_COMMENT,
+    DOC_COMMENT,
+    ERROR_SENTINEL,
+};
+
+// The scanner has no state: everything it needs is in `valid_symbols`.
+void *tree_sitter_xva_external_scanner_create(void) { return NULL; }
+
+void tree_sitter_xva_external_scanner_destroy(void *payload) {}
+
+unsigned tree_sitter_xva_external_scanner_serialize(void *payload, char *buffer) { return 0; }
+
+void tree_sitter_xva_external_scanner_deserialize(void *payload, const char *buffer, unsigned length) {}
+
+static inline void advance(TSLexer *lexer) { lexer->advance(lexer, false); }
+
+static inline void skip(TSLexer *lexer) { lexer->advance(lexer, true); }
+
+// The text of a string, up to its closing quote. Escapes and interpolations are left to the grammar, except in raw
+// strings where they're just text. Only multi-line strings can contain line breaks, and a quote only closes one of
+// those when there are three of them in a row.
+static bool scan_string_content(TSLexer *lexer, bool multiline, bool raw) {
+    bool has_content = false;
+
+    for (;;) {
+        lexer->mark_end(lexer);
+
+        switch (lexer->lookahead) {
+            case '"':
+                if (!multiline) {
+                    return has_content;
+                }
+
+                advance(lexer);
+                if (lexer->lookahead == '"') {
+                    advance(lexer);
+                    if (lexer->lookahead == '"') {
+                        return has_content;
+                    }
+                }
+                has_content = true;
+                continue;
+            case '\\':
+            case '{':
+                if (!raw) {
+                    return has_content;
+                }
+                break;
+            case '\r':
+            case '\n':
+                if (!multiline) {
+                    return has_content;
+                }
+                break;
+            case '\0':
+                if (lexer->eof(lexer)) {
+                    return has_content;
+                }
+                break;
+        }
+
+        advance(lexer);
+        has_content = true;
+    }
+}
+
+// A block comment, after its opening `/*`, including any comments nested inside it. An unterminated comment runs to
+// the end of the file.
+static void scan_block_comment(TSLexer *lexer) {
+    unsigned depth = 1;
+
+    while (depth > 0 && !lexer->eof(lexer)) {
+        switch (lexer->lookahead) {
+            case '/':
+                advance(lexer);
+                if (lexer->lookahead == '*') {
+                    advance(lexer);
+                    depth++;
+                }
+                break;
+            case '*':
+                advance(lexer);
+                if (lexer->lookahead == '/') {
+                    advance(lexer);
+                    depth--;
+                }
+                break;
+            default:
+                advance(lexer);
+                break;
+        }
+    }
+}
+
+// `// ...`, `/* ... */`, and the doc comments `/// ...` and `/** ... */`. As in the lexer, `/**/` is an empty comment
+// and `/***` starts a comment made to stand out, not doc comments.
+static bool scan_comment(TSLexer *lexer) {
+    while (iswspace(lexer->lookahead)) {
+        skip(lexer);
+    }
+
+    if (lexer->lookahead != '/') {
+        return false;
+    }
+    advance(lexer);
+
+    if (lexer->lookahead == '/') {
+        advance(lexer);
+        lexer->result_symbol = LINE_COMMENT;
+        if (lexer->lookahead == '/') {
+            lexer->result_symbol = DOC_COMMENT;
+        }
+
+        while (lexer->lookahead != '\n' && !lexer->eof(lexer)) {
+            advance(lexer);
+        }
+        return true;
+    }
+
+    if (lexer->lookahead != '*') {
+        return false;
+    }
+    advance(lexer);
+
+    lexer->result_symbol = BLOCK_COMMENT;
+    if (lexer->lookahead == '*') {
+        advance(lexer);
+        if (lexer->lookahead == '/') {
+            advance(lexer);
+            return true;
+        }
+        if (lexer->lookahead != '*') {
+            lexer->result_symbol = DOC_COMMENT;
+        }
+    }
+
+    scan_block_comment(lexer);
+    return true;
+}
+
+bool tree_sitter_xva_external_scanner_scan(void *payload, TSLexer *lexer, const bool *valid_symbols) {
+    // During error recovery every symbol is valid, and nothing can be known about where a string starts.
+    if (valid_symbols[ERROR_SENTINEL]) {
+        return scan_comment(lexer);
+    }
+
+    // Inside a string, slashes are just text.
+    if (valid_symbols[STRING_CONTENT]) {
+        lexer->result_symbol = STRING_CONTENT;
+        return scan_string_content(lexer, false, false);
+    }
+    if (valid_symbols[MULTILINE_STRING_CONTENT]) {
+        lexer->result_symbol = MULTILINE_STRING_CONTENT;
+        return scan_string_content(lexer, true, false);
+    }
+    if (valid_symbols[RAW_STRING_CONTENT]) {
+        lexer->result_symbol = RAW_STRING_CONTENT;
+        return scan_string_content(lexer, false, true);
+    }
+    if (valid_symbols[RAW_MULTILINE_STRING_CONTENT]) {
+        lexer->result_symbol = RAW_MULTILINE_STRING_CONTENT;
+        return scan_string_content(lexer, true, true);
+    }
+
+    return scan_comment(lexer);
+}
@@ -0,0 +1,186 @@
+//! `xva check`: finds every diagnostic in a set of files, without running any of them.
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use xva_compiler::{check::Checker, diagnostic::ErrorFormat, Compiler};
+use xva_parse::error::Severity;
+
+/// The extension of Xva source files.
+const SOURCE_EXTENSION: &str = "xva";
+
+/// Exit code for when the command line itself is wrong, e.g. a path that doesn't exist. This matches the exit code
+/// `clap` uses for its own usage errors.
+const USAGE_ERROR: u8 = 2;
+
+/// Parses and checks every source file found at `paths` with `compiler`, writing all diagnostics to `stderr`.
+///
+/// Files are checked as given. Directories are searched recursively for `.xva` files, skipping hidden entries.
+///
+/// # Returns
+/// [`ExitCode::SUCCESS`] if there were no errors, [`ExitCode::FAILURE`] if there were any, or
+/// [`USAGE_ERROR`] if any of the paths don't exist.
+pub(crate) fn check_paths(paths: &[PathBuf], mut compiler: Compiler) -> std::io::Result<ExitCode> {
+    let mut files = vec![];
+    for path in paths {
+        if !path.exists() {
+            compiler.write_message(
+                &format!("No such file or directory: {}", path.display()),
+                std::io::stderr().lock(),
+            );
+            return Ok(ExitCode::from(USAGE_ERROR));
+        }
+
+        collect_sources(path, &mut files)?;
+    }
+
+    let mut failed_files = 0;
+    let mut error_count = 0;
+
+    for file in files {
+        let src_id = match compiler.load_file(file.clone()) {
+            Ok(id) => id,
+            Err(e) => {
+                compiler.write_message(
+                    &format!("Could not read {}: {e}", file.display()),
+                    std::io::stderr().lock(),
+                );
+                failed_files += 1;
+                error_count += 1;
+                continue;
+            }
+        };
+
+        // Files don't refer to each other yet, so each is checked on its own.
+        compiler.checker = Checker::default();
+        let tree = compiler.parse_file(src_id);
+        compiler.check(&tree);
+
+        let errors = compiler
+            .diagnostics()
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() == Severity::Error)
+            .count();
+        if errors > 0 {
+            failed_files += 1;
+            error_count += errors;
+        }
+
+        compiler.write_diagnostics(std::io::stderr().lock());
+    }
+
+    if error_count == 0 {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    // Anything reading JSON only expects diagnostics.
+    if compiler.error_format == ErrorFormat::Human {
+        eprintln!("Found {error_count} error(s) in {failed_files} file(s)");
+    }
+    Ok(ExitCode::FAILURE)
+}
+
+/// Adds `path` to `files` if it is a file, or every source file under it if it is a directory, in a stable order.
+fn collect_sources(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let mut entries = std::fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+
+    for entry in entries {
+        let hidden = entry
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+
+        if hidden {
+            continue;
+        }
+
+        if entry.is_dir() {
+            collect_sources(&entry, files)?;
+        } else if entry.extension().is_some_and(|ext| ext == SOURCE_EXTENSION) {
+            files.push(entry);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, process::ExitCode};
+
+    use xva_compiler::Compiler;
+
+    use super::{check_paths, collect_sources};
+
+    /// Creates a fresh directory for a test to put files in.
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("xva_check_{name}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn collects_source_files_recursively() {
+        let dir = scratch_dir("collect");
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::create_dir_all(dir.join(".hidden")).unwrap();
+        for file in [
+            "b.xva",
+            "a.xva",
+            "notes.txt",
+            "nested/c.xva",
+            ".hidden/d.xva",
+        ] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+
+        let mut files = vec![];
+        collect_sources(&dir, &mut files).unwrap();
+        assert_eq!(
+            files,
+            [
+                dir.join("a.xva"),
+                dir.join("b.xva"),
+                dir.join("nested/c.xva")
+            ]
+        );
+    }
+
+    #[test]
+    fn exit_codes() {
+        let dir = scratch_dir("exit_codes");
+        std::fs::write(dir.join("good.xva"), "let x = 1 + 2").unwrap();
+        assert_eq!(
+            check_paths(std::slice::from_ref(&dir), Compiler::default()).unwrap(),
+            ExitCode::SUCCESS
+        );
+
+        // The `а` is Cyrillic, which is only a warning.
+        std::fs::write(dir.join("warning.xva"), "let p\u{430}y = 1").unwrap();
+        assert_eq!(
+            check_paths(std::slice::from_ref(&dir), Compiler::default()).unwrap(),
+            ExitCode::SUCCESS
+        );
+
+        std::fs::write(dir.join("bad.xva"), "let y = 1 + true").unwrap();
+        assert_eq!(
+            check_paths(std::slice::from_ref(&dir), Compiler::default()).unwrap(),
+            ExitCode::FAILURE
+        );
+
+        assert_eq!(
+            check_paths(&[dir.join("missing.xva")], Compiler::default()).unwrap(),
+            ExitCode::from(2)
+        );
+    }
+}
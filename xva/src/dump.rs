@@ -0,0 +1,121 @@
+//! `xva dump`: writes the tokens or tree of a file in a stable format, for golden tests to compare against.
+
+use std::{path::Path, process::ExitCode};
+
+use xva_ast::dump::{dump_items, Dump, DumpOptions};
+use xva_compiler::Compiler;
+use xva_parse::{error::Severity, token::dump_tokens, SyntaxError};
+
+/// What `xva dump` should write.
+pub(crate) struct DumpKinds {
+    pub tokens: bool,
+    pub ast: bool,
+    pub cst: bool,
+    pub node_ids: bool,
+}
+
+/// Dumps a file to `stdout`, and any diagnostics to `stderr`. The tokens come first, then the concrete syntax tree,
+/// then the abstract syntax tree.
+pub(crate) fn dump_file(
+    path: &Path,
+    kinds: DumpKinds,
+    mut compiler: Compiler,
+) -> std::io::Result<ExitCode> {
+    let src_id = match compiler.load_file(path.to_path_buf()) {
+        Ok(id) => id,
+        Err(e) => {
+            eprintln!("Could not read {}: {e}", path.display());
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    // Errors that are only written here, rather than reported to the compiler.
+    let mut failed = false;
+
+    if kinds.tokens {
+        let src = compiler.get_file_content(src_id).unwrap();
+        let (tokens, errors) = xva_parse::lex(&src, src_id, false);
+        print!("{}", dump_tokens(&tokens));
+        failed |= has_errors(&errors);
+
+        // The parser lexes the file again, so only report these if it won't.
+        if !kinds.ast && !kinds.cst {
+            for error in errors {
+                compiler.write_syntax_error(error, std::io::stderr().lock());
+            }
+        }
+    }
+
+    if kinds.cst {
+        let src = compiler.get_file_content(src_id).unwrap();
+        let (cst, errors) = xva_parse::parser::parse_lossless(&src, src_id);
+        println!("{}", cst.dump(&DumpOptions::default()));
+        failed |= has_errors(&errors);
+
+        if !kinds.ast {
+            for error in errors {
+                compiler.write_syntax_error(error, std::io::stderr().lock());
+            }
+        }
+    }
+
+    if kinds.ast {
+        let tree = compiler.parse_file(src_id);
+        let opts = DumpOptions {
+            node_ids: kinds.node_ids,
+        };
+        print!("{}", dump_items(&tree, &opts));
+    }
+
+    let failed = failed || compiler.has_errors();
+    compiler.write_diagnostics(std::io::stderr().lock());
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn has_errors(errors: &[SyntaxError]) -> bool {
+    errors
+        .iter()
+        .any(|error| error.severity() == Severity::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::ExitCode;
+
+    use xva_compiler::Compiler;
+
+    use super::{dump_file, DumpKinds};
+
+    #[test]
+    fn exit_codes() {
+        let dir = std::env::temp_dir().join("xva_dump_exit_codes");
+        std::fs::create_dir_all(&dir).unwrap();
+        let (good, bad) = (dir.join("good.xva"), dir.join("bad.xva"));
+        std::fs::write(&good, "let x = 1").unwrap();
+        std::fs::write(&bad, "let x = 0x").unwrap();
+
+        let kinds = [
+            (true, false, false),
+            (false, true, false),
+            (false, false, true),
+        ];
+        for (tokens, cst, ast) in kinds {
+            let dump = |path| {
+                let kinds = DumpKinds {
+                    tokens,
+                    ast,
+                    cst,
+                    node_ids: false,
+                };
+                dump_file(path, kinds, Compiler::default()).unwrap()
+            };
+            assert_eq!(dump(&good), ExitCode::SUCCESS);
+            assert_eq!(dump(&bad), ExitCode::FAILURE, "{tokens} {cst} {ast}");
+        }
+    }
+}
@@ -1,55 +1,82 @@
 #![deny(unused_crate_dependencies)]
 
 use clap::Parser;
-use std::io::{BufRead, Write};
-use xva_compiler::Compiler;
+use std::{path::Path, process::ExitCode};
+use xva_compiler::{eval::Interpreter, options::UnstableOptions, Compiler};
 
+mod check;
+mod dump;
 mod opts;
+mod repl;
 
-use opts::Options;
+use opts::{Command, Options};
 
 const BUILD_INFO: &str = include_str!("../.buildinfo");
 
-fn main() -> Result<(), std::io::Error> {
-    println!("{BUILD_INFO}");
-
+fn main() -> Result<ExitCode, std::io::Error> {
     let opts = Options::parse();
-    run_repl(&opts)?;
 
-    Ok(())
-}
+    if opts.compiler().unstable_options.is_set("help") {
+        println!("{}", UnstableOptions::help());
+        return Ok(ExitCode::SUCCESS);
+    }
 
-const REPL_SOURCE_NAME: &str = "<repl>";
-fn run_repl(opts: &Options) -> std::io::Result<()> {
-    let stdin = std::io::stdin();
-    let mut stdout = std::io::stdout();
-    let pretty_lex = opts.unstable_option_contains("pretty", "lex");
-    let pretty_ast = opts.unstable_option_contains("pretty", "ast");
-
-    loop {
-        let _stdout_lock = stdout.lock();
-        stdout.write_all("> ".as_bytes())?;
-        stdout.flush()?;
-
-        let line = stdin.lock().lines().next().unwrap()?;
-        let mut compiler = Compiler::default();
-        let src_id = compiler.load_virtual_file(REPL_SOURCE_NAME.into(), line);
-
-        let (tree, errors) = xva_parse::parser::parse(
-            compiler.get_file_content(src_id).unwrap().as_ref(),
-            src_id,
-            pretty_lex,
-        );
-
-        if pretty_ast {
-            println!("{tree:#?}")
+    match &opts.command {
+        Some(Command::Run { file, args }) => run_file(file, args.clone(), opts.compiler()),
+        Some(Command::Check { paths }) => check::check_paths(paths, opts.compiler()),
+        Some(Command::Dump {
+            file,
+            tokens,
+            ast,
+            cst,
+            node_ids,
+        }) => {
+            let kinds = dump::DumpKinds {
+                tokens: *tokens,
+                ast: *ast,
+                cst: *cst,
+                node_ids: *node_ids,
+            };
+            dump::dump_file(file, kinds, opts.compiler())
         }
-
-        if errors.len() != 0 {
-            for error in errors {
-                let writer = stdout.lock();
-                compiler.write_syntax_error(error, writer);
+        None => match &opts.script {
+            Some(script) => run_file(script, opts.script_args.clone(), opts.compiler()),
+            None => {
+                println!("{BUILD_INFO}");
+                repl::run_repl(&opts)?;
+                Ok(ExitCode::SUCCESS)
             }
+        },
+    }
+}
+
+/// Runs a source file through every stage: lexing, parsing, checking and executing.
+///
+/// Each stage only runs if the ones before it succeeded. All diagnostics are written to `stderr`. The program can
+/// read `args` through the `args()` builtin.
+fn run_file(path: &Path, args: Vec<String>, mut compiler: Compiler) -> std::io::Result<ExitCode> {
+    let src_id = match compiler.load_file(path.to_path_buf()) {
+        Ok(id) => id,
+        Err(e) => {
+            eprintln!("Could not read {}: {e}", path.display());
+            return Ok(ExitCode::FAILURE);
         }
+    };
+
+    compiler.interpreter = Interpreter::new(args);
+
+    let tree = compiler.parse_file(src_id);
+    compiler.check(&tree);
+    if let Some(program) = compiler.lower(tree) {
+        compiler.run(&program);
     }
+
+    let failed = compiler.has_errors();
+    compiler.write_diagnostics(std::io::stderr().lock());
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
 }
@@ -1,97 +1,87 @@
-use std::{collections::HashMap, error::Error};
+use std::path::PathBuf;
 
-use clap::Parser;
-pub(super) fn parse_unstable_option(
-    s: &str,
-) -> Result<UnstableOption, Box<dyn Error + Send + Sync + 'static>> {
-    if s.contains('=') {
-        let pos = s
-            .find('=')
-            .ok_or_else(|| format!("Invalid KEY=value: no `=` found in `{}`", s))?;
-        let comma_pos = s.find(',');
-        let key = s[..pos].parse()?;
-        let value: UnstableOptionValue;
-        if let Some(_) = comma_pos {
-            value = UnstableOptionValue::Multiple(
-                s[pos + 1..]
-                    .split(',')
-                    .into_iter()
-                    .map(|x| x.trim().to_string())
-                    .collect(),
-            );
-        } else {
-            value = UnstableOptionValue::Single(s[pos + 1..].parse()?);
-        }
-        Ok(UnstableOption { key, value })
-    } else {
-        let key = s.parse()?;
-        Ok(UnstableOption {
-            key,
-            value: UnstableOptionValue::None,
-        })
-    }
-}
+use clap::{ArgGroup, Parser, Subcommand};
+use xva_compiler::{
+    diagnostic::ErrorFormat,
+    options::{UnstableOption, UnstableOptions},
+    Compiler,
+};
 
-#[derive(Debug, Clone)]
-pub(super) enum UnstableOptionValue {
-    None,
-    Single(String),
-    Multiple(Vec<String>),
-}
+#[derive(Parser, Debug)]
+pub(super) struct Options {
+    /// Specify unstable compiler options. Use `-Z help` to list them
+    #[arg(short = 'Z', value_name = "OPTION", global = true)]
+    pub unstable_options: Vec<UnstableOption>,
 
-#[derive(Debug)]
+    /// How to write diagnostics: `human` or `json`
+    #[arg(long, default_value = "human", global = true)]
+    pub error_format: ErrorFormat,
 
-pub(super) struct UnstableOption {
-    key: String,
-    value: UnstableOptionValue,
-}
+    /// What to do. Starts the REPL if omitted.
+    #[command(subcommand)]
+    pub command: Option<Command>,
 
-impl Clone for UnstableOption {
-    fn clone(&self) -> Self {
-        Self {
-            key: self.key.clone(),
-            value: self.value.clone(),
-        }
-    }
-}
+    /// A source file to run, like `xva run`. This is how scripts starting with `#!/usr/bin/env xva` are run
+    pub script: Option<PathBuf>,
 
-#[derive(Parser, Debug)]
-pub(super) struct Options {
-    /// Specify unstable compiler options
-    #[arg(short = 'Z', value_parser = parse_unstable_option)]
-    pub unstable_options: Vec<UnstableOption>,
+    /// Arguments passed on to the script
+    #[arg(
+        trailing_var_arg = true,
+        allow_hyphen_values = true,
+        requires = "script"
+    )]
+    pub script_args: Vec<String>,
 }
 
-impl Options {
-    pub(crate) fn get_unstable(&self, key: &str) -> Option<&UnstableOption> {
-        self.unstable_options.iter().find(|x| x.key == key)
-    }
+#[derive(Subcommand, Debug)]
+pub(super) enum Command {
+    /// Run a source file
+    Run {
+        /// The source file to run
+        file: PathBuf,
 
-    pub(crate) fn unstable_option_is(&self, key: &str, check: Option<&str>) -> bool {
-        match self.unstable_options.iter().find(|x| x.key.as_str() == key) {
-            Some(ref opt) => match &opt.value {
-                UnstableOptionValue::None => check.is_none(),
-                UnstableOptionValue::Single(v) => match check {
-                    Some(c) => c == v,
-                    None => false,
-                },
-                _ => false,
-            },
-            None => false,
-        }
-    }
+        /// Arguments passed on to the program
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+
+    /// Check source files for errors, without running them
+    Check {
+        /// Files, or directories to search for `.xva` files
+        #[arg(default_value = ".")]
+        paths: Vec<PathBuf>,
+    },
+
+    /// Write the tokens or syntax tree of a source file as S-expressions
+    #[command(group(ArgGroup::new("dump").required(true).multiple(true)))]
+    Dump {
+        /// The source file to dump
+        file: PathBuf,
+
+        /// Dump the tokens
+        #[arg(long, group = "dump")]
+        tokens: bool,
 
-    pub(crate) fn unstable_option_contains(&self, key: &str, check: &str) -> bool {
-        // println!("opts: {:#?}", self.unstable_options);
-        match self.unstable_options.iter().find(|x| x.key.as_str() == key) {
-            Some(ref opt) => match &opt.value {
-                UnstableOptionValue::Single(v) => v == check,
-                UnstableOptionValue::Multiple(v) => {
-                    v.iter().map(|x| x.as_str()).find(|x| *x == check).is_some()
-                }
-                _ => false,
-            },
-            None => false,
-        }
+        /// Dump the syntax tree
+        #[arg(long, group = "dump")]
+        ast: bool,
+
+        /// Dump the concrete syntax tree, with every token and its whitespace and comments
+        #[arg(long, group = "dump")]
+        cst: bool,
+
+        /// Include node IDs in the syntax tree
+        #[arg(long, requires = "ast")]
+        node_ids: bool,
+    },
+}
+
+impl Options {
+    /// Creates a compiler configured by these options.
+    pub(crate) fn compiler(&self) -> Compiler {
+        let mut compiler = Compiler::default();
+        compiler.error_format = self.error_format;
+        compiler.unstable_options = UnstableOptions::new(self.unstable_options.iter().cloned());
+        compiler
     }
 }
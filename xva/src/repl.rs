@@ -0,0 +1,456 @@
+//! The interactive read-eval-print loop.
+
+use std::{io::Write, path::Path};
+
+use rustyline::error::ReadlineError;
+
+use xva_ast::{
+    ast::{Item, ItemKind},
+    dump::{dump_items, DumpOptions},
+};
+use xva_compiler::{
+    check::{Checker, Ty},
+    eval::{Interpreter, Value},
+    Compiler,
+};
+use xva_parse::{
+    error::SyntaxErrorKind,
+    token::{dump_tokens, Token, TokenKind},
+    SyntaxError,
+};
+use xva_span::SourceId;
+
+use crate::opts::Options;
+
+mod command;
+mod editor;
+
+use self::{
+    command::MetaCommand,
+    editor::{Editor, ReplHelper},
+};
+
+const PROMPT: &str = "> ";
+const CONTINUATION_PROMPT: &str = "... ";
+
+/// A single REPL session.
+///
+/// Everything entered into the REPL shares one session, so bindings made on one line can be used on the next.
+/// Each line is loaded into the source map as its own virtual file, named `<repl:N>`, so diagnostics always point
+/// at the line they came from.
+pub(crate) struct Session {
+    compiler: Compiler,
+
+    /// The number of lines entered so far.
+    lines: usize,
+}
+
+impl Session {
+    pub(crate) fn new(opts: &Options) -> Self {
+        Self {
+            compiler: opts.compiler(),
+            lines: 0,
+        }
+    }
+
+    /// Loads a line of input into the source map as a new virtual file.
+    fn load_line(&mut self, line: String) -> SourceId {
+        self.lines += 1;
+        self.compiler
+            .load_virtual_file(format!("<repl:{}>", self.lines), line)
+    }
+
+    fn parse(&mut self, src_id: SourceId) -> Result<Vec<Item>, Vec<SyntaxError>> {
+        let tree = self.compiler.parse_file(src_id);
+        match self.compiler.has_errors() {
+            true => Err(self.compiler.take_diagnostics()),
+            false => {
+                self.write_warnings();
+                Ok(tree)
+            }
+        }
+    }
+
+    /// Writes the warnings left over from a line that succeeded, so that they aren't reported again with the next.
+    fn write_warnings(&mut self) {
+        let warnings = self.compiler.take_diagnostics();
+        self.write_errors(warnings, std::io::stdout().lock());
+    }
+
+    /// Lexes, parses, checks and executes a single line of input.
+    ///
+    /// A line either succeeds completely or has no effect: if anything goes wrong, the bindings of the session are
+    /// left exactly as they were before the line was entered.
+    ///
+    /// # Returns
+    /// The value of the line, or every diagnostic it produced.
+    pub(crate) fn eval(&mut self, line: String) -> Result<Value, Vec<SyntaxError>> {
+        let src_id = self.load_line(line);
+        self.eval_source(src_id)
+    }
+
+    /// Runs a file in this session, in the same way as [`Session::eval`].
+    pub(crate) fn load(&mut self, path: &Path) -> std::io::Result<Result<Value, Vec<SyntaxError>>> {
+        let src_id = self.compiler.load_file(path.to_path_buf())?;
+        Ok(self.eval_source(src_id))
+    }
+
+    fn eval_source(&mut self, src_id: SourceId) -> Result<Value, Vec<SyntaxError>> {
+        let checker = self.compiler.checker.clone();
+        let interpreter = self.compiler.interpreter.clone();
+
+        let tree = self.compiler.parse_file(src_id);
+        self.compiler.check(&tree);
+        let value = self
+            .compiler
+            .lower(tree)
+            .and_then(|program| self.compiler.run(&program));
+
+        let value = value.ok_or_else(|| {
+            self.compiler.checker = checker;
+            self.compiler.interpreter = interpreter;
+            self.compiler.take_diagnostics()
+        })?;
+
+        self.write_warnings();
+        Ok(value)
+    }
+
+    /// Lexes a line of input, without doing anything else with it.
+    pub(crate) fn tokens(&mut self, line: &str) -> (Vec<Token>, Vec<SyntaxError>) {
+        let src_id = self.load_line(line.to_string());
+        xva_parse::lex(line, src_id, false)
+    }
+
+    /// Parses a line of input, without checking or executing it.
+    pub(crate) fn ast(&mut self, line: &str) -> Result<Vec<Item>, Vec<SyntaxError>> {
+        let src_id = self.load_line(line.to_string());
+        self.parse(src_id)
+    }
+
+    /// Infers the type of a line of input, which must be a single expression. Nothing is executed or bound.
+    ///
+    /// # Returns
+    /// The type, if the line is a single expression. Otherwise, `None`.
+    pub(crate) fn type_of(&mut self, line: &str) -> Result<Option<Ty>, Vec<SyntaxError>> {
+        let tree = self.ast(line)?;
+        let expr = match tree.as_slice() {
+            [Item {
+                kind: ItemKind::Expression(expr),
+                ..
+            }] => expr,
+            _ => return Ok(None),
+        };
+
+        match self.compiler.checker.type_of(expr) {
+            (ty, errors) if errors.is_empty() => Ok(Some(ty)),
+            (_, errors) => Err(errors),
+        }
+    }
+
+    /// Forgets every binding made in this session.
+    pub(crate) fn reset(&mut self) {
+        self.compiler.checker = Checker::default();
+        self.compiler.interpreter = Interpreter::default();
+    }
+
+    pub(crate) fn write_errors(&self, errors: Vec<SyntaxError>, mut writer: impl Write) {
+        for error in errors {
+            self.compiler.write_syntax_error(error, &mut writer);
+        }
+    }
+
+    /// Runs a meta-command, writing whatever it shows to `writer`.
+    fn run_command(&mut self, command: MetaCommand, mut writer: impl Write) -> std::io::Result<()> {
+        match command {
+            MetaCommand::Tokens(input) => {
+                let (tokens, errors) = self.tokens(input);
+                write!(writer, "{}", dump_tokens(&tokens))?;
+                self.write_errors(errors, writer);
+            }
+
+            MetaCommand::Ast(input) => match self.ast(input) {
+                Ok(tree) => write!(writer, "{}", dump_items(&tree, &DumpOptions::default()))?,
+                Err(errors) => self.write_errors(errors, writer),
+            },
+
+            MetaCommand::Type(input) => match self.type_of(input) {
+                Ok(Some(ty)) => writeln!(writer, "{ty}")?,
+                Ok(None) => writeln!(writer, "`:type` expects a single expression")?,
+                Err(errors) => self.write_errors(errors, writer),
+            },
+
+            MetaCommand::Load(path) => match self.load(path) {
+                Ok(Ok(Value::Unit)) => (),
+                Ok(Ok(value)) => writeln!(writer, "{value}")?,
+                Ok(Err(errors)) => self.write_errors(errors, writer),
+                Err(e) => writeln!(writer, "Could not read {}: {e}", path.display())?,
+            },
+
+            MetaCommand::Reset => self.reset(),
+            MetaCommand::Help => writeln!(writer, "{}", command::help())?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the input so far is unfinished, and the REPL should keep reading before evaluating it: either there are
+/// delimiters or a multi-line string that haven't been closed yet, or the parser ran out of input while it was still
+/// expecting something.
+fn is_incomplete(input: &str) -> bool {
+    // Nothing is reported from here, so it doesn't matter which file the input is said to come from.
+    let (tokens, errors) = xva_parse::lex(input, 0u32.into(), false);
+    if has_unclosed_delimiters(&tokens) || ends_in_multi_line_string(input, &errors) {
+        return true;
+    }
+
+    let (_, errors) = xva_parse::parser::parse(input, 0u32.into(), false);
+    errors
+        .iter()
+        .any(|error| matches!(error.kind(), SyntaxErrorKind::UnexpectedEnd))
+}
+
+/// Whether any delimiters are left open at the end of the tokens. Mismatched delimiters, like `(]`, can't be fixed
+/// by reading more input, so they don't count.
+fn has_unclosed_delimiters(tokens: &[Token]) -> bool {
+    let mut open = vec![];
+    for tok in tokens {
+        match tok.kind() {
+            TokenKind::OpenDelim(delim) => open.push(delim),
+            // Popping the matching delimiter happens in the guard, whether or not it matches.
+            TokenKind::CloseDelim(delim) if open.pop() != Some(delim) => return false,
+            _ => (),
+        }
+    }
+
+    !open.is_empty()
+}
+
+/// Whether the input ends inside a multi-line string or a block comment. Other strings end with their line, so
+/// reading more input can't close them.
+fn ends_in_multi_line_string(input: &str, errors: &[SyntaxError]) -> bool {
+    // Unterminated strings are reported at their opening quotes.
+    errors.iter().any(|error| {
+        let range = error.span().range();
+        match error.kind() {
+            SyntaxErrorKind::UnterminatedString => {
+                input[range.start()..range.end()].ends_with(r#"""""#)
+            }
+            SyntaxErrorKind::UnterminatedComment => true,
+            _ => false,
+        }
+    })
+}
+
+pub(crate) fn run_repl(opts: &Options) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout();
+    let mut session = Session::new(opts);
+
+    let mut editor = Editor::new().map_err(into_io_error)?;
+    editor.set_helper(Some(ReplHelper::default()));
+
+    // Having no history to start with is fine, so failing to load it is too.
+    let history = editor::history_path();
+    if let Some(history) = &history {
+        let _ = editor.load_history(history);
+    }
+
+    // Input is collected here until it's complete.
+    let mut input = String::new();
+
+    loop {
+        let prompt = if input.is_empty() {
+            PROMPT
+        } else {
+            CONTINUATION_PROMPT
+        };
+
+        let line = match editor.readline(prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Eof | ReadlineError::Interrupted) => break,
+            Err(e) => return Err(into_io_error(e)),
+        };
+
+        let blank = line.trim().is_empty();
+        if input.is_empty() && blank {
+            continue;
+        }
+
+        if !blank {
+            editor.add_history_entry(&line).map_err(into_io_error)?;
+        }
+
+        // Meta-commands are only recognised at the start of new input, never as a continuation.
+        if input.is_empty() && line.trim_start().starts_with(':') {
+            match MetaCommand::parse(&line) {
+                Ok(command) => session.run_command(command, stdout.lock())?,
+                Err(message) => println!("{message}"),
+            }
+        } else {
+            if !input.is_empty() {
+                input.push('\n');
+            }
+            input.push_str(&line);
+
+            // A blank line gives up on waiting for the rest of the input.
+            if !blank && is_incomplete(&input) {
+                continue;
+            }
+
+            match session.eval(std::mem::take(&mut input)) {
+                Ok(Value::Unit) => (),
+                Ok(value) => println!("{value}"),
+                Err(errors) => session.write_errors(errors, stdout.lock()),
+            }
+        }
+
+        if let Some(helper) = editor.helper_mut() {
+            helper.names = session
+                .compiler
+                .checker
+                .names()
+                .map(str::to_string)
+                .collect();
+        }
+        stdout.flush()?;
+    }
+
+    if let Some(history) = &history {
+        if let Some(dir) = history.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        editor.save_history(history).map_err(into_io_error)?;
+    }
+
+    Ok(())
+}
+
+fn into_io_error(error: ReadlineError) -> std::io::Error {
+    match error {
+        ReadlineError::Io(e) => e,
+        e => std::io::Error::other(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use xva_compiler::{check::Ty, eval::Value};
+    use xva_parse::error::SyntaxErrorKind;
+
+    use super::{is_incomplete, Session};
+    use crate::opts::Options;
+
+    fn session() -> Session {
+        Session::new(&Options::parse_from(["xva"]))
+    }
+
+    #[test]
+    fn bindings_persist_between_lines() {
+        let mut session = session();
+        assert_eq!(session.eval("let x = 5".into()).ok(), Some(Value::Unit));
+        assert_eq!(session.eval("1 + 2".into()).ok(), Some(Value::Int(3)));
+        assert_eq!(
+            session.compiler.interpreter.lookup("x"),
+            Some(&Value::Int(5))
+        );
+    }
+
+    #[test]
+    fn failed_lines_have_no_effect() {
+        let mut session = session();
+        let errors = session.eval("let x = 1; let y = 1 / 0".into()).unwrap_err();
+        assert_eq!(errors[0].kind(), &SyntaxErrorKind::DivisionByZero);
+        assert_eq!(session.compiler.checker.lookup("x"), None);
+        assert_eq!(session.compiler.interpreter.lookup("x"), None);
+    }
+
+    #[test]
+    fn lines_are_separate_files() {
+        let mut session = session();
+        let errors = session.eval("@".into()).unwrap_err();
+        let errors_again = session.eval("@".into()).unwrap_err();
+
+        let (first, second) = (errors[0].span().src(), errors_again[0].span().src());
+        assert_ne!(first, second);
+        assert_eq!(
+            session.compiler.source_map.get_name(&second),
+            Some("<repl:2>")
+        );
+    }
+
+    #[test]
+    fn unclosed_delimiters_are_incomplete() {
+        assert!(is_incomplete("(1 +"));
+        assert!(is_incomplete("((1 + 2)"));
+        assert!(is_incomplete("{"));
+    }
+
+    #[test]
+    fn balanced_input_is_complete() {
+        assert!(!is_incomplete("(1 + 2)"));
+        assert!(!is_incomplete("(1 +\n 2)"));
+        assert!(!is_incomplete("let x = 5"));
+    }
+
+    #[test]
+    fn mismatched_delimiters_are_complete() {
+        assert!(!is_incomplete("(1 + 2}"));
+        assert!(!is_incomplete(")"));
+    }
+
+    #[test]
+    fn unclosed_multi_line_strings_are_incomplete() {
+        assert!(is_incomplete(r#"let s = """abc"#));
+        assert!(is_incomplete("r\"\"\"\nabc\n"));
+        assert!(is_incomplete(r#"""" {1} "#));
+        assert!(is_incomplete("1 /* comment"));
+        assert!(!is_incomplete(r#"let s = """abc""""#));
+        assert!(!is_incomplete(r#"let s = "abc"#));
+    }
+
+    #[test]
+    fn multi_line_input() {
+        let mut session = session();
+        assert_eq!(
+            session.eval("(1 +\n 2) *\n 3".into()).ok(),
+            Some(Value::Int(9))
+        );
+        assert_eq!(
+            session.eval("\"\"\"\none\ntwo\"\"\"".into()).ok(),
+            Some(Value::String("one\ntwo".into()))
+        );
+    }
+
+    #[test]
+    fn type_of_expression() {
+        let mut session = session();
+        assert_eq!(session.type_of("1 + 2").ok(), Some(Some(Ty::Int)));
+        assert_eq!(session.type_of("not true").ok(), Some(Some(Ty::Bool)));
+        assert_eq!(session.type_of("let x = 1").ok(), Some(None));
+        assert!(session.type_of("1 + true").is_err());
+    }
+
+    #[test]
+    fn reset_forgets_bindings() {
+        let mut session = session();
+        assert!(session.eval("let x = 5".into()).is_ok());
+        session.reset();
+        assert_eq!(session.compiler.checker.lookup("x"), None);
+        assert_eq!(session.compiler.interpreter.lookup("x"), None);
+    }
+
+    #[test]
+    fn load_file() {
+        let path = std::env::temp_dir().join("xva_repl_load_file.xva");
+        std::fs::write(&path, "let loaded = 2 ** 4").unwrap();
+
+        let mut session = session();
+        assert!(matches!(session.load(&path), Ok(Ok(Value::Unit))));
+        assert_eq!(
+            session.compiler.interpreter.lookup("loaded"),
+            Some(&Value::Int(16))
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Meta-commands: instructions to the REPL itself, rather than code to run. They all start with a colon, like `:help`.
+
+use std::path::Path;
+
+/// Every meta-command, as its name, its argument and a description, in the order `:help` lists them.
+pub(super) const COMMANDS: &[(&str, &str, &str)] = &[
+    (
+        "tokens",
+        "<expr>",
+        "Shows the tokens that the input lexes into",
+    ),
+    ("ast", "<expr>", "Shows the tree that the input parses into"),
+    ("type", "<expr>", "Shows the type of an expression"),
+    ("load", "<file>", "Runs a file in this session"),
+    ("reset", "", "Forgets everything bound in this session"),
+    ("help", "", "Shows this list"),
+];
+
+#[derive(Debug, PartialEq)]
+pub(super) enum MetaCommand<'a> {
+    Tokens(&'a str),
+    Ast(&'a str),
+    Type(&'a str),
+    Load(&'a Path),
+    Reset,
+    Help,
+}
+
+impl<'a> MetaCommand<'a> {
+    /// Parses a meta-command from a line of input that starts with `:`.
+    ///
+    /// # Returns
+    /// The command, or a message explaining why the line isn't a valid command.
+    pub(super) fn parse(line: &'a str) -> Result<Self, String> {
+        let line = line.trim().strip_prefix(':').unwrap_or(line);
+        let (name, arg) = match line.split_once(char::is_whitespace) {
+            Some((name, arg)) => (name, arg.trim()),
+            None => (line, ""),
+        };
+
+        let expects = |what: &str| format!("`:{name}` expects {what}");
+
+        match (name, arg) {
+            ("tokens" | "ast" | "type", "") => Err(expects("an expression")),
+            ("tokens", arg) => Ok(Self::Tokens(arg)),
+            ("ast", arg) => Ok(Self::Ast(arg)),
+            ("type", arg) => Ok(Self::Type(arg)),
+            ("load", "") => Err(expects("a file")),
+            ("load", arg) => Ok(Self::Load(Path::new(arg))),
+            ("reset", "") => Ok(Self::Reset),
+            ("help", "") => Ok(Self::Help),
+            ("reset" | "help", _) => Err(format!("`:{name}` takes no arguments")),
+            _ => Err(format!("Unknown command `:{name}`, try `:help`")),
+        }
+    }
+}
+
+/// The text shown by `:help`.
+pub(super) fn help() -> String {
+    let usage = |name: &str, arg: &str| format!(":{name} {arg}");
+    let width = COMMANDS
+        .iter()
+        .map(|(name, arg, _)| usage(name, arg).len())
+        .max()
+        .unwrap_or_default();
+
+    COMMANDS
+        .iter()
+        .map(|(name, arg, description)| format!("  {:width$}  {description}", usage(name, arg)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::MetaCommand;
+
+    #[test]
+    fn commands_with_arguments() {
+        assert_eq!(
+            MetaCommand::parse(":tokens 1 + 2"),
+            Ok(MetaCommand::Tokens("1 + 2"))
+        );
+        assert_eq!(MetaCommand::parse(":ast  (1)"), Ok(MetaCommand::Ast("(1)")));
+        assert_eq!(
+            MetaCommand::parse(":type 1.5"),
+            Ok(MetaCommand::Type("1.5"))
+        );
+        assert_eq!(
+            MetaCommand::parse(":load ./main.xva"),
+            Ok(MetaCommand::Load(Path::new("./main.xva")))
+        );
+    }
+
+    #[test]
+    fn commands_without_arguments() {
+        assert_eq!(MetaCommand::parse(":reset"), Ok(MetaCommand::Reset));
+        assert_eq!(MetaCommand::parse(" :help "), Ok(MetaCommand::Help));
+        assert!(MetaCommand::parse(":help me").is_err());
+    }
+
+    #[test]
+    fn missing_arguments() {
+        assert!(MetaCommand::parse(":type").is_err());
+        assert!(MetaCommand::parse(":load ").is_err());
+    }
+
+    #[test]
+    fn unknown_command() {
+        assert!(MetaCommand::parse(":frobnicate").is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! Line editing for the REPL: history, and tab completion of keywords and names bound in the session.
+
+use std::path::PathBuf;
+
+use rustyline::{
+    completion::Completer, highlight::Highlighter, hint::Hinter, history::DefaultHistory,
+    validate::Validator, Context, Helper,
+};
+
+pub(super) type Editor = rustyline::Editor<ReplHelper, DefaultHistory>;
+
+/// Where REPL history is kept between sessions, under the user's data directory.
+pub(super) fn history_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("xva").join("history"))
+}
+
+/// Completes keywords, and the names that have been bound in the session so far.
+#[derive(Debug, Default)]
+pub(super) struct ReplHelper {
+    /// Every name bound in the session. This is kept up to date by the REPL after each line.
+    pub(super) names: Vec<String>,
+}
+
+impl ReplHelper {
+    /// Every keyword or name that starts with `prefix`, in alphabetical order.
+    fn candidates(&self, prefix: &str) -> Vec<String> {
+        let mut candidates = xva_parse::KEYWORDS
+            .iter()
+            .map(|(keyword, _)| *keyword)
+            .chain(self.names.iter().map(String::as_str))
+            .filter(|word| word.starts_with(prefix))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+
+        candidates.sort();
+        candidates.dedup();
+        candidates
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Self::Candidate>)> {
+        // The character before the word can be more than one byte long, like `“`.
+        let start = line[..pos]
+            .char_indices()
+            .rev()
+            .find(|(_, c)| !xva_parse::is_ident_continue(*c))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+
+        // Nothing has been typed yet, so there's nothing to go on.
+        if start == pos {
+            return Ok((pos, vec![]));
+        }
+
+        Ok((start, self.candidates(&line[start..pos])))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+#[cfg(test)]
+mod tests {
+    use rustyline::{history::DefaultHistory, Context};
+
+    use super::{Completer, ReplHelper};
+
+    fn complete(helper: &ReplHelper, line: &str) -> (usize, Vec<String>) {
+        let history = DefaultHistory::new();
+        helper
+            .complete(line, line.len(), &Context::new(&history))
+            .unwrap()
+    }
+
+    #[test]
+    fn completes_keywords() {
+        let helper = ReplHelper::default();
+        assert_eq!(complete(&helper, "le"), (0, vec!["let".to_string()]));
+    }
+
+    #[test]
+    fn completes_bound_names() {
+        let helper = ReplHelper {
+            names: vec!["value".into(), "variable".into()],
+        };
+        assert_eq!(
+            complete(&helper, "1 + va"),
+            (4, vec!["value".into(), "var".into(), "variable".into()])
+        );
+    }
+
+    #[test]
+    fn multi_byte_separators() {
+        let helper = ReplHelper::default();
+        assert_eq!(
+            complete(&helper, "\u{201c}va"),
+            (3, vec!["var".to_string()])
+        );
+        assert_eq!(complete(&helper, "cafe\u{301}le"), (0, vec![]));
+    }
+
+    #[test]
+    fn nothing_to_complete() {
+        let helper = ReplHelper::default();
+        assert_eq!(complete(&helper, "1 + "), (4, vec![]));
+    }
+}